@@ -0,0 +1,605 @@
+//! Game configuration, loadable from TOML.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::game::day::SpeakingOrder;
+use crate::game::death::DeathReveal;
+use crate::game::night::WolfDeadlock;
+use crate::game::rng::Rng;
+use crate::game::timeout::FallbackStrategy;
+use crate::roles::{Role, RoleBehavior, RoleRegistry};
+
+/// Named ready-made setups for people who don't want to hand-tune ratios.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Preset {
+    /// The classic 7-player table: 2 wolves, Seer, Witch, 3 villagers.
+    Classic,
+    /// A loud 9-player table: 2 wolves plus every special role, random
+    /// speaking order, accusations, and runoff voting.
+    Chaos,
+}
+
+impl Preset {
+    /// The full config for this preset.
+    pub fn config(self) -> GameConfig {
+        match self {
+            Preset::Classic => GameConfig::default(),
+            Preset::Chaos => GameConfig {
+                player_count: 9,
+                roles: BTreeMap::from([
+                    (Role::Werewolf, 2),
+                    (Role::Seer, 1),
+                    (Role::Witch, 1),
+                    (Role::Guard, 1),
+                    (Role::Hunter, 1),
+                    (Role::Villager, 3),
+                ]),
+                speaking_order: SpeakingOrder::RandomEachDay,
+                accusation_phase: true,
+                voting_mode: VotingMode::Runoff,
+                hunter_shoots_on_poison: true,
+                ..GameConfig::default()
+            },
+        }
+    }
+}
+
+/// Picks a reasonably balanced role multiset for a table of
+/// `player_count`: roughly one wolf per 3–4 players (the exact count drawn
+/// from `rng`), always a Seer, and special roles scaling up with the table
+/// size. The result always passes [`GameConfig::validate`].
+pub fn suggest_setup(player_count: usize, rng: &mut Rng) -> GameConfig {
+    let player_count = player_count.max(3);
+    // One wolf per 3-4 players: draw from the inclusive range.
+    let min_wolves = (player_count / 4).max(1);
+    let max_wolves = (player_count / 3).max(1);
+    let wolves = min_wolves + rng.index(max_wolves - min_wolves + 1);
+
+    // Specials beyond the Seer, in rough order of impact; larger tables
+    // carry more of them.
+    let mut specials = vec![Role::Witch, Role::Guard, Role::Hunter];
+    rng.shuffle(&mut specials);
+    let special_count = (player_count.saturating_sub(4) / 3)
+        .min(specials.len())
+        .min(player_count - wolves - 1);
+
+    let mut roles = BTreeMap::from([(Role::Werewolf, wolves), (Role::Seer, 1)]);
+    for role in specials.into_iter().take(special_count) {
+        roles.insert(role, 1);
+    }
+    let villagers = player_count - wolves - 1 - special_count;
+    if villagers > 0 {
+        roles.insert(Role::Villager, villagers);
+    }
+    GameConfig { player_count, roles, ..GameConfig::default() }
+}
+
+/// How the day-phase vote is tallied.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum VotingMode {
+    /// Single round, most votes is eliminated.
+    #[default]
+    Plurality,
+    /// Repeated rounds dropping the weakest candidate until someone clears
+    /// the runoff threshold; see
+    /// [`RunoffSettings`](crate::game::vote::RunoffSettings).
+    Runoff,
+    /// Votes are cast in seat order and each is immediately public; after
+    /// the first pass, up to `vote_change_rounds` revision passes let
+    /// players change their vote before the tally. Only the last vote per
+    /// player counts; see [`run_open_vote`](crate::game::vote::run_open_vote).
+    Open,
+    /// Every player votes against a pre-vote snapshot of the table and
+    /// all votes reveal simultaneously; see
+    /// [`run_secret_vote`](crate::game::vote::run_secret_vote).
+    Secret,
+}
+
+/// Which phase a fresh game opens with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum FirstPhase {
+    /// The game opens with a night ("Night-0" rule sets).
+    #[default]
+    Night,
+    /// The game opens with a day discussion.
+    Day,
+}
+
+/// A full game setup, typically loaded from a TOML file.
+///
+/// The role multiset is a map from role to count so a TOML author can write
+/// `[roles]` / `Werewolf = 2` without repeating entries. A `BTreeMap` keeps
+/// serialization order stable.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GameConfig {
+    /// Number of seats at the table.
+    pub player_count: usize,
+    /// The multiset of roles in play; counts must sum to `player_count`.
+    pub roles: BTreeMap<Role, usize>,
+    /// Number of discussion rounds per day.
+    #[serde(default = "default_discussion_rounds")]
+    pub discussion_rounds: u32,
+    /// The order players speak in during discussion.
+    #[serde(default)]
+    pub speaking_order: SpeakingOrder,
+    /// Estimated-token budget per player per game; `None` is unlimited.
+    #[serde(default)]
+    pub token_budget: Option<u32>,
+    /// Hard ceiling on the game's estimated dollar cost; once exceeded,
+    /// discussion ends early and the table goes straight to the vote.
+    /// `None` is unlimited.
+    #[serde(default)]
+    pub max_cost: Option<f64>,
+    /// Whether the pre-vote accusation-and-defense sub-phase runs.
+    #[serde(default)]
+    pub accusation_phase: bool,
+    /// Accusations allowed per day when the sub-phase is enabled.
+    #[serde(default = "default_max_accusations")]
+    pub max_accusations_per_day: u32,
+    /// How day votes are tallied.
+    #[serde(default)]
+    pub voting_mode: VotingMode,
+    /// Revision passes after the first open-voting pass; only meaningful
+    /// under [`VotingMode::Open`].
+    #[serde(default = "default_vote_change_rounds")]
+    pub vote_change_rounds: u32,
+    /// Vote fraction a runoff candidate must exceed to win outright.
+    #[serde(default = "default_runoff_threshold")]
+    pub runoff_threshold: f64,
+    /// Hard cap on runoff voting rounds.
+    #[serde(default = "default_runoff_max_rounds")]
+    pub runoff_max_rounds: u32,
+    /// Hard cap on the number of days; a game still undecided after
+    /// playing day `max_days` ends in a draw instead of starting another
+    /// day. `None` plays until a win condition fires, guarding against
+    /// non-termination when, say, every player abstains forever.
+    #[serde(default)]
+    pub max_days: Option<u32>,
+    /// Which phase the game opens with.
+    #[serde(default)]
+    pub first_phase: FirstPhase,
+    /// Whether Night-0 is a setup-only night: investigations and
+    /// protections resolve, kills and potions are dropped. Only meaningful
+    /// when the game opens with Night; day numbering is unaffected either
+    /// way (Night-0 games count from day 0, day-start games from day 1).
+    #[serde(default)]
+    pub peaceful_night0: bool,
+    /// Whether the game has night phases at all. Day-only party variants
+    /// disable this; night-acting roles are then invalid.
+    #[serde(default = "default_true")]
+    pub night_phase: bool,
+    /// How much of a dead player's role is publicly revealed: the exact
+    /// role, the alignment only, or nothing.
+    #[serde(default)]
+    pub death_reveal: DeathReveal,
+    /// Milliseconds each individual player call may take before the
+    /// fallback kicks in.
+    #[serde(default = "default_action_timeout_ms")]
+    pub action_timeout_ms: u64,
+    /// What to answer for a player who timed out.
+    #[serde(default)]
+    pub fallback: FallbackStrategy,
+    /// Whether a Hunter killed by Witch poison still gets a dying shot.
+    #[serde(default)]
+    pub hunter_shoots_on_poison: bool,
+    /// Whether the Witch may heal herself.
+    #[serde(default = "default_true")]
+    pub witch_self_heal: bool,
+    /// Whether the Witch may spend both potions in the same night.
+    #[serde(default = "default_true")]
+    pub witch_both_potions_same_night: bool,
+    /// Whether the Guard may protect himself.
+    #[serde(default = "default_true")]
+    pub guard_self_protect: bool,
+    /// Whether the Guard may protect the same target two nights running.
+    #[serde(default)]
+    pub guard_repeat_protect: bool,
+    /// What the Seer learns from an investigation: the target's alignment,
+    /// their exact role, or an appearance-based werewolf check.
+    #[serde(default)]
+    pub seer_mode: crate::game::night::SeerMode,
+    /// Whether the wolves coordinate their kill through a private chat and
+    /// pack-internal vote instead of acting independently.
+    #[serde(default)]
+    pub wolf_coordination: bool,
+    /// How a deadlocked wolf council resolves: a peaceful night, or a
+    /// forced kill picked by the seeded RNG.
+    #[serde(default)]
+    pub wolf_deadlock: WolfDeadlock,
+    /// Whether town must also eliminate the Minion to win. Off by default:
+    /// killing every actual killing wolf ends the game, and a surviving
+    /// Minion simply loses with the pack.
+    #[serde(default)]
+    pub minion_blocks_town_win: bool,
+    /// Whether dead players keep talking in a graveyard-only channel. The
+    /// transcript never reaches a living player's view; it exists for
+    /// post-mortems and spectators.
+    #[serde(default)]
+    pub graveyard_chat: bool,
+    /// Whether a player whose death was just decided gets a public final
+    /// statement, recorded as a `LastWords` event. A lynch victim speaks
+    /// before the death is applied; night victims speak at dawn.
+    #[serde(default)]
+    pub last_words: bool,
+    /// Whether raw speech replies are checked for refusals — empty output
+    /// or a trigger phrase — and logged as `PlayerRefused` events before
+    /// the seat falls back to silence.
+    #[serde(default)]
+    pub detect_refusals: bool,
+    /// Case-insensitive substrings that mark a reply as a refusal when
+    /// detection is enabled. An empty list uses the built-in set; see
+    /// [`RefusalDetector`](crate::llm::parse::RefusalDetector).
+    #[serde(default)]
+    pub refusal_triggers: Vec<String>,
+    /// Whether near-duplicate speeches are collapsed: a speech too similar
+    /// to the speaker's recent ones is replaced in the transcript by a
+    /// short placeholder. See [`DedupFilter`](crate::llm::dedup::DedupFilter).
+    #[serde(default)]
+    pub dedup_speeches: bool,
+    /// Cosine-similarity threshold at or above which a speech counts as a
+    /// repeat, when deduplication is enabled.
+    #[serde(default = "default_dedup_threshold")]
+    pub dedup_threshold: f32,
+    /// How many of a speaker's recent speeches a new one is compared to.
+    #[serde(default = "default_dedup_lookback")]
+    pub dedup_lookback: usize,
+    /// Whether town players' contexts include a computed suspicion prior
+    /// over the living players, derived from public information only; see
+    /// [`suspicion_scores`](crate::game::suspicion::suspicion_scores).
+    #[serde(default)]
+    pub suspicion_scores: bool,
+    /// The role behaviors available to this game. Not serialized — custom
+    /// behaviors are code, registered via [`GameConfig::register_role`].
+    #[serde(skip)]
+    pub registry: RoleRegistry,
+}
+
+fn default_action_timeout_ms() -> u64 {
+    60_000
+}
+
+fn default_discussion_rounds() -> u32 {
+    1
+}
+
+fn default_max_accusations() -> u32 {
+    3
+}
+
+fn default_runoff_threshold() -> f64 {
+    0.5
+}
+
+fn default_runoff_max_rounds() -> u32 {
+    3
+}
+
+fn default_vote_change_rounds() -> u32 {
+    1
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_dedup_threshold() -> f32 {
+    0.9
+}
+
+fn default_dedup_lookback() -> usize {
+    5
+}
+
+/// A problem with a [`GameConfig`], precise enough to fix by hand.
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum ConfigError {
+    #[error("config is not valid TOML: {0}")]
+    Toml(String),
+    #[error("role counts sum to {role_total} but player_count is {player_count}")]
+    RoleCountMismatch { role_total: usize, player_count: usize },
+    #[error("at least one Werewolf is required, found none")]
+    NoWolves,
+    #[error("{role:?} acts at night but night_phase is disabled")]
+    NightRoleWithoutNight { role: Role },
+    #[error("player_count must be at least 3, got {0}")]
+    TooFewPlayers(usize),
+}
+
+impl GameConfig {
+    /// Registers a custom [`RoleBehavior`] under a string id, making it
+    /// available to games built from this config.
+    pub fn register_role(
+        &mut self,
+        id: impl Into<String>,
+        behavior: std::sync::Arc<dyn RoleBehavior>,
+    ) {
+        self.registry.register(id, behavior);
+    }
+
+    /// Parses a config from a TOML document. The result is not yet
+    /// validated; call [`GameConfig::validate`] before building a game.
+    pub fn from_toml(source: &str) -> Result<Self, ConfigError> {
+        toml::from_str(source).map_err(|e| ConfigError::Toml(e.to_string()))
+    }
+
+    /// Total number of roles in the multiset.
+    pub fn role_total(&self) -> usize {
+        self.roles.values().sum()
+    }
+
+    /// Number of killing wolves in the setup (Werewolves and Alpha
+    /// Werewolves; a Minion has no kill and does not count).
+    pub fn wolf_count(&self) -> usize {
+        [Role::Werewolf, Role::AlphaWerewolf]
+            .iter()
+            .filter_map(|role| self.roles.get(role))
+            .sum()
+    }
+
+    /// The per-action timeout and fallback as a [`TurnPolicy`].
+    ///
+    /// [`TurnPolicy`]: crate::game::timeout::TurnPolicy
+    pub fn turn_policy(&self) -> crate::game::timeout::TurnPolicy {
+        crate::game::timeout::TurnPolicy {
+            timeout: std::time::Duration::from_millis(self.action_timeout_ms),
+            fallback: self.fallback,
+            ..Default::default()
+        }
+    }
+
+    /// The discussion-phase knobs as [`DiscussionSettings`].
+    ///
+    /// [`DiscussionSettings`]: crate::game::day::DiscussionSettings
+    pub fn discussion_settings(&self) -> crate::game::day::DiscussionSettings {
+        crate::game::day::DiscussionSettings {
+            rounds: self.discussion_rounds,
+            policy: crate::game::day::DiscussionPolicy::default(),
+            token_budget: self.token_budget,
+            max_cost: self.max_cost,
+            accusations: self.accusation_phase.then_some(self.max_accusations_per_day),
+            order: self.speaking_order,
+            refusals: self.detect_refusals.then(|| {
+                if self.refusal_triggers.is_empty() {
+                    crate::llm::parse::RefusalDetector::default()
+                } else {
+                    crate::llm::parse::RefusalDetector::with_triggers(
+                        self.refusal_triggers.clone(),
+                    )
+                }
+            }),
+            dedup: self.dedup_speeches.then(|| {
+                crate::llm::dedup::DedupFilter::new(
+                    std::sync::Arc::new(crate::llm::dedup::HashingEmbedder::default()),
+                    self.dedup_threshold,
+                    self.dedup_lookback,
+                )
+            }),
+        }
+    }
+
+    /// The runoff-voting knobs as [`RunoffSettings`].
+    ///
+    /// [`RunoffSettings`]: crate::game::vote::RunoffSettings
+    pub fn runoff_settings(&self) -> crate::game::vote::RunoffSettings {
+        crate::game::vote::RunoffSettings {
+            threshold: self.runoff_threshold,
+            max_rounds: self.runoff_max_rounds,
+            tie: crate::game::vote::TieResolution::default(),
+        }
+    }
+
+    /// The Witch rule variants as [`WitchRules`].
+    ///
+    /// [`WitchRules`]: crate::game::night::WitchRules
+    pub fn witch_rules(&self) -> crate::game::night::WitchRules {
+        crate::game::night::WitchRules {
+            may_self_heal: self.witch_self_heal,
+            both_potions_same_night: self.witch_both_potions_same_night,
+        }
+    }
+
+    /// The Guard rule variants as [`GuardRules`].
+    ///
+    /// [`GuardRules`]: crate::game::night::GuardRules
+    pub fn guard_rules(&self) -> crate::game::night::GuardRules {
+        crate::game::night::GuardRules {
+            may_guard_self: self.guard_self_protect,
+            may_repeat: self.guard_repeat_protect,
+        }
+    }
+
+    /// The win-rule variants as [`WinRules`].
+    ///
+    /// [`WinRules`]: crate::game::win::WinRules
+    pub fn win_rules(&self) -> crate::game::win::WinRules {
+        crate::game::win::WinRules {
+            minion_blocks_town_win: self.minion_blocks_town_win,
+            ..crate::game::win::WinRules::default()
+        }
+    }
+
+    /// The dying-shot rule variants as [`HunterRules`].
+    ///
+    /// [`HunterRules`]: crate::game::death::HunterRules
+    pub fn hunter_rules(&self) -> crate::game::death::HunterRules {
+        crate::game::death::HunterRules { shoot_on_poison: self.hunter_shoots_on_poison }
+    }
+
+    /// Checks the setup is actually playable.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        if self.player_count < 3 {
+            return Err(ConfigError::TooFewPlayers(self.player_count));
+        }
+        let role_total = self.role_total();
+        if role_total != self.player_count {
+            return Err(ConfigError::RoleCountMismatch {
+                role_total,
+                player_count: self.player_count,
+            });
+        }
+        if self.wolf_count() == 0 {
+            return Err(ConfigError::NoWolves);
+        }
+        if !self.night_phase {
+            if let Some(role) = self
+                .roles
+                .iter()
+                .filter(|&(_, &count)| count > 0)
+                .map(|(role, _)| *role)
+                .find(|role| {
+                    role.info().acts_at_night
+                        && !matches!(role, Role::Werewolf | Role::AlphaWerewolf)
+                })
+            {
+                return Err(ConfigError::NightRoleWithoutNight { role });
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Default for GameConfig {
+    /// A classic 7-player setup: 2 wolves, Seer, Witch, 3 villagers.
+    fn default() -> Self {
+        Self {
+            player_count: 7,
+            roles: BTreeMap::from([
+                (Role::Werewolf, 2),
+                (Role::Seer, 1),
+                (Role::Witch, 1),
+                (Role::Villager, 3),
+            ]),
+            discussion_rounds: default_discussion_rounds(),
+            speaking_order: SpeakingOrder::default(),
+            token_budget: None,
+            max_cost: None,
+            accusation_phase: false,
+            max_accusations_per_day: default_max_accusations(),
+            voting_mode: VotingMode::default(),
+            vote_change_rounds: default_vote_change_rounds(),
+            runoff_threshold: default_runoff_threshold(),
+            runoff_max_rounds: default_runoff_max_rounds(),
+            max_days: None,
+            first_phase: FirstPhase::default(),
+            peaceful_night0: false,
+            night_phase: true,
+            death_reveal: DeathReveal::default(),
+            action_timeout_ms: default_action_timeout_ms(),
+            fallback: FallbackStrategy::default(),
+            hunter_shoots_on_poison: false,
+            witch_self_heal: true,
+            witch_both_potions_same_night: true,
+            guard_self_protect: true,
+            guard_repeat_protect: false,
+            seer_mode: crate::game::night::SeerMode::default(),
+            wolf_coordination: false,
+            wolf_deadlock: WolfDeadlock::default(),
+            minion_blocks_town_win: false,
+            graveyard_chat: false,
+            last_words: false,
+            detect_refusals: false,
+            refusal_triggers: Vec::new(),
+            dedup_speeches: false,
+            dedup_threshold: default_dedup_threshold(),
+            dedup_lookback: default_dedup_lookback(),
+            suspicion_scores: false,
+            registry: RoleRegistry::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_config_validates() {
+        assert_eq!(GameConfig::default().validate(), Ok(()));
+    }
+
+    #[test]
+    fn parses_a_toml_config() {
+        let cfg = GameConfig::from_toml(
+            r#"
+            player_count = 5
+            discussion_rounds = 2
+
+            [roles]
+            Werewolf = 1
+            Seer = 1
+            Villager = 3
+            "#,
+        )
+        .unwrap();
+        assert_eq!(cfg.player_count, 5);
+        assert_eq!(cfg.discussion_rounds, 2);
+        assert_eq!(cfg.wolf_count(), 1);
+        assert_eq!(cfg.validate(), Ok(()));
+    }
+
+    #[test]
+    fn rejects_more_roles_than_players() {
+        let cfg = GameConfig { player_count: 5, ..GameConfig::default() };
+        assert_eq!(
+            cfg.validate(),
+            Err(ConfigError::RoleCountMismatch { role_total: 7, player_count: 5 })
+        );
+    }
+
+    #[test]
+    fn rejects_zero_wolves() {
+        let mut cfg = GameConfig::default();
+        cfg.roles.remove(&Role::Werewolf);
+        cfg.roles.insert(Role::Villager, 5);
+        assert_eq!(cfg.validate(), Err(ConfigError::NoWolves));
+    }
+
+    #[test]
+    fn rejects_night_role_without_night_phase() {
+        let cfg = GameConfig { night_phase: false, ..GameConfig::default() };
+        assert!(matches!(
+            cfg.validate(),
+            Err(ConfigError::NightRoleWithoutNight { .. })
+        ));
+    }
+
+    #[test]
+    fn suggested_nine_player_setup_is_sane() {
+        let mut rng = Rng::new(0);
+        let cfg = suggest_setup(9, &mut rng);
+        assert_eq!(cfg.validate(), Ok(()));
+        assert_eq!(cfg.role_total(), 9);
+        assert!((2..=3).contains(&cfg.wolf_count()));
+        assert_eq!(cfg.roles.get(&Role::Seer), Some(&1));
+    }
+
+    #[test]
+    fn suggested_setups_validate_across_table_sizes() {
+        let mut rng = Rng::new(7);
+        for player_count in 3..=20 {
+            let cfg = suggest_setup(player_count, &mut rng);
+            assert_eq!(cfg.validate(), Ok(()), "bad setup for {player_count} players");
+        }
+    }
+
+    #[test]
+    fn suggestions_are_deterministic_per_seed() {
+        let a = suggest_setup(11, &mut Rng::new(5));
+        let b = suggest_setup(11, &mut Rng::new(5));
+        assert_eq!(a.roles, b.roles);
+    }
+
+    #[test]
+    fn presets_validate() {
+        assert_eq!(Preset::Classic.config().validate(), Ok(()));
+        assert_eq!(Preset::Chaos.config().validate(), Ok(()));
+    }
+
+    #[test]
+    fn invalid_toml_names_the_problem() {
+        let err = GameConfig::from_toml("player_count = ").unwrap_err();
+        assert!(matches!(err, ConfigError::Toml(_)));
+    }
+}
@@ -0,0 +1,65 @@
+//! The crate-wide error type: one enum to match on, wrapping every
+//! error the public API can surface.
+//!
+//! Each variant is `#[error(transparent)]` over the module-level error it
+//! wraps, so `Display` shows the specific, human-readable message and
+//! callers who care can still match the variant to tell a network failure
+//! from a config mistake. None of the wrapped messages include request
+//! headers or API keys.
+
+use crate::config::ConfigError;
+use crate::game::builder::BuildError;
+use crate::game::replay::ReplayError;
+use crate::game::state::PersistError;
+use crate::llm::{HealthCheckFailure, LlmError};
+
+/// Any error the crate's public API can return.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// Talking to a model backend failed.
+    #[error(transparent)]
+    Llm(#[from] LlmError),
+    /// A provider failed its pre-game health check.
+    #[error(transparent)]
+    HealthCheck(#[from] HealthCheckFailure),
+    /// A [`GameConfig`](crate::config::GameConfig) is invalid.
+    #[error(transparent)]
+    Config(#[from] ConfigError),
+    /// A [`GameBuilder`](crate::game::builder::GameBuilder) setup is
+    /// inconsistent.
+    #[error(transparent)]
+    Build(#[from] BuildError),
+    /// A saved event log fails consistency checks.
+    #[error(transparent)]
+    Replay(#[from] ReplayError),
+    /// Saving or restoring a state checkpoint failed.
+    #[error(transparent)]
+    Persist(#[from] PersistError),
+}
+
+/// The crate-wide result alias; the default error is [`enum@Error`].
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::state::PlayerId;
+
+    #[test]
+    fn display_forwards_the_underlying_message() {
+        let err = Error::from(LlmError::Network("connection refused".into()));
+        assert_eq!(err.to_string(), "network error: connection refused");
+
+        let err = Error::from(ConfigError::NoWolves);
+        assert_eq!(err.to_string(), "at least one Werewolf is required, found none");
+    }
+
+    #[test]
+    fn variants_stay_distinguishable() {
+        let err: Error = BuildError::DuplicatePlayer(PlayerId(3)).into();
+        assert!(matches!(err, Error::Build(BuildError::DuplicatePlayer(PlayerId(3)))));
+
+        let err: Error = ReplayError { index: 2, reason: "x".into() }.into();
+        assert!(matches!(err, Error::Replay(ReplayError { index: 2, .. })));
+    }
+}
@@ -0,0 +1,54 @@
+//! Actions a player can take, night or day.
+
+use serde::{Deserialize, Serialize};
+
+use crate::game::state::PlayerId;
+use crate::roles::Role;
+
+/// A single decision taken by a player.
+///
+/// Night actions carry their target; day-phase decisions such as voting go
+/// through dedicated flows but are represented here too so the engine has
+/// one action vocabulary.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Action {
+    /// Werewolf kill attempt.
+    Kill(PlayerId),
+    /// Guard protection.
+    Protect(PlayerId),
+    /// Seer investigation.
+    Investigate(PlayerId),
+    /// Witch heal (saves tonight's attack victim).
+    Heal(PlayerId),
+    /// Witch poison.
+    Poison(PlayerId),
+    /// Day-phase vote to eliminate.
+    Vote(PlayerId),
+    /// Formally accusing a player before the vote.
+    Accuse(PlayerId),
+    /// Publicly claiming a role during discussion.
+    Claim(Role),
+    /// A message on the wolves' private coordination channel.
+    WolfChat(String),
+    /// A dying Hunter's shot.
+    HunterShot(PlayerId),
+    /// Explicitly doing nothing this phase.
+    Pass,
+}
+
+impl Action {
+    /// The player targeted by this action, if any.
+    pub fn target(&self) -> Option<PlayerId> {
+        match self {
+            Action::Kill(t)
+            | Action::Protect(t)
+            | Action::Investigate(t)
+            | Action::Heal(t)
+            | Action::Poison(t)
+            | Action::Vote(t)
+            | Action::Accuse(t)
+            | Action::HunterShot(t) => Some(*t),
+            Action::Claim(_) | Action::WolfChat(_) | Action::Pass => None,
+        }
+    }
+}
@@ -0,0 +1,374 @@
+//! Chainable construction of a ready-to-play [`GameState`].
+//!
+//! Hand-wiring a game means creating the state, assigning every role, and
+//! copying rule variants over from the config — easy to get subtly wrong.
+//! [`GameBuilder`] does all of it behind a small chainable API and refuses
+//! to produce a state whose roster and role multiset don't line up.
+
+use std::collections::{BTreeMap, HashMap};
+
+use crate::config::GameConfig;
+use crate::game::state::{GameState, Phase, PlayerId};
+use crate::player::Player;
+use crate::roles::Role;
+
+/// A seat-keyed player roster, as the tournament driver consumes it.
+pub type Roster = HashMap<PlayerId, Box<dyn Player>>;
+
+/// A problem that stops [`GameBuilder::build`] from producing a game.
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum BuildError {
+    #[error("player {0} was added twice")]
+    DuplicatePlayer(PlayerId),
+    #[error("{players} players were added but the roles sum to {roles}")]
+    PlayerRoleMismatch { players: usize, roles: usize },
+    #[error("role was pinned to player {0}, who was never added")]
+    PinnedToUnknownPlayer(PlayerId),
+    #[error("{role:?} was pinned more times than the multiset provides")]
+    PinnedRoleUnavailable { role: Role },
+}
+
+/// Builds a [`GameState`] (and optionally its player roster) step by step.
+///
+/// Roles are auto-assigned to seats via the seeded shuffle — the same
+/// deterministic assignment the tournament driver uses — unless a specific
+/// seat is pinned with [`GameBuilder::assign`]. Seats are sorted and the
+/// multiset drained in role order before the shuffle, so the assignment
+/// depends only on the seed, the seats, and the roles — never on the
+/// order players were added. Reproducible research depends on this.
+///
+/// ```
+/// use llmwerewolf_rs::game::builder::GameBuilder;
+/// use llmwerewolf_rs::game::PlayerId;
+/// use llmwerewolf_rs::player::ScriptedPlayer;
+/// use llmwerewolf_rs::roles::Role;
+///
+/// let state = GameBuilder::new()
+///     .role(Role::Werewolf, 1)
+///     .role(Role::Villager, 2)
+///     .player(PlayerId(0), Box::new(ScriptedPlayer::new()))
+///     .player(PlayerId(1), Box::new(ScriptedPlayer::new()))
+///     .player(PlayerId(2), Box::new(ScriptedPlayer::new()))
+///     .seed(42)
+///     .build()
+///     .unwrap();
+/// assert_eq!(state.alive_players(), vec![PlayerId(0), PlayerId(1), PlayerId(2)]);
+/// ```
+#[derive(Default)]
+pub struct GameBuilder {
+    roles: BTreeMap<Role, usize>,
+    players: Vec<(PlayerId, Box<dyn Player>)>,
+    pinned: Vec<(PlayerId, Role)>,
+    seed: u64,
+    config: Option<GameConfig>,
+}
+
+impl GameBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `count` copies of `role` to the multiset. Repeated calls for
+    /// the same role accumulate.
+    pub fn role(mut self, role: Role, count: usize) -> Self {
+        *self.roles.entry(role).or_default() += count;
+        self
+    }
+
+    /// Adds a player at the given seat.
+    pub fn player(mut self, id: PlayerId, player: Box<dyn Player>) -> Self {
+        self.players.push((id, player));
+        self
+    }
+
+    /// Pins a specific role to a specific seat, exempting it from the
+    /// shuffle. The pinned role still counts against the multiset.
+    pub fn assign(mut self, id: PlayerId, role: Role) -> Self {
+        self.pinned.push((id, role));
+        self
+    }
+
+    /// Seeds the game's RNG; defaults to 0.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Adopts a [`GameConfig`]: its role multiset (unless roles were added
+    /// directly, which take precedence), first phase, and rule variants.
+    pub fn config(mut self, config: GameConfig) -> Self {
+        self.config = Some(config);
+        self
+    }
+
+    /// Builds the [`GameState`], discarding the player roster. For driving
+    /// a game loop, [`GameBuilder::build_with_players`] keeps both.
+    pub fn build(self) -> Result<GameState, BuildError> {
+        self.build_with_players().map(|(state, _)| state)
+    }
+
+    /// Builds the [`GameState`] together with the roster the driver loop
+    /// needs, keyed by seat.
+    pub fn build_with_players(self) -> Result<(GameState, Roster), BuildError> {
+        let roles = if self.roles.is_empty() {
+            self.config.as_ref().map(|c| c.roles.clone()).unwrap_or_default()
+        } else {
+            self.roles
+        };
+
+        let mut ids: Vec<PlayerId> = Vec::with_capacity(self.players.len());
+        for (id, _) in &self.players {
+            if ids.contains(id) {
+                return Err(BuildError::DuplicatePlayer(*id));
+            }
+            ids.push(*id);
+        }
+
+        let role_total: usize = roles.values().sum();
+        if role_total != ids.len() {
+            return Err(BuildError::PlayerRoleMismatch {
+                players: ids.len(),
+                roles: role_total,
+            });
+        }
+
+        // Pinned assignments come out of the pool first; whatever is left
+        // is shuffled over the remaining seats.
+        let mut pool: BTreeMap<Role, usize> = roles;
+        for (id, role) in &self.pinned {
+            if !ids.contains(id) {
+                return Err(BuildError::PinnedToUnknownPlayer(*id));
+            }
+            let available = pool.get_mut(role).filter(|count| **count > 0);
+            match available {
+                Some(count) => *count -= 1,
+                None => return Err(BuildError::PinnedRoleUnavailable { role: *role }),
+            }
+        }
+
+        let first_phase = match self.config.as_ref().map(|c| c.first_phase) {
+            Some(crate::config::FirstPhase::Day) => Phase::Day,
+            _ => Phase::Night,
+        };
+        // Sort the seats before shuffling: the assignment must be a
+        // function of (seed, seats, roles) alone, not of the order the
+        // caller happened to add players in.
+        ids.sort_unstable();
+        let mut state = GameState::new(ids.iter().copied(), first_phase, self.seed);
+
+        let mut shuffled: Vec<Role> = pool
+            .iter()
+            .flat_map(|(role, count)| std::iter::repeat_n(*role, *count))
+            .collect();
+        state.rng_mut().shuffle(&mut shuffled);
+        for (id, role) in &self.pinned {
+            state.assign_role(*id, *role);
+        }
+        let unpinned =
+            ids.iter().filter(|id| !self.pinned.iter().any(|(pinned, _)| pinned == *id));
+        for (id, role) in unpinned.zip(shuffled) {
+            state.assign_role(*id, role);
+        }
+
+        if let Some(config) = &self.config {
+            state.set_death_reveal(config.death_reveal);
+            state.set_witch_rules(config.witch_rules());
+            state.set_guard_rules(config.guard_rules());
+            state.set_seer_mode(config.seer_mode);
+            state.set_win_rules(config.win_rules());
+            state.set_max_days(config.max_days);
+        }
+
+        // A Minion knows the wolves from the start: seed its private
+        // knowledge with a night-0 reveal of every killing wolf.
+        let minions: Vec<PlayerId> = ids
+            .iter()
+            .copied()
+            .filter(|&id| state.role_of(id) == Some(Role::Minion))
+            .collect();
+        if !minions.is_empty() {
+            let wolves: Vec<PlayerId> = ids
+                .iter()
+                .copied()
+                .filter(|&id| {
+                    state.role_of(id).is_some_and(|r| {
+                        r.alignment() == crate::roles::Alignment::Wolf
+                            && r.info().acts_at_night
+                    })
+                })
+                .collect();
+            for minion in minions {
+                for &wolf in &wolves {
+                    state.record_investigation(
+                        minion,
+                        crate::game::knowledge::Investigation {
+                            night: 0,
+                            target: wolf,
+                            revealed_alignment: crate::roles::Alignment::Wolf,
+                            revealed_role: None,
+                        },
+                    );
+                }
+            }
+        }
+
+        Ok((state, self.players.into_iter().collect()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::player::ScriptedPlayer;
+
+    fn seat(id: PlayerId) -> (PlayerId, Box<dyn Player>) {
+        (id, Box::new(ScriptedPlayer::new()) as Box<dyn Player>)
+    }
+
+    fn three_seats() -> GameBuilder {
+        let mut b = GameBuilder::new().role(Role::Werewolf, 1).role(Role::Villager, 2);
+        for id in 0..3 {
+            let (id, p) = seat(PlayerId(id));
+            b = b.player(id, p);
+        }
+        b
+    }
+
+    #[test]
+    fn builds_and_assigns_every_seat_a_role() {
+        let state = three_seats().seed(1).build().unwrap();
+        assert_eq!(state.alive_players(), vec![PlayerId(0), PlayerId(1), PlayerId(2)]);
+        assert!((0..3).all(|id| state.role_of(PlayerId(id)).is_some()));
+        let wolves = (0..3).filter(|&id| state.role_of(PlayerId(id)) == Some(Role::Werewolf));
+        assert_eq!(wolves.count(), 1);
+    }
+
+    #[test]
+    fn same_seed_reproduces_the_same_assignment() {
+        let a = three_seats().seed(9).build().unwrap();
+        let b = three_seats().seed(9).build().unwrap();
+        for id in 0..3 {
+            assert_eq!(a.role_of(PlayerId(id)), b.role_of(PlayerId(id)));
+        }
+    }
+
+    #[test]
+    fn assignment_ignores_player_insertion_order() {
+        for seed in 0..10 {
+            let mut forward = GameBuilder::new()
+                .role(Role::Werewolf, 1)
+                .role(Role::Seer, 1)
+                .role(Role::Villager, 3)
+                .seed(seed);
+            let mut reverse = GameBuilder::new()
+                .role(Role::Werewolf, 1)
+                .role(Role::Seer, 1)
+                .role(Role::Villager, 3)
+                .seed(seed);
+            for id in 0..5 {
+                let (id, p) = seat(PlayerId(id));
+                forward = forward.player(id, p);
+                let (id, p) = seat(PlayerId(4 - id.0));
+                reverse = reverse.player(id, p);
+            }
+            let forward = forward.build().unwrap();
+            let reverse = reverse.build().unwrap();
+            for id in 0..5 {
+                assert_eq!(
+                    forward.role_of(PlayerId(id)),
+                    reverse.role_of(PlayerId(id)),
+                    "seat {id} diverged at seed {seed}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn pinned_assignment_wins_over_the_shuffle() {
+        for seed in 0..10 {
+            let state = three_seats().assign(PlayerId(2), Role::Werewolf).seed(seed).build().unwrap();
+            assert_eq!(state.role_of(PlayerId(2)), Some(Role::Werewolf));
+            assert_eq!(state.role_of(PlayerId(0)), Some(Role::Villager));
+            assert_eq!(state.role_of(PlayerId(1)), Some(Role::Villager));
+        }
+    }
+
+    #[test]
+    fn rejects_duplicate_seats() {
+        let (id, p) = seat(PlayerId(0));
+        let err = three_seats().player(id, p).role(Role::Villager, 1).build().unwrap_err();
+        assert_eq!(err, BuildError::DuplicatePlayer(PlayerId(0)));
+    }
+
+    #[test]
+    fn rejects_roster_role_mismatch() {
+        let err = three_seats().role(Role::Seer, 1).build().unwrap_err();
+        assert_eq!(err, BuildError::PlayerRoleMismatch { players: 3, roles: 4 });
+    }
+
+    #[test]
+    fn rejects_pinning_an_unknown_player() {
+        let err = three_seats().assign(PlayerId(9), Role::Werewolf).build().unwrap_err();
+        assert_eq!(err, BuildError::PinnedToUnknownPlayer(PlayerId(9)));
+    }
+
+    #[test]
+    fn rejects_pinning_more_than_the_multiset_provides() {
+        let err = three_seats()
+            .assign(PlayerId(0), Role::Werewolf)
+            .assign(PlayerId(1), Role::Werewolf)
+            .build()
+            .unwrap_err();
+        assert_eq!(err, BuildError::PinnedRoleUnavailable { role: Role::Werewolf });
+    }
+
+    #[test]
+    fn a_minion_starts_knowing_the_killing_wolves() {
+        let mut b = GameBuilder::new()
+            .role(Role::Werewolf, 1)
+            .role(Role::AlphaWerewolf, 1)
+            .role(Role::Minion, 1)
+            .role(Role::Villager, 2)
+            .assign(PlayerId(0), Role::Minion)
+            .assign(PlayerId(1), Role::Werewolf)
+            .assign(PlayerId(2), Role::AlphaWerewolf);
+        for id in 0..5 {
+            let (id, p) = seat(PlayerId(id));
+            b = b.player(id, p);
+        }
+        let state = b.build().unwrap();
+        let known: Vec<_> = state
+            .knowledge_of(PlayerId(0))
+            .investigations
+            .iter()
+            .map(|i| (i.target, i.revealed_alignment))
+            .collect();
+        assert_eq!(
+            known,
+            vec![
+                (PlayerId(1), crate::roles::Alignment::Wolf),
+                (PlayerId(2), crate::roles::Alignment::Wolf)
+            ]
+        );
+        // The wolves do not learn the Minion in return.
+        assert!(state.knowledge_of(PlayerId(1)).investigations.is_empty());
+    }
+
+    #[test]
+    fn config_supplies_roles_phase_and_rules() {
+        let config = GameConfig {
+            first_phase: crate::config::FirstPhase::Day,
+            death_reveal: crate::game::death::DeathReveal::Hidden,
+            ..GameConfig::default()
+        };
+        let mut b = GameBuilder::new().config(config);
+        for id in 0..7 {
+            let (id, p) = seat(PlayerId(id));
+            b = b.player(id, p);
+        }
+        let (state, players) = b.build_with_players().unwrap();
+        assert_eq!(state.phase(), Phase::Day);
+        assert_eq!(players.len(), 7);
+        assert_eq!(state.revealed_role_of(PlayerId(0)), None);
+    }
+}
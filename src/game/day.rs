@@ -0,0 +1,801 @@
+//! The day discussion loop.
+//!
+//! Discussion runs a configurable number of speaking rounds in seat order.
+//! Each player has a token budget for the whole game; once spent, their
+//! further speeches are skipped (and the skip is logged) so a rambling
+//! model can't blow up the bill.
+
+use std::collections::HashMap;
+
+use crate::game::event::GameEventKind;
+use crate::game::state::{ContextCache, GameState, PlayerId};
+use crate::game::timeout::{
+    ActionKind, FallbackReason, TurnPolicy, timed_accuse, timed_speak, timed_speak_observed,
+};
+use crate::llm::dedup::DedupFilter;
+use crate::llm::parse::RefusalDetector;
+use crate::player::Player;
+
+/// A callback fed each streamed speech fragment as it is produced,
+/// together with the speaking player. For live spectator UIs; the final
+/// assembled speech still goes to the log as usual.
+pub type SpeechObserver = dyn Fn(PlayerId, &str) + Send + Sync;
+
+/// The order living players speak in during discussion.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize)]
+pub enum SpeakingOrder {
+    /// Seat order, every day.
+    #[default]
+    Clockwise,
+    /// A fresh shuffle every day, drawn from the seeded RNG so replays
+    /// are stable.
+    RandomEachDay,
+    /// Seat order, but starting from the seat after the most recent
+    /// death. Falls back to seat order while nobody has died.
+    StartFromLastDeath,
+}
+
+/// How the number of discussion rounds is decided.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum DiscussionPolicy {
+    /// Exactly [`DiscussionSettings::rounds`] rounds, always.
+    #[default]
+    Fixed,
+    /// Between `min_rounds` and `max_rounds` rounds: after the minimum,
+    /// another round starts only while the game's tracked cost is under
+    /// `budget` dollars and the previous round still contributed new
+    /// information (at least one non-trivial, not-yet-heard speech). The
+    /// stop decision is logged as [`DiscussionEnded`];
+    /// [`DiscussionSettings::rounds`] is ignored.
+    ///
+    /// [`DiscussionEnded`]: GameEventKind::DiscussionEnded
+    Adaptive { max_rounds: u32, min_rounds: u32, budget: f64 },
+}
+
+/// Why an adaptive discussion stopped; carried by
+/// [`DiscussionEnded`](GameEventKind::DiscussionEnded).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum DiscussionEndReason {
+    /// The round ceiling was reached with budget and novelty to spare.
+    RoundsExhausted,
+    /// The tracked cost reached the adaptive budget.
+    BudgetTight,
+    /// The last round added nothing new: every speech was trivially short
+    /// or a repeat of something already said today.
+    NoNovelty,
+}
+
+/// Speeches under this estimated-token length (≈16 characters) count as
+/// trivially short for the adaptive novelty check.
+const NOVELTY_MIN_TOKENS: u32 = 4;
+
+/// Discussion-phase knobs.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DiscussionSettings {
+    /// Speaking rounds per day (under [`DiscussionPolicy::Fixed`]).
+    pub rounds: u32,
+    /// How the round count is decided; [`DiscussionPolicy::Adaptive`]
+    /// overrides `rounds`.
+    pub policy: DiscussionPolicy,
+    /// Estimated-token budget per player per game; `None` is unlimited.
+    pub token_budget: Option<u32>,
+    /// Hard dollar ceiling for the whole game; once the tracked cost
+    /// passes it, discussion ends early and the vote is forced.
+    pub max_cost: Option<f64>,
+    /// Accusations allowed per day in the pre-vote sub-phase; `None`
+    /// disables the sub-phase entirely.
+    pub accusations: Option<u32>,
+    /// The order players speak in.
+    pub order: SpeakingOrder,
+    /// Refusal detection over raw speech replies. `None` (the default)
+    /// keeps the old behavior: an empty reply is skipped without a trace.
+    /// With a detector installed, an empty or refusal-matching reply is
+    /// logged as [`PlayerRefused`] — raw text included — and the seat
+    /// stays silent for the turn.
+    ///
+    /// [`PlayerRefused`]: GameEventKind::PlayerRefused
+    pub refusals: Option<RefusalDetector>,
+    /// Near-duplicate suppression over recorded speeches. `None` (the
+    /// default) records every speech verbatim; with a filter installed, a
+    /// speech too similar to the speaker's recent ones is replaced in the
+    /// transcript by a short placeholder. The original reply is still
+    /// charged against the token budget — the filter only spares readers,
+    /// not the bill.
+    pub dedup: Option<DedupFilter>,
+}
+
+impl Default for DiscussionSettings {
+    fn default() -> Self {
+        Self {
+            rounds: 1,
+            policy: DiscussionPolicy::default(),
+            token_budget: None,
+            max_cost: None,
+            accusations: None,
+            order: SpeakingOrder::default(),
+            refusals: None,
+            dedup: None,
+        }
+    }
+}
+
+/// A cheap token estimate (≈4 characters per token) used for budgeting
+/// when the provider doesn't report real usage.
+pub fn estimate_tokens(text: &str) -> u32 {
+    (text.chars().count() as u32 / 4).max(1)
+}
+
+/// Runs one day's discussion: `rounds` passes over the living players in
+/// seat order, each asked to speak with a context containing today's
+/// discussion plus summaries of prior days.
+pub async fn run_discussion(
+    state: &mut GameState,
+    players: &HashMap<PlayerId, Box<dyn Player>>,
+    policy: &TurnPolicy,
+    settings: &DiscussionSettings,
+) {
+    run_discussion_observed(state, players, policy, settings, None).await;
+}
+
+/// [`run_discussion`] with an optional [`SpeechObserver`] that sees each
+/// speech fragment as it streams in.
+pub async fn run_discussion_observed(
+    state: &mut GameState,
+    players: &HashMap<PlayerId, Box<dyn Player>>,
+    policy: &TurnPolicy,
+    settings: &DiscussionSettings,
+    observer: Option<&SpeechObserver>,
+) {
+    let order = speaking_order(state, settings.order);
+    state.record(GameEventKind::SpeakingOrder { order: order.clone() });
+    let (min_rounds, max_rounds) = match settings.policy {
+        DiscussionPolicy::Fixed => (settings.rounds, settings.rounds),
+        DiscussionPolicy::Adaptive { max_rounds, min_rounds, .. } => (min_rounds, max_rounds),
+    };
+    // Everything said today, for the adaptive repetition check.
+    let mut heard: std::collections::HashSet<String> = std::collections::HashSet::new();
+    let mut last_round_novel = true;
+    let mut ended = None;
+    let mut cache = ContextCache::new();
+    for round in 0..max_rounds {
+        if round >= min_rounds
+            && let DiscussionPolicy::Adaptive { budget, .. } = settings.policy
+        {
+            if state.cost().total_cost() >= budget {
+                ended = Some(DiscussionEndReason::BudgetTight);
+                break;
+            }
+            if !last_round_novel {
+                ended = Some(DiscussionEndReason::NoNovelty);
+                break;
+            }
+        }
+        last_round_novel = false;
+        for &id in &order {
+            if !state.is_alive(id) {
+                continue;
+            }
+            if let Some(max_cost) = settings.max_cost {
+                let cost = state.cost().total_cost();
+                if cost > max_cost {
+                    state.record(GameEventKind::BudgetExceeded { cost, max_cost });
+                    return;
+                }
+            }
+            if let Some(budget) = settings.token_budget {
+                if state.tokens_used(id) >= budget {
+                    state.record(GameEventKind::FallbackTriggered {
+                        player: id,
+                        action: ActionKind::Speech,
+                        reason: FallbackReason::TokenBudget,
+                    });
+                    continue;
+                }
+            }
+            let Some(player) = players.get(&id) else { continue };
+            let ctx = cache.context_for(state, id);
+            let text = match observer {
+                Some(observer) => {
+                    timed_speak_observed(player.as_ref(), &ctx, state, policy, observer).await
+                }
+                None => timed_speak(player.as_ref(), &ctx, state, policy).await,
+            };
+            if let Some(detector) = &settings.refusals
+                && detector.is_refusal(&text)
+            {
+                // A refusal is not a speech: log it verbatim and fall
+                // back to silence. The reply still cost tokens.
+                state.add_tokens(id, estimate_tokens(&text));
+                state.record(GameEventKind::PlayerRefused { player: id, text });
+                continue;
+            }
+            if text.is_empty() {
+                continue;
+            }
+            state.add_tokens(id, estimate_tokens(&text));
+            // Tokens are charged on the real reply; only the transcript
+            // sees the placeholder for a near-duplicate.
+            let text = match &settings.dedup {
+                Some(filter) => filter.rewrite(id, text).await,
+                None => text,
+            };
+            if estimate_tokens(&text) >= NOVELTY_MIN_TOKENS && heard.insert(text.clone()) {
+                last_round_novel = true;
+            }
+            state.record(GameEventKind::PlayerSpoke { player: id, text });
+        }
+    }
+    if matches!(settings.policy, DiscussionPolicy::Adaptive { .. }) {
+        state.record(GameEventKind::DiscussionEnded {
+            reason: ended.unwrap_or(DiscussionEndReason::RoundsExhausted),
+        });
+    }
+    if let Some(cap) = settings.accusations {
+        run_accusations(state, players, policy, cap).await;
+    }
+}
+
+/// Runs one round of graveyard chat: every dead player with a seat at the
+/// table may speak into the graveyard-only channel, in seat order. A
+/// player who died this very phase is already dead by now and speaks too.
+///
+/// Remarks land in the log as [`GraveyardChat`] events, which never reach
+/// a living player's view.
+///
+/// [`GraveyardChat`]: GameEventKind::GraveyardChat
+pub async fn run_graveyard(
+    state: &mut GameState,
+    players: &HashMap<PlayerId, Box<dyn Player>>,
+    policy: &TurnPolicy,
+) {
+    let dead: Vec<PlayerId> = state
+        .players()
+        .iter()
+        .filter(|p| !p.alive)
+        .map(|p| p.id)
+        .collect();
+    let mut cache = ContextCache::new();
+    for id in dead {
+        let Some(player) = players.get(&id) else { continue };
+        let ctx = cache.context_for(state, id);
+        let text = timed_speak(player.as_ref(), &ctx, state, policy).await;
+        if text.is_empty() {
+            continue;
+        }
+        state.record(GameEventKind::GraveyardChat { player: id, text });
+    }
+}
+
+/// Collects a final public statement from each player in `dying`, in
+/// order, recorded as a [`LastWords`] event. The runner calls this before
+/// a lynch is applied — the victim is still alive and sees the full vote —
+/// and at dawn for night victims, right after resolution. Empty replies
+/// are skipped.
+///
+/// [`LastWords`]: GameEventKind::LastWords
+pub async fn run_last_words(
+    state: &mut GameState,
+    players: &HashMap<PlayerId, Box<dyn Player>>,
+    policy: &TurnPolicy,
+    dying: &[PlayerId],
+) {
+    for &id in dying {
+        let Some(player) = players.get(&id) else { continue };
+        let ctx = state.context_for(id);
+        let text = timed_speak(player.as_ref(), &ctx, state, policy).await;
+        if text.is_empty() {
+            continue;
+        }
+        state.record(GameEventKind::LastWords { player: id, text });
+    }
+}
+
+/// Today's speaking order over the living players.
+fn speaking_order(state: &mut GameState, order: SpeakingOrder) -> Vec<PlayerId> {
+    let mut alive = state.alive_players();
+    match order {
+        SpeakingOrder::Clockwise => {}
+        SpeakingOrder::RandomEachDay => state.rng_mut().shuffle(&mut alive),
+        SpeakingOrder::StartFromLastDeath => {
+            let last_death = state.log().iter().rev().find_map(|e| match e.kind {
+                GameEventKind::PlayerDied { player, .. } => Some(player),
+                _ => None,
+            });
+            if let Some(dead) = last_death {
+                // First living seat after the dead one, wrapping around.
+                let start = alive.iter().position(|&id| id > dead).unwrap_or(0);
+                alive.rotate_left(start);
+            }
+        }
+    }
+    alive
+}
+
+/// Runs the pre-vote accusation sub-phase: living players may formally
+/// accuse in seat order until `cap` accusations are on record, then every
+/// accused player gets a guaranteed defense speech. The defense context
+/// contains the accusations verbatim, so the accused can answer the
+/// specific case against them.
+pub async fn run_accusations(
+    state: &mut GameState,
+    players: &HashMap<PlayerId, Box<dyn Player>>,
+    policy: &TurnPolicy,
+    cap: u32,
+) {
+    let mut accused_order: Vec<PlayerId> = Vec::new();
+    let mut made = 0u32;
+    let mut cache = ContextCache::new();
+    for id in state.alive_players() {
+        if made >= cap {
+            break;
+        }
+        let Some(player) = players.get(&id) else { continue };
+        let ctx = cache.context_for(state, id);
+        let Some((accused, text)) = timed_accuse(player.as_ref(), &ctx, state, policy).await
+        else {
+            continue;
+        };
+        // Accusing the dead or oneself is meaningless; drop it.
+        if !state.is_alive(accused) || accused == id {
+            continue;
+        }
+        state.record(GameEventKind::Accusation { accuser: id, accused, text });
+        if !accused_order.contains(&accused) {
+            accused_order.push(accused);
+        }
+        made += 1;
+    }
+    for accused in accused_order {
+        let Some(player) = players.get(&accused) else { continue };
+        let ctx = cache.context_for(state, accused);
+        let text = timed_speak(player.as_ref(), &ctx, state, policy).await;
+        if text.is_empty() {
+            continue;
+        }
+        state.add_tokens(accused, estimate_tokens(&text));
+        state.record(GameEventKind::Defense { player: accused, text });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::state::Phase;
+    use crate::player::ScriptedPlayer;
+    use crate::roles::Role;
+
+    fn setup(scripts: Vec<ScriptedPlayer>) -> (GameState, HashMap<PlayerId, Box<dyn Player>>) {
+        let n = scripts.len() as u32;
+        let mut state = GameState::new(0..n, Phase::Day, 0);
+        state.assign_role(PlayerId(0), Role::Werewolf);
+        for id in 1..n {
+            state.assign_role(PlayerId(id), Role::Villager);
+        }
+        let players = scripts
+            .into_iter()
+            .enumerate()
+            .map(|(id, p)| (PlayerId(id as u32), Box::new(p) as Box<dyn Player>))
+            .collect();
+        (state, players)
+    }
+
+    #[tokio::test]
+    async fn speaks_in_seat_order_for_each_round() {
+        let (mut state, players) = setup(vec![
+            ScriptedPlayer::new().will_say("a1").will_say("a2"),
+            ScriptedPlayer::new().will_say("b1").will_say("b2"),
+        ]);
+        let settings = DiscussionSettings { rounds: 2, ..Default::default() };
+        run_discussion(&mut state, &players, &TurnPolicy::default(), &settings).await;
+        let speeches: Vec<String> = state
+            .log()
+            .iter()
+            .filter_map(|e| match &e.kind {
+                GameEventKind::PlayerSpoke { text, .. } => Some(text.clone()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(speeches, vec!["a1", "b1", "a2", "b2"]);
+    }
+
+    #[tokio::test]
+    async fn exhausted_budget_skips_and_logs() {
+        let longwinded = "blah ".repeat(100);
+        let (mut state, players) = setup(vec![
+            ScriptedPlayer::new().will_say(longwinded).will_say("more"),
+            ScriptedPlayer::new().will_say("short").will_say("again"),
+        ]);
+        let settings =
+            DiscussionSettings { rounds: 2, token_budget: Some(50), ..Default::default() };
+        run_discussion(&mut state, &players, &TurnPolicy::default(), &settings).await;
+
+        // Player 0 blew the budget in round 1, so round 2 skips them.
+        let p0_speeches = state
+            .log()
+            .iter()
+            .filter(|e| matches!(e.kind, GameEventKind::PlayerSpoke { player: PlayerId(0), .. }))
+            .count();
+        assert_eq!(p0_speeches, 1);
+        assert!(state.log().iter().any(|e| matches!(
+            e.kind,
+            GameEventKind::FallbackTriggered {
+                player: PlayerId(0),
+                action: ActionKind::Speech,
+                reason: FallbackReason::TokenBudget,
+            }
+        )));
+        // Player 1 stayed under budget and spoke twice.
+        let p1_speeches = state
+            .log()
+            .iter()
+            .filter(|e| matches!(e.kind, GameEventKind::PlayerSpoke { player: PlayerId(1), .. }))
+            .count();
+        assert_eq!(p1_speeches, 2);
+    }
+
+    fn end_reason(state: &GameState) -> Option<DiscussionEndReason> {
+        state.log().iter().find_map(|e| match e.kind {
+            GameEventKind::DiscussionEnded { reason } => Some(reason),
+            _ => None,
+        })
+    }
+
+    #[tokio::test]
+    async fn a_tight_budget_caps_adaptive_discussion_at_min_rounds() {
+        let long = |seat: u32| format!("Seat {seat} has been dodging every question today.");
+        let (mut state, players) = setup(vec![
+            ScriptedPlayer::new().will_say(long(1)).will_say(long(2)).will_say(long(3)),
+            ScriptedPlayer::new().will_say(long(0)).will_say(long(2)).will_say(long(0)),
+        ]);
+        // Budget already spent (0 >= 0): the discussion stops as soon as
+        // the minimum is in.
+        let settings = DiscussionSettings {
+            policy: DiscussionPolicy::Adaptive { max_rounds: 3, min_rounds: 1, budget: 0.0 },
+            ..Default::default()
+        };
+        run_discussion(&mut state, &players, &TurnPolicy::default(), &settings).await;
+
+        let speeches = state
+            .log()
+            .iter()
+            .filter(|e| matches!(e.kind, GameEventKind::PlayerSpoke { .. }))
+            .count();
+        assert_eq!(speeches, 2); // one round of two seats
+        assert_eq!(end_reason(&state), Some(DiscussionEndReason::BudgetTight));
+    }
+
+    #[tokio::test]
+    async fn repetitive_speech_ends_adaptive_discussion_early() {
+        let stuck = "I still think Seat 0 is the wolf, same as before.";
+        let (mut state, players) = setup(vec![
+            ScriptedPlayer::new().will_say(stuck).will_say(stuck).will_say(stuck),
+            ScriptedPlayer::new().will_say(stuck).will_say(stuck).will_say(stuck),
+        ]);
+        let settings = DiscussionSettings {
+            policy: DiscussionPolicy::Adaptive { max_rounds: 4, min_rounds: 1, budget: 100.0 },
+            ..Default::default()
+        };
+        run_discussion(&mut state, &players, &TurnPolicy::default(), &settings).await;
+
+        // Round 1 is fresh; round 2 is verbatim repetition, so round 3
+        // never starts.
+        let speeches = state
+            .log()
+            .iter()
+            .filter(|e| matches!(e.kind, GameEventKind::PlayerSpoke { .. }))
+            .count();
+        assert_eq!(speeches, 4);
+        assert_eq!(end_reason(&state), Some(DiscussionEndReason::NoNovelty));
+    }
+
+    #[tokio::test]
+    async fn fresh_content_carries_adaptive_discussion_to_the_ceiling() {
+        let mut a = ScriptedPlayer::new();
+        let mut b = ScriptedPlayer::new();
+        for round in 0..3 {
+            a = a.will_say(format!("Round {round}: seat 1 contradicted their day-1 story."));
+            b = b.will_say(format!("Round {round}: seat 0 keeps deflecting onto me."));
+        }
+        let (mut state, players) = setup(vec![a, b]);
+        let settings = DiscussionSettings {
+            policy: DiscussionPolicy::Adaptive { max_rounds: 3, min_rounds: 1, budget: 100.0 },
+            ..Default::default()
+        };
+        run_discussion(&mut state, &players, &TurnPolicy::default(), &settings).await;
+
+        let speeches = state
+            .log()
+            .iter()
+            .filter(|e| matches!(e.kind, GameEventKind::PlayerSpoke { .. }))
+            .count();
+        assert_eq!(speeches, 6);
+        assert_eq!(end_reason(&state), Some(DiscussionEndReason::RoundsExhausted));
+    }
+
+    #[tokio::test]
+    async fn refusals_are_logged_verbatim_and_fall_back_to_silence() {
+        let (mut state, players) = setup(vec![
+            ScriptedPlayer::new().will_say("I cannot take part in this."),
+            ScriptedPlayer::new(), // an empty reply is a refusal too
+            ScriptedPlayer::new().will_say("Player 0 is dodging."),
+        ]);
+        let settings = DiscussionSettings {
+            refusals: Some(RefusalDetector::default()),
+            ..Default::default()
+        };
+        run_discussion(&mut state, &players, &TurnPolicy::default(), &settings).await;
+
+        assert!(state.log().iter().any(|e| matches!(
+            &e.kind,
+            GameEventKind::PlayerRefused { player: PlayerId(0), text }
+                if text == "I cannot take part in this."
+        )));
+        assert!(state.log().iter().any(|e| matches!(
+            &e.kind,
+            GameEventKind::PlayerRefused { player: PlayerId(1), text } if text.is_empty()
+        )));
+        // Refused seats stay silent; the normal reply goes through.
+        let speeches: Vec<PlayerId> = state
+            .log()
+            .iter()
+            .filter_map(|e| match e.kind {
+                GameEventKind::PlayerSpoke { player, .. } => Some(player),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(speeches, vec![PlayerId(2)]);
+    }
+
+    #[tokio::test]
+    async fn a_repeated_speech_is_collapsed_but_still_charged() {
+        use crate::llm::dedup::{DedupFilter, HashingEmbedder};
+
+        let (mut state, players) = setup(vec![
+            ScriptedPlayer::new()
+                .will_say("Player 1 is a wolf, their vote made no sense at all.")
+                .will_say("Player 1 is a wolf; their vote made no sense at all!"),
+            ScriptedPlayer::new().will_say("First point.").will_say("A second, new point."),
+        ]);
+        let settings = DiscussionSettings {
+            rounds: 2,
+            dedup: Some(DedupFilter::new(
+                std::sync::Arc::new(HashingEmbedder::default()),
+                0.9,
+                5,
+            )),
+            ..Default::default()
+        };
+        run_discussion(&mut state, &players, &TurnPolicy::default(), &settings).await;
+
+        let p0_speeches: Vec<String> = state
+            .log()
+            .iter()
+            .filter_map(|e| match &e.kind {
+                GameEventKind::PlayerSpoke { player: PlayerId(0), text } => Some(text.clone()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(p0_speeches[0], "Player 1 is a wolf, their vote made no sense at all.");
+        assert_eq!(p0_speeches[1], "(Player 0 reiterates their point.)");
+        // The repeat was still the model's real reply: both turns charge
+        // full-length tokens, not the placeholder's.
+        assert!(state.tokens_used(PlayerId(0)) >= 2 * estimate_tokens("Player 1 is a wolf"));
+        // The other seat's distinct speeches pass through untouched.
+        assert!(state.log().iter().any(|e| matches!(
+            &e.kind,
+            GameEventKind::PlayerSpoke { player: PlayerId(1), text } if text == "A second, new point."
+        )));
+    }
+
+    #[tokio::test]
+    async fn without_a_detector_an_empty_reply_leaves_no_trace() {
+        let (mut state, players) =
+            setup(vec![ScriptedPlayer::new(), ScriptedPlayer::new().will_say("hello")]);
+        run_discussion(
+            &mut state,
+            &players,
+            &TurnPolicy::default(),
+            &DiscussionSettings::default(),
+        )
+        .await;
+        assert!(!state
+            .log()
+            .iter()
+            .any(|e| matches!(e.kind, GameEventKind::PlayerRefused { .. })));
+    }
+
+    #[tokio::test]
+    async fn observer_sees_streamed_speech() {
+        use std::sync::{Arc, Mutex};
+
+        let (mut state, players) =
+            setup(vec![ScriptedPlayer::new().will_say("watch me"), ScriptedPlayer::new()]);
+        let seen: Arc<Mutex<Vec<(PlayerId, String)>>> = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&seen);
+        let observer = move |id: PlayerId, chunk: &str| {
+            sink.lock().unwrap().push((id, chunk.to_string()));
+        };
+        run_discussion_observed(
+            &mut state,
+            &players,
+            &TurnPolicy::default(),
+            &DiscussionSettings::default(),
+            Some(&observer),
+        )
+        .await;
+        assert_eq!(*seen.lock().unwrap(), vec![(PlayerId(0), "watch me".to_string())]);
+        // The assembled speech still reached the log.
+        assert!(state.log().iter().any(|e| matches!(
+            &e.kind,
+            GameEventKind::PlayerSpoke { player: PlayerId(0), text } if text == "watch me"
+        )));
+    }
+
+    #[tokio::test]
+    async fn accused_player_defends_against_the_stated_case() {
+        let (mut state, players) = setup(vec![
+            ScriptedPlayer::new().will_accuse(PlayerId(1), "You dodged every question."),
+            ScriptedPlayer::new().will_say("I answered plenty."),
+            ScriptedPlayer::new(),
+        ]);
+        run_accusations(&mut state, &players, &TurnPolicy::default(), 3).await;
+
+        assert!(state.log().iter().any(|e| matches!(
+            &e.kind,
+            GameEventKind::Accusation { accuser: PlayerId(0), accused: PlayerId(1), text }
+                if text == "You dodged every question."
+        )));
+        assert!(state.log().iter().any(|e| matches!(
+            &e.kind,
+            GameEventKind::Defense { player: PlayerId(1), text } if text == "I answered plenty."
+        )));
+        // The accusation text reached the accused's context before the
+        // defense was recorded.
+        let ctx = state.context_for(PlayerId(1));
+        assert!(ctx
+            .public_log
+            .iter()
+            .any(|line| line.contains("accuses Player 1") && line.contains("dodged")));
+    }
+
+    #[tokio::test]
+    async fn accusation_cap_is_enforced() {
+        let (mut state, players) = setup(vec![
+            ScriptedPlayer::new().will_accuse(PlayerId(2), "first"),
+            ScriptedPlayer::new().will_accuse(PlayerId(2), "second"),
+            ScriptedPlayer::new(),
+        ]);
+        run_accusations(&mut state, &players, &TurnPolicy::default(), 1).await;
+        let accusations = state
+            .log()
+            .iter()
+            .filter(|e| matches!(e.kind, GameEventKind::Accusation { .. }))
+            .count();
+        assert_eq!(accusations, 1);
+    }
+
+    #[tokio::test]
+    async fn discussion_skips_the_sub_phase_unless_enabled() {
+        let (mut state, players) = setup(vec![
+            ScriptedPlayer::new().will_accuse(PlayerId(1), "wolf!"),
+            ScriptedPlayer::new(),
+        ]);
+        run_discussion(
+            &mut state,
+            &players,
+            &TurnPolicy::default(),
+            &DiscussionSettings::default(),
+        )
+        .await;
+        assert!(!state
+            .log()
+            .iter()
+            .any(|e| matches!(e.kind, GameEventKind::Accusation { .. })));
+    }
+
+    fn recorded_order(state: &GameState) -> Vec<Vec<PlayerId>> {
+        state
+            .log()
+            .iter()
+            .filter_map(|e| match &e.kind {
+                GameEventKind::SpeakingOrder { order } => Some(order.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn random_order_is_seed_deterministic() {
+        let settings =
+            DiscussionSettings { order: SpeakingOrder::RandomEachDay, ..Default::default() };
+        let mut orders = Vec::new();
+        for _ in 0..2 {
+            let (mut state, players) = setup(vec![
+                ScriptedPlayer::new().will_say("a").will_say("a"),
+                ScriptedPlayer::new().will_say("b").will_say("b"),
+                ScriptedPlayer::new().will_say("c").will_say("c"),
+                ScriptedPlayer::new().will_say("d").will_say("d"),
+            ]);
+            // Two days of discussion on the same seeded state.
+            run_discussion(&mut state, &players, &TurnPolicy::default(), &settings).await;
+            run_discussion(&mut state, &players, &TurnPolicy::default(), &settings).await;
+            orders.push(recorded_order(&state));
+        }
+        assert_eq!(orders[0], orders[1]);
+        assert_eq!(orders[0].len(), 2);
+        // Each day's order is a permutation of the living players.
+        for day in &orders[0] {
+            let mut sorted = day.clone();
+            sorted.sort_unstable();
+            assert_eq!(sorted, vec![PlayerId(0), PlayerId(1), PlayerId(2), PlayerId(3)]);
+        }
+    }
+
+    #[tokio::test]
+    async fn order_starts_after_the_most_recent_death() {
+        let (mut state, players) = setup(vec![
+            ScriptedPlayer::new().will_say("a"),
+            ScriptedPlayer::new().will_say("b"),
+            ScriptedPlayer::new(),
+            ScriptedPlayer::new().will_say("d"),
+        ]);
+        state.kill(PlayerId(2));
+        state.record(GameEventKind::PlayerDied {
+            player: PlayerId(2),
+            cause: crate::game::night::DeathCause::WolfKill,
+            role: None,
+            alignment: None,
+        });
+        let settings = DiscussionSettings {
+            order: SpeakingOrder::StartFromLastDeath,
+            ..Default::default()
+        };
+        run_discussion(&mut state, &players, &TurnPolicy::default(), &settings).await;
+        assert_eq!(recorded_order(&state), vec![vec![PlayerId(3), PlayerId(0), PlayerId(1)]]);
+    }
+
+    #[tokio::test]
+    async fn blown_cost_ceiling_short_circuits_discussion() {
+        use crate::llm::TokenUsage;
+        use crate::llm::cost::{CostTracker, ModelPrice};
+
+        let (mut state, players) = setup(vec![
+            ScriptedPlayer::new().will_say("never spoken"),
+            ScriptedPlayer::new().will_say("never spoken either"),
+        ]);
+        *state.cost_mut() = CostTracker::new().with_price("pricey", ModelPrice {
+            prompt_per_million: 10.0,
+            completion_per_million: 30.0,
+        });
+        state.cost_mut().record(PlayerId(0), "pricey", TokenUsage {
+            prompt_tokens: 1_000_000,
+            completion_tokens: 0,
+            total_tokens: 1_000_000,
+        });
+        let settings =
+            DiscussionSettings { max_cost: Some(0.05), ..Default::default() };
+        run_discussion(&mut state, &players, &TurnPolicy::default(), &settings).await;
+
+        // Nobody got to speak; the log explains why.
+        assert!(!state
+            .log()
+            .iter()
+            .any(|e| matches!(e.kind, GameEventKind::PlayerSpoke { .. })));
+        assert!(state.log().iter().any(|e| matches!(
+            e.kind,
+            GameEventKind::BudgetExceeded { max_cost, .. } if max_cost == 0.05
+        )));
+        // Players see the running total and can be told to keep it short.
+        assert_eq!(state.context_for(PlayerId(1)).cost_so_far, 10.0);
+    }
+
+    #[tokio::test]
+    async fn context_exposes_running_token_total() {
+        let (mut state, players) =
+            setup(vec![ScriptedPlayer::new().will_say("x".repeat(40)), ScriptedPlayer::new()]);
+        let settings = DiscussionSettings::default();
+        run_discussion(&mut state, &players, &TurnPolicy::default(), &settings).await;
+        assert_eq!(state.tokens_used(PlayerId(0)), 10);
+        assert_eq!(state.context_for(PlayerId(0)).tokens_used, 10);
+    }
+}
@@ -0,0 +1,355 @@
+//! Death handling: the single death funnel and the Hunter's dying shot.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::game::event::GameEventKind;
+use crate::game::night::DeathCause;
+use crate::game::state::{GameState, PlayerId, Relationship};
+use crate::player::Player;
+use crate::roles::Role;
+
+/// How much of a dead player's role becomes public.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum DeathReveal {
+    /// The exact role is announced ("They were the Seer").
+    #[default]
+    Full,
+    /// Only the alignment is announced ("They were Town") — the common
+    /// middle ground between full reveal and no reveal.
+    AlignmentOnly,
+    /// Nothing is announced.
+    Hidden,
+}
+
+/// Applies one death: marks the player dead, appends the `PlayerDied`
+/// event (role revealed or hidden per the table rules), and immediately
+/// takes any Lover down with it. Returns every death applied, in order —
+/// the requested one first, grief deaths after — so callers can feed the
+/// full list into Hunter-shot resolution.
+///
+/// Every death source — wolf kill, poison, day vote, Hunter shot, lover
+/// grief — funnels through here, one death at a time, in a deterministic
+/// order: night resolution applies the wolf kill before poison deaths,
+/// and Hunter shots resolve after the deaths that triggered them but
+/// before the phase boundary declares the game over. A Hunter who dies
+/// alongside the last wolf therefore still takes their shot, and the
+/// shot's victim counts toward the final outcome.
+pub fn apply_death(
+    state: &mut GameState,
+    id: PlayerId,
+    cause: DeathCause,
+) -> Vec<(PlayerId, DeathCause)> {
+    state.kill(id);
+    state.record(GameEventKind::PlayerDied {
+        player: id,
+        cause,
+        role: state.revealed_role_of(id),
+        alignment: state.revealed_alignment_of(id),
+    });
+    let mut deaths = vec![(id, cause)];
+    for partner in state.partners_of(id, Relationship::Lovers) {
+        if state.is_alive(partner) {
+            deaths.extend(apply_death(state, partner, DeathCause::LoverGrief));
+        }
+    }
+    deaths
+}
+
+/// Rule variants for dying-shot handling.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct HunterRules {
+    /// Whether a Hunter killed by Witch poison still gets to shoot.
+    /// Classic rules say no — the poison is too quick.
+    pub shoot_on_poison: bool,
+}
+
+/// Gives every Hunter among `deaths` their dying shot.
+///
+/// The shot is requested from the (now dead) player via
+/// [`Player::hunter_shot`] and resolves immediately. A shot can kill
+/// another Hunter, so the chain is processed as a queue until no new
+/// Hunter deaths remain. Each shot is logged as its own
+/// [`GameEventKind::HunterShot`] (plus the victim's `PlayerDied`), and the
+/// caller should re-check win conditions afterwards — a shot can end the
+/// game.
+///
+/// Returns all additional deaths the shots caused, in resolution order.
+pub async fn resolve_hunter_shots(
+    state: &mut GameState,
+    players: &HashMap<PlayerId, Box<dyn Player>>,
+    deaths: &[(PlayerId, DeathCause)],
+    rules: &HunterRules,
+) -> Vec<(PlayerId, DeathCause)> {
+    let mut queue: Vec<(PlayerId, DeathCause)> = deaths.to_vec();
+    let mut extra = Vec::new();
+
+    while let Some((dead, cause)) = queue.first().copied() {
+        queue.remove(0);
+        if state.role_of(dead) != Some(Role::Hunter) {
+            continue;
+        }
+        if cause == DeathCause::Poison && !rules.shoot_on_poison {
+            continue;
+        }
+        let Some(player) = players.get(&dead) else { continue };
+        let ctx = state.context_for(dead);
+        let Some(target) = player.hunter_shot(&ctx).await else { continue };
+        if !state.is_alive(target) || target == dead {
+            continue;
+        }
+        state.record(GameEventKind::HunterShot { hunter: dead, target });
+        let deaths = apply_death(state, target, DeathCause::HunterShot);
+        extra.extend(deaths.iter().copied());
+        // The victim (or a Lover who died of grief alongside them) may be
+        // another Hunter: keep the chain going.
+        queue.extend(deaths);
+    }
+
+    extra
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::state::Phase;
+    use crate::game::win::check_win;
+    use crate::player::ScriptedPlayer;
+    use crate::roles::Alignment;
+
+    fn boxed(p: ScriptedPlayer) -> Box<dyn Player> {
+        Box::new(p)
+    }
+
+    /// 0: Hunter, 1: Werewolf, 2/3: Villagers.
+    fn setup() -> (GameState, HashMap<PlayerId, Box<dyn Player>>) {
+        let mut state = GameState::new(0..4, Phase::Night, 0);
+        state.assign_role(PlayerId(0), Role::Hunter);
+        state.assign_role(PlayerId(1), Role::Werewolf);
+        state.assign_role(PlayerId(2), Role::Villager);
+        state.assign_role(PlayerId(3), Role::Villager);
+        let players = HashMap::from([
+            (PlayerId(0), boxed(ScriptedPlayer::new().will_shoot(Some(PlayerId(1))))),
+            (PlayerId(1), boxed(ScriptedPlayer::new())),
+            (PlayerId(2), boxed(ScriptedPlayer::new())),
+            (PlayerId(3), boxed(ScriptedPlayer::new())),
+        ]);
+        (state, players)
+    }
+
+    #[tokio::test]
+    async fn hunter_shot_fires_on_night_death() {
+        let (mut state, players) = setup();
+        state.kill(PlayerId(0));
+        let extra = resolve_hunter_shots(
+            &mut state,
+            &players,
+            &[(PlayerId(0), DeathCause::WolfKill)],
+            &HunterRules::default(),
+        )
+        .await;
+        assert_eq!(extra, vec![(PlayerId(1), DeathCause::HunterShot)]);
+        assert!(!state.is_alive(PlayerId(1)));
+        assert!(state
+            .log()
+            .iter()
+            .any(|e| matches!(e.kind, GameEventKind::HunterShot { hunter: PlayerId(0), target: PlayerId(1) })));
+    }
+
+    #[tokio::test]
+    async fn hunter_shot_fires_on_vote_death() {
+        let (mut state, players) = setup();
+        state.kill(PlayerId(0));
+        let extra = resolve_hunter_shots(
+            &mut state,
+            &players,
+            &[(PlayerId(0), DeathCause::Vote)],
+            &HunterRules::default(),
+        )
+        .await;
+        assert_eq!(extra.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn poisoned_hunter_holds_fire_under_classic_rules() {
+        let (mut state, players) = setup();
+        state.kill(PlayerId(0));
+        let extra = resolve_hunter_shots(
+            &mut state,
+            &players,
+            &[(PlayerId(0), DeathCause::Poison)],
+            &HunterRules::default(),
+        )
+        .await;
+        assert!(extra.is_empty());
+        assert!(state.is_alive(PlayerId(1)));
+    }
+
+    #[tokio::test]
+    async fn poisoned_hunter_shoots_when_variant_allows() {
+        let (mut state, players) = setup();
+        state.kill(PlayerId(0));
+        let extra = resolve_hunter_shots(
+            &mut state,
+            &players,
+            &[(PlayerId(0), DeathCause::Poison)],
+            &HunterRules { shoot_on_poison: true },
+        )
+        .await;
+        assert_eq!(extra, vec![(PlayerId(1), DeathCause::HunterShot)]);
+    }
+
+    #[tokio::test]
+    async fn chained_hunters_both_shoot() {
+        let mut state = GameState::new(0..4, Phase::Night, 0);
+        state.assign_role(PlayerId(0), Role::Hunter);
+        state.assign_role(PlayerId(1), Role::Hunter);
+        state.assign_role(PlayerId(2), Role::Werewolf);
+        state.assign_role(PlayerId(3), Role::Villager);
+        let players: HashMap<PlayerId, Box<dyn Player>> = HashMap::from([
+            (PlayerId(0), boxed(ScriptedPlayer::new().will_shoot(Some(PlayerId(1))))),
+            (PlayerId(1), boxed(ScriptedPlayer::new().will_shoot(Some(PlayerId(2))))),
+            (PlayerId(2), boxed(ScriptedPlayer::new())),
+            (PlayerId(3), boxed(ScriptedPlayer::new())),
+        ]);
+        state.kill(PlayerId(0));
+        let extra = resolve_hunter_shots(
+            &mut state,
+            &players,
+            &[(PlayerId(0), DeathCause::WolfKill)],
+            &HunterRules::default(),
+        )
+        .await;
+        assert_eq!(
+            extra,
+            vec![(PlayerId(1), DeathCause::HunterShot), (PlayerId(2), DeathCause::HunterShot)]
+        );
+        assert!(!state.is_alive(PlayerId(1)));
+        assert!(!state.is_alive(PlayerId(2)));
+    }
+
+    #[test]
+    fn apply_death_records_the_death_and_decides_the_game() {
+        let mut state = GameState::new(0..3, Phase::Voting, 0);
+        state.assign_role(PlayerId(0), Role::Werewolf);
+        state.assign_role(PlayerId(1), Role::Villager);
+        state.assign_role(PlayerId(2), Role::Villager);
+        let deaths = apply_death(&mut state, PlayerId(0), DeathCause::Vote);
+        assert_eq!(deaths, vec![(PlayerId(0), DeathCause::Vote)]);
+        assert_eq!(check_win(&state), Some(Alignment::Town));
+        assert!(!state.is_alive(PlayerId(0)));
+        assert!(state
+            .log()
+            .iter()
+            .any(|e| matches!(e.kind, GameEventKind::PlayerDied { player: PlayerId(0), .. })));
+    }
+
+    #[test]
+    fn a_lover_dies_of_grief_with_their_partner() {
+        let mut state = GameState::new(0..5, Phase::Night, 0);
+        state.assign_role(PlayerId(0), Role::Werewolf);
+        for id in 1..5 {
+            state.assign_role(PlayerId(id), Role::Villager);
+        }
+        state.add_relationship(PlayerId(2), PlayerId(3), Relationship::Lovers);
+        let deaths = apply_death(&mut state, PlayerId(2), DeathCause::WolfKill);
+        assert_eq!(
+            deaths,
+            vec![(PlayerId(2), DeathCause::WolfKill), (PlayerId(3), DeathCause::LoverGrief)]
+        );
+        assert!(!state.is_alive(PlayerId(3)));
+        assert!(state.log().iter().any(|e| matches!(
+            e.kind,
+            GameEventKind::PlayerDied { player: PlayerId(3), cause: DeathCause::LoverGrief, .. }
+        )));
+    }
+
+    #[tokio::test]
+    async fn a_hunter_lover_still_takes_their_shot() {
+        let (mut state, players) = setup();
+        // The Hunter (seat 0) loves seat 2; the village votes seat 2 out.
+        state.add_relationship(PlayerId(0), PlayerId(2), Relationship::Lovers);
+        let deaths = apply_death(&mut state, PlayerId(2), DeathCause::Vote);
+        assert_eq!(
+            deaths,
+            vec![(PlayerId(2), DeathCause::Vote), (PlayerId(0), DeathCause::LoverGrief)]
+        );
+        let extra =
+            resolve_hunter_shots(&mut state, &players, &deaths, &HunterRules::default())
+                .await;
+        assert_eq!(extra, vec![(PlayerId(1), DeathCause::HunterShot)]);
+        assert!(!state.is_alive(PlayerId(1)));
+    }
+
+    #[tokio::test]
+    async fn hunter_dying_with_the_last_wolf_still_shoots() {
+        // 0: Hunter, 1: Werewolf (the last one), 2: Witch, 3: Villager.
+        // The wolf kills the Hunter while the Witch poisons the wolf: town
+        // has already won on paper, but the Hunter's shot must still land
+        // before the phase boundary declares it.
+        let mut state = GameState::new(0..4, Phase::Night, 0);
+        state.assign_role(PlayerId(0), Role::Hunter);
+        state.assign_role(PlayerId(1), Role::Werewolf);
+        state.assign_role(PlayerId(2), Role::Witch);
+        state.assign_role(PlayerId(3), Role::Villager);
+        let players: HashMap<PlayerId, Box<dyn Player>> = HashMap::from([
+            (PlayerId(0), boxed(ScriptedPlayer::new().will_shoot(Some(PlayerId(3))))),
+            (PlayerId(1), boxed(ScriptedPlayer::new())),
+            (PlayerId(2), boxed(ScriptedPlayer::new())),
+            (PlayerId(3), boxed(ScriptedPlayer::new())),
+        ]);
+
+        let outcome = crate::game::night::resolve_night(
+            &mut state,
+            vec![
+                (PlayerId(1), crate::game::Action::Kill(PlayerId(0))),
+                (PlayerId(2), crate::game::Action::Poison(PlayerId(1))),
+            ],
+        );
+        assert_eq!(
+            outcome.deaths,
+            vec![(PlayerId(0), DeathCause::WolfKill), (PlayerId(1), DeathCause::Poison)]
+        );
+        let extra = resolve_hunter_shots(
+            &mut state,
+            &players,
+            &outcome.deaths,
+            &HunterRules::default(),
+        )
+        .await;
+        assert_eq!(extra, vec![(PlayerId(3), DeathCause::HunterShot)]);
+        assert!(!state.is_alive(PlayerId(3)));
+
+        // The shot resolved before the game-over declaration: the log shows
+        // the shot's death after the wolf's, and only then does advancing
+        // end the game with the Witch as the sole survivor.
+        let died: Vec<PlayerId> = state
+            .log()
+            .iter()
+            .filter_map(|e| match e.kind {
+                GameEventKind::PlayerDied { player, .. } => Some(player),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(died, vec![PlayerId(0), PlayerId(1), PlayerId(3)]);
+        assert_eq!(state.advance(), Phase::GameOver);
+        assert_eq!(check_win(&state), Some(Alignment::Town));
+        assert_eq!(state.alive_players(), vec![PlayerId(2)]);
+    }
+
+    #[tokio::test]
+    async fn shot_at_a_dead_target_is_wasted() {
+        let (mut state, players) = setup();
+        state.kill(PlayerId(0));
+        state.kill(PlayerId(1));
+        let extra = resolve_hunter_shots(
+            &mut state,
+            &players,
+            &[(PlayerId(0), DeathCause::WolfKill)],
+            &HunterRules::default(),
+        )
+        .await;
+        assert!(extra.is_empty());
+    }
+}
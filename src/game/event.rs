@@ -0,0 +1,279 @@
+//! Structured, machine-readable game history.
+//!
+//! Every notable occurrence is appended to the [`GameState`] log as a
+//! [`GameEvent`]; a full narration or replay can be reconstructed from the
+//! log alone, without re-running the engine.
+//!
+//! [`GameState`]: crate::game::state::GameState
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::game::action::Action;
+use crate::game::night::DeathCause;
+use crate::game::state::{Phase, PlayerId};
+use crate::game::timeout::{ActionKind, FallbackReason};
+use crate::roles::{Alignment, Role};
+
+/// One logged occurrence, stamped with the day it happened on and a
+/// wall-clock timestamp (milliseconds since the Unix epoch).
+///
+/// Equality compares the day and the kind but deliberately ignores the
+/// timestamp, so logs from two runs of the same seed compare equal even
+/// though they were produced at different times.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GameEvent {
+    pub day: u32,
+    pub timestamp_ms: u64,
+    pub kind: GameEventKind,
+}
+
+impl GameEvent {
+    /// Stamps an event with the current wall-clock time.
+    pub fn now(day: u32, kind: GameEventKind) -> Self {
+        let timestamp_ms = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_millis() as u64)
+            .unwrap_or(0);
+        Self { day, timestamp_ms, kind }
+    }
+}
+
+impl PartialEq for GameEvent {
+    fn eq(&self, other: &Self) -> bool {
+        self.day == other.day && self.kind == other.kind
+    }
+}
+
+/// What actually happened.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum GameEventKind {
+    PhaseChanged { from: Phase, to: Phase },
+    PlayerSpoke { player: PlayerId, text: String },
+    VoteCast {
+        voter: PlayerId,
+        target: Option<PlayerId>,
+        /// The voter's stated public justification, when one was given.
+        #[serde(default)]
+        reason: Option<String>,
+    },
+    PlayerDied {
+        player: PlayerId,
+        cause: DeathCause,
+        /// The dead player's exact role, present only under
+        /// [`DeathReveal::Full`]; see [`GameConfig`].
+        ///
+        /// [`DeathReveal::Full`]: crate::game::death::DeathReveal
+        /// [`GameConfig`]: crate::config::GameConfig
+        #[serde(default)]
+        role: Option<Role>,
+        /// The dead player's alignment, present under both
+        /// [`DeathReveal::Full`] and [`DeathReveal::AlignmentOnly`].
+        ///
+        /// [`DeathReveal::Full`]: crate::game::death::DeathReveal
+        /// [`DeathReveal::AlignmentOnly`]: crate::game::death::DeathReveal
+        #[serde(default)]
+        alignment: Option<Alignment>,
+    },
+    NightAction { actor: PlayerId, action: Action },
+    /// The game is over. `winner` is the victorious alignment, or `None`
+    /// when the [`GameConfig::max_days`] cap fired with no winner — a
+    /// draw.
+    ///
+    /// [`GameConfig::max_days`]: crate::config::GameConfig::max_days
+    GameEnded { winner: Option<Alignment> },
+    FallbackTriggered { player: PlayerId, action: ActionKind, reason: FallbackReason },
+    /// A player's raw reply was empty or matched a refusal trigger; the
+    /// reply is kept verbatim and the configured fallback applies. This is
+    /// distinct from a parse failure — valid text in the wrong format —
+    /// which surfaces as [`FallbackTriggered`], so metrics can separate
+    /// "model refused" from "model spoke but couldn't be parsed". See
+    /// [`RefusalDetector`].
+    ///
+    /// [`FallbackTriggered`]: GameEventKind::FallbackTriggered
+    /// [`RefusalDetector`]: crate::llm::parse::RefusalDetector
+    PlayerRefused { player: PlayerId, text: String },
+    /// An action the rules reject (e.g. a second use of a single-use
+    /// potion). The action has no effect; the log keeps the evidence.
+    InvalidAction { player: PlayerId, action: Action },
+    /// A formal pre-vote accusation, with the accuser's stated case.
+    Accusation { accuser: PlayerId, accused: PlayerId, text: String },
+    /// The accused player's guaranteed defense speech.
+    Defense { player: PlayerId, text: String },
+    /// The order players speak in today, for transparency and replays.
+    SpeakingOrder { order: Vec<PlayerId> },
+    /// The configured cost ceiling was hit mid-game: discussion ends
+    /// early and the table goes straight to the vote.
+    BudgetExceeded { cost: f64, max_cost: f64 },
+    /// An adaptive discussion decided to stop, and why; see
+    /// [`DiscussionPolicy::Adaptive`].
+    ///
+    /// [`DiscussionPolicy::Adaptive`]: crate::game::day::DiscussionPolicy
+    DiscussionEnded { reason: crate::game::day::DiscussionEndReason },
+    HunterShot { hunter: PlayerId, target: PlayerId },
+    /// The final public statement of a player whose death was just
+    /// decided — a lynch victim speaks before the death is applied, a
+    /// night victim at dawn. Gated on `last_words` on [`GameConfig`].
+    ///
+    /// [`GameConfig`]: crate::config::GameConfig
+    LastWords { player: PlayerId, text: String },
+    /// A dead player's remark in the graveyard channel. Never part of any
+    /// living player's view; see `graveyard_chat` on [`GameConfig`].
+    ///
+    /// [`GameConfig`]: crate::config::GameConfig
+    GraveyardChat { player: PlayerId, text: String },
+    /// The wolf council deadlocked (everyone abstained). `forced_target`
+    /// carries the RNG-picked victim under `WolfForcedRandom`, or `None`
+    /// when the table plays `WolfNoKill` (or no candidate was left).
+    WolfDeadlock { forced_target: Option<PlayerId> },
+    /// Which model backs a seat, recorded once at game start for every
+    /// model-backed player so metrics can be attributed per model.
+    PlayerModel { player: PlayerId, model: String },
+}
+
+/// The first point where two event logs diverge; see [`diff_logs`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogDiff {
+    /// Index of the first mismatch in both logs, or the shorter log's
+    /// length when one log is a strict prefix of the other.
+    pub index: usize,
+    /// A human-readable description of the mismatch, naming both sides.
+    pub description: String,
+}
+
+/// Finds the first index where two event logs diverge — the tool for
+/// pinning down where a supposedly deterministic rerun actually forked.
+///
+/// Events are compared with [`GameEvent`]'s equality, which ignores
+/// wall-clock timestamps, so only the day and the kind matter. Returns
+/// `None` when the logs are equivalent; when one log is a strict prefix
+/// of the other, the diff points just past its end.
+pub fn diff_logs(a: &[GameEvent], b: &[GameEvent]) -> Option<LogDiff> {
+    for (index, (ea, eb)) in a.iter().zip(b).enumerate() {
+        if ea != eb {
+            return Some(LogDiff {
+                index,
+                description: format!(
+                    "event {index} differs: day {} {:?} vs day {} {:?}",
+                    ea.day, ea.kind, eb.day, eb.kind
+                ),
+            });
+        }
+    }
+    let (short, long, side) = match a.len().cmp(&b.len()) {
+        std::cmp::Ordering::Equal => return None,
+        std::cmp::Ordering::Less => (a, b, "first"),
+        std::cmp::Ordering::Greater => (b, a, "second"),
+    };
+    let next = &long[short.len()];
+    Some(LogDiff {
+        index: short.len(),
+        description: format!(
+            "{side} log ends after {} events; the other continues with day {} {:?}",
+            short.len(),
+            next.day,
+            next.kind
+        ),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::state::GameState;
+
+    #[test]
+    fn state_accumulates_phase_change_events() {
+        let mut state = GameState::new(0..4, Phase::Night, 0);
+        state.assign_role(PlayerId(0), crate::roles::Role::Werewolf);
+        for id in 1..4 {
+            state.assign_role(PlayerId(id), crate::roles::Role::Villager);
+        }
+        state.advance();
+        state.advance();
+        let kinds: Vec<_> = state.log().iter().map(|e| &e.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                &GameEventKind::PhaseChanged { from: Phase::Night, to: Phase::Day },
+                &GameEventKind::PhaseChanged { from: Phase::Day, to: Phase::Voting },
+            ]
+        );
+    }
+
+    #[test]
+    fn events_carry_day_numbers() {
+        let mut state = GameState::new(0..4, Phase::Night, 0);
+        state.assign_role(PlayerId(0), crate::roles::Role::Werewolf);
+        for id in 1..4 {
+            state.assign_role(PlayerId(id), crate::roles::Role::Villager);
+        }
+        state.advance(); // Night -> Day 1
+        assert_eq!(state.log()[0].day, 1);
+    }
+
+    #[test]
+    fn equality_ignores_the_wall_clock_timestamp() {
+        let kind = GameEventKind::PlayerSpoke { player: PlayerId(1), text: "hi".into() };
+        let a = GameEvent { day: 1, timestamp_ms: 100, kind: kind.clone() };
+        let b = GameEvent { day: 1, timestamp_ms: 999, kind };
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn diff_logs_pins_the_first_divergent_vote() {
+        let log = |target| {
+            vec![
+                GameEvent::now(1, GameEventKind::PhaseChanged {
+                    from: Phase::Night,
+                    to: Phase::Day,
+                }),
+                GameEvent::now(1, GameEventKind::VoteCast {
+                    voter: PlayerId(0),
+                    target: Some(target),
+                    reason: None,
+                }),
+                GameEvent::now(1, GameEventKind::VoteCast {
+                    voter: PlayerId(1),
+                    target: Some(PlayerId(0)),
+                    reason: None,
+                }),
+            ]
+        };
+        let (a, b) = (log(PlayerId(2)), log(PlayerId(3)));
+        assert_eq!(diff_logs(&a, &a.clone()), None);
+        let diff = diff_logs(&a, &b).unwrap();
+        assert_eq!(diff.index, 1);
+        assert!(diff.description.contains("event 1 differs"));
+    }
+
+    #[test]
+    fn a_strict_prefix_diffs_just_past_its_end() {
+        let a = vec![GameEvent::now(1, GameEventKind::PhaseChanged {
+            from: Phase::Night,
+            to: Phase::Day,
+        })];
+        let mut b = a.clone();
+        b.push(GameEvent::now(1, GameEventKind::GameEnded {
+            winner: Some(Alignment::Town),
+        }));
+        let diff = diff_logs(&a, &b).unwrap();
+        assert_eq!(diff.index, 1);
+        assert!(diff.description.contains("first log ends after 1 events"));
+        assert_eq!(diff_logs(&b, &a).unwrap().index, 1);
+    }
+
+    #[test]
+    fn log_serializes_to_json() {
+        let event = GameEvent::now(2, GameEventKind::PlayerDied {
+            player: PlayerId(3),
+            cause: DeathCause::WolfKill,
+            role: None,
+            alignment: None,
+        });
+        let json = serde_json::to_string(&[event]).unwrap();
+        assert!(json.contains("PlayerDied"));
+        assert!(json.contains("\"day\":2"));
+    }
+}
@@ -0,0 +1,118 @@
+//! Private knowledge accumulated during a game.
+//!
+//! The Seer's value is remembering what she has learned; the engine tracks
+//! that in a per-player [`KnowledgeBase`] that only the owning player's
+//! `GameContext` exposes. Public role claims are tracked separately in a
+//! [`ClaimTracker`] — those are common knowledge.
+
+use serde::{Deserialize, Serialize};
+
+use crate::game::state::{PlayerId, Relationship};
+use crate::roles::{Alignment, Role};
+
+/// One night's investigation result.
+///
+/// `revealed_alignment` is what the Seer *perceives* — under
+/// [`SeerMode::Binary`] a deceiving role can make it a lie.
+///
+/// [`SeerMode::Binary`]: crate::game::night::SeerMode::Binary
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Investigation {
+    /// Which night (day counter) the investigation happened on.
+    pub night: u32,
+    pub target: PlayerId,
+    pub revealed_alignment: Alignment,
+    /// The target's exact role, filled in only under [`SeerMode::Role`].
+    ///
+    /// [`SeerMode::Role`]: crate::game::night::SeerMode::Role
+    #[serde(default)]
+    pub revealed_role: Option<Role>,
+}
+
+/// Everything one player privately knows.
+///
+/// A dead player's knowledge base is kept on the `GameState` (and in
+/// checkpoints) for post-game analysis.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct KnowledgeBase {
+    pub investigations: Vec<Investigation>,
+    /// Partners bound to this player by a hidden relationship (Masons,
+    /// Lovers), filled in when the bond is made.
+    #[serde(default)]
+    pub partners: Vec<(PlayerId, Relationship)>,
+}
+
+impl KnowledgeBase {
+    /// What this player last learned about `target`, if anything.
+    pub fn about(&self, target: PlayerId) -> Option<Alignment> {
+        self.investigations
+            .iter()
+            .rev()
+            .find(|i| i.target == target)
+            .map(|i| i.revealed_alignment)
+    }
+}
+
+/// A public role claim made during discussion.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Claim {
+    pub day: u32,
+    pub claimant: PlayerId,
+    pub role: Role,
+}
+
+/// Records who has publicly claimed which role, so players (and prompts)
+/// can spot contradictory claims.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct ClaimTracker {
+    claims: Vec<Claim>,
+}
+
+impl ClaimTracker {
+    pub fn record(&mut self, claim: Claim) {
+        self.claims.push(claim);
+    }
+
+    pub fn all(&self) -> &[Claim] {
+        &self.claims
+    }
+
+    /// Every player who has claimed `role`, in claim order. More than one
+    /// entry for a unique role means someone is lying.
+    pub fn claimants_of(&self, role: Role) -> Vec<PlayerId> {
+        self.claims.iter().filter(|c| c.role == role).map(|c| c.claimant).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn knowledge_base_returns_latest_result() {
+        let mut kb = KnowledgeBase::default();
+        kb.investigations.push(Investigation {
+            night: 0,
+            target: PlayerId(3),
+            revealed_alignment: Alignment::Town,
+            revealed_role: None,
+        });
+        kb.investigations.push(Investigation {
+            night: 1,
+            target: PlayerId(3),
+            revealed_alignment: Alignment::Wolf,
+            revealed_role: None,
+        });
+        assert_eq!(kb.about(PlayerId(3)), Some(Alignment::Wolf));
+        assert_eq!(kb.about(PlayerId(4)), None);
+    }
+
+    #[test]
+    fn claim_tracker_spots_competing_claims() {
+        let mut claims = ClaimTracker::default();
+        claims.record(Claim { day: 1, claimant: PlayerId(0), role: Role::Seer });
+        claims.record(Claim { day: 1, claimant: PlayerId(2), role: Role::Seer });
+        assert_eq!(claims.claimants_of(Role::Seer), vec![PlayerId(0), PlayerId(2)]);
+        assert_eq!(claims.claimants_of(Role::Witch), Vec::<PlayerId>::new());
+    }
+}
@@ -0,0 +1,56 @@
+//! Core game engine: state, phases, and transition logic.
+
+pub mod action;
+pub mod builder;
+pub mod day;
+pub mod death;
+pub mod event;
+pub mod knowledge;
+pub mod night;
+pub mod observer;
+pub mod replay;
+pub mod rng;
+pub mod runner;
+pub mod state;
+pub mod suspicion;
+pub mod timeout;
+pub mod validate;
+pub mod view;
+pub mod vote;
+pub mod win;
+
+pub use action::Action;
+pub use builder::{BuildError, GameBuilder, Roster};
+pub use day::{
+    DiscussionEndReason, DiscussionPolicy, DiscussionSettings, SpeakingOrder, SpeechObserver,
+    run_accusations, run_discussion, run_discussion_observed, run_graveyard,
+};
+pub use death::{DeathReveal, HunterRules, apply_death, resolve_hunter_shots};
+pub use event::{GameEvent, GameEventKind, LogDiff, diff_logs};
+pub use knowledge::{Claim, ClaimTracker, Investigation, KnowledgeBase};
+pub use night::{
+    DeathCause, GuardRules, NightOutcome, WitchPotions, WitchRules, WolfDeadlock,
+    resolve_night, resolve_night_with, run_wolf_council, setup_actions_only,
+};
+pub use observer::{CountingObserver, GameObserver};
+#[cfg(feature = "tracing")]
+pub use observer::TracingObserver;
+pub use replay::{ReplayError, replay, verify_survivors};
+pub use rng::Rng;
+pub use runner::{
+    GameOutcome, GameResult, PhaseHook, run_game, run_game_hooked, run_game_observed,
+    run_game_with,
+};
+pub use state::{
+    ContextCache, GameState, PersistError, Phase, PlayerId, PlayerRoster, PlayerState,
+    Relationship,
+};
+pub use suspicion::suspicion_scores;
+pub use timeout::{ActionKind, FallbackReason, FallbackStrategy, TurnPolicy};
+pub use validate::{InvalidAction, validate_action};
+pub use view::{GameSnapshot, PlayerSnapshot, PlayerView};
+pub use vote::{
+    RunoffSettings, TieResolution, VoteOutcome, VoteResult, run_open_vote, run_runoff,
+    run_secret_vote, tally,
+};
+pub use win::{WinRules, check_win, check_win_with};
@@ -0,0 +1,1030 @@
+//! Night-phase action resolution.
+//!
+//! Actions are resolved in a fixed, role-priority order (Guard, then Seer,
+//! then wolves, then Witch) so that identical inputs always produce the
+//! same outcome regardless of the order actions were collected in. Seat
+//! number breaks priority ties, and the concurrent gathering in
+//! [`timed_night_actions`] sorts by seat as well — completion timing can
+//! never reach the outcome or the event log.
+//!
+//! [`timed_night_actions`]: crate::game::timeout::timed_night_actions
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::game::action::Action;
+use crate::game::event::GameEventKind;
+use crate::game::knowledge::Investigation;
+use crate::game::state::{GameState, PlayerId};
+use crate::game::timeout::{TurnPolicy, timed_night_action, timed_vote};
+use crate::game::vote::{TieResolution, VoteOutcome, tally};
+use crate::player::Player;
+use crate::roles::{Alignment, NightEffect, RoleRegistry};
+
+/// Why a player died during the night.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DeathCause {
+    WolfKill,
+    Poison,
+    /// Eliminated by the day vote.
+    Vote,
+    /// Shot by a dying Hunter.
+    HunterShot,
+    /// Died of grief the moment their Lover died; see
+    /// [`Relationship::Lovers`](crate::game::state::Relationship).
+    LoverGrief,
+}
+
+/// The Witch's remaining single-use potions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WitchPotions {
+    pub heal_available: bool,
+    pub poison_available: bool,
+}
+
+impl Default for WitchPotions {
+    fn default() -> Self {
+        Self { heal_available: true, poison_available: true }
+    }
+}
+
+/// Table-variant rules for the Witch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WitchRules {
+    /// Whether the Witch may heal herself.
+    pub may_self_heal: bool,
+    /// Whether both potions may be spent in the same night.
+    pub both_potions_same_night: bool,
+}
+
+impl Default for WitchRules {
+    fn default() -> Self {
+        Self { may_self_heal: true, both_potions_same_night: true }
+    }
+}
+
+/// Table-variant rules for the Guard.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GuardRules {
+    /// Whether the Guard may protect himself.
+    pub may_guard_self: bool,
+    /// Whether the Guard may protect the same target two nights running.
+    pub may_repeat: bool,
+}
+
+impl Default for GuardRules {
+    /// The standard rules: self-guarding is allowed, repeating last
+    /// night's target is not.
+    fn default() -> Self {
+        Self { may_guard_self: true, may_repeat: false }
+    }
+}
+
+/// Table-variant semantics for the Seer's investigation result.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum SeerMode {
+    /// The target's true alignment (the default).
+    #[default]
+    Alignment,
+    /// The target's exact role.
+    Role,
+    /// A werewolf / not-a-werewolf check that goes by appearance: a role
+    /// overriding [`RoleBehavior::appears_as_werewolf`] reads falsely.
+    ///
+    /// [`RoleBehavior::appears_as_werewolf`]: crate::roles::behavior::RoleBehavior::appears_as_werewolf
+    Binary,
+}
+
+/// The result of resolving one night, suitable for moderator narration.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct NightOutcome {
+    /// Players who died tonight, with the cause, in resolution order.
+    pub deaths: Vec<(PlayerId, DeathCause)>,
+    /// Players who were attacked but survived (protected or healed).
+    pub saved: Vec<PlayerId>,
+}
+
+/// Resolves a night's worth of collected actions against the state.
+///
+/// Actions are sorted by the acting role's `night_action_priority` before
+/// being applied; actions from players with no night priority are ignored.
+/// Interactions honored here:
+///
+/// - a Guard protection on the wolves' target cancels the kill;
+/// - a Witch heal on the wolves' target cancels the kill (the Witch acts
+///   last, so the save overrides the attack);
+/// - a Witch poison plus a wolf kill on the same player counts as a single
+///   death, attributed to the wolf kill since it resolves first.
+pub fn resolve_night(
+    state: &mut GameState,
+    actions: Vec<(PlayerId, Action)>,
+) -> NightOutcome {
+    resolve_night_with(state, actions, &RoleRegistry::default())
+}
+
+/// [`resolve_night`] against an explicit [`RoleRegistry`], so games with
+/// custom roles resolve through their registered behaviors.
+pub fn resolve_night_with(
+    state: &mut GameState,
+    actions: Vec<(PlayerId, Action)>,
+    registry: &RoleRegistry,
+) -> NightOutcome {
+    let behavior_of = |state: &GameState, id: PlayerId| {
+        state.role_key(id).and_then(|key| registry.get(&key).cloned())
+    };
+    let mut ordered: Vec<(u8, PlayerId, Action)> = actions
+        .into_iter()
+        .filter_map(|(actor, action)| {
+            let priority = behavior_of(state, actor)?.night_priority()?;
+            Some((priority, actor, action))
+        })
+        .collect();
+    ordered.sort_by_key(|(priority, actor, _)| (*priority, *actor));
+
+    for (_, actor, action) in &ordered {
+        state.record(GameEventKind::NightAction { actor: *actor, action: action.clone() });
+    }
+
+    let mut protected: Option<PlayerId> = None;
+    // A Bodyguard and who they stand in for tonight.
+    let mut bodyguard: Option<(PlayerId, PlayerId)> = None;
+    let mut wolf_target: Option<PlayerId> = None;
+    let mut healed: Option<PlayerId> = None;
+    let mut poisoned: Vec<PlayerId> = Vec::new();
+    // Actors who already spent a potion tonight, for the one-per-night rule.
+    let mut potion_spent_tonight: Vec<PlayerId> = Vec::new();
+    // Successful protections, for the no-consecutive-protection rule.
+    let mut protections_tonight: Vec<(PlayerId, PlayerId)> = Vec::new();
+
+    for (_, actor, action) in ordered {
+        let Some(behavior) = behavior_of(state, actor) else { continue };
+        match behavior.resolve(&action, state) {
+            NightEffect::Reveal(target) => {
+                let target_behavior = behavior_of(state, target);
+                let (revealed_alignment, revealed_role) = match state.seer_mode() {
+                    SeerMode::Alignment => {
+                        let alignment = target_behavior
+                            .map(|b| b.alignment())
+                            .unwrap_or(crate::roles::Alignment::Town);
+                        (alignment, None)
+                    }
+                    SeerMode::Role => {
+                        let alignment = target_behavior
+                            .map(|b| b.alignment())
+                            .unwrap_or(crate::roles::Alignment::Town);
+                        (alignment, state.role_of(target))
+                    }
+                    SeerMode::Binary => {
+                        let is_wolf =
+                            target_behavior.is_some_and(|b| b.appears_as_werewolf());
+                        let alignment = if is_wolf {
+                            crate::roles::Alignment::Wolf
+                        } else {
+                            crate::roles::Alignment::Town
+                        };
+                        (alignment, None)
+                    }
+                };
+                let night = state.day();
+                state.record_investigation(actor, Investigation {
+                    night,
+                    target,
+                    revealed_alignment,
+                    revealed_role,
+                });
+            }
+            NightEffect::Protect(target) => {
+                let rules = state.guard_rules();
+                let invalid = (!rules.may_guard_self && target == actor)
+                    || (!rules.may_repeat && state.last_protected_of(actor) == Some(target));
+                if invalid {
+                    state.record(GameEventKind::InvalidAction {
+                        player: actor,
+                        action: action.clone(),
+                    });
+                } else {
+                    protections_tonight.push((actor, target));
+                    protected = Some(target);
+                }
+            }
+            NightEffect::Sacrifice(target) => {
+                let rules = state.guard_rules();
+                let invalid = (!rules.may_guard_self && target == actor)
+                    || (!rules.may_repeat && state.last_protected_of(actor) == Some(target));
+                if invalid {
+                    state.record(GameEventKind::InvalidAction {
+                        player: actor,
+                        action: action.clone(),
+                    });
+                } else if target == actor {
+                    // Standing in for yourself is a normal self-save.
+                    protections_tonight.push((actor, target));
+                    protected = Some(target);
+                } else {
+                    protections_tonight.push((actor, target));
+                    bodyguard = Some((actor, target));
+                }
+            }
+            NightEffect::Attack(target) => wolf_target = Some(target),
+            NightEffect::Heal(target) => {
+                let rules = state.witch_rules();
+                let invalid = !state.potions_of(actor).heal_available
+                    || (!rules.may_self_heal && target == actor)
+                    || (!rules.both_potions_same_night
+                        && potion_spent_tonight.contains(&actor));
+                if invalid {
+                    state.record(GameEventKind::InvalidAction {
+                        player: actor,
+                        action: action.clone(),
+                    });
+                } else {
+                    state.spend_heal(actor);
+                    potion_spent_tonight.push(actor);
+                    healed = Some(target);
+                }
+            }
+            NightEffect::Poison(target) => {
+                let rules = state.witch_rules();
+                let invalid = !state.potions_of(actor).poison_available
+                    || (!rules.both_potions_same_night
+                        && potion_spent_tonight.contains(&actor));
+                if invalid {
+                    state.record(GameEventKind::InvalidAction {
+                        player: actor,
+                        action: action.clone(),
+                    });
+                } else {
+                    state.spend_poison(actor);
+                    potion_spent_tonight.push(actor);
+                    poisoned.push(target);
+                }
+            }
+            // The behavior declined the action (not a night power of this
+            // role), so it has no effect.
+            NightEffect::None => {}
+        }
+    }
+
+    state.set_last_protections(protections_tonight);
+
+    let mut outcome = NightOutcome::default();
+
+    if let Some(target) = wolf_target {
+        if protected == Some(target) || healed == Some(target) {
+            outcome.saved.push(target);
+        } else if let Some((shield, _)) =
+            bodyguard.filter(|&(shield, charge)| charge == target && state.is_alive(shield))
+        {
+            // The attack lands, one seat over: the Bodyguard dies in the
+            // charge's place.
+            outcome.saved.push(target);
+            outcome.deaths.push((shield, DeathCause::WolfKill));
+        } else if state.is_alive(target) {
+            outcome.deaths.push((target, DeathCause::WolfKill));
+        }
+    }
+
+    for target in poisoned {
+        let already_dead_tonight = outcome.deaths.iter().any(|(id, _)| *id == target);
+        if !already_dead_tonight && state.is_alive(target) {
+            outcome.deaths.push((target, DeathCause::Poison));
+        }
+    }
+
+    // Applying a death can cascade (a Lover dies of grief), so the outcome
+    // reports what actually happened rather than the plan: cascade deaths
+    // are appended, and a planned death pre-empted by a cascade is dropped.
+    let mut applied = Vec::new();
+    for (id, cause) in &outcome.deaths {
+        if state.is_alive(*id) {
+            applied.extend(crate::game::death::apply_death(state, *id, *cause));
+        }
+    }
+    outcome.deaths = applied;
+
+    outcome
+}
+
+/// Restricts a Night-0 action set to setup-only effects, for variants
+/// where the first night has no deaths: investigations and protections go
+/// through, kills and potions are silently dropped (not invalid — the
+/// variant simply has no use for them yet). Kept investigations resolve
+/// and store exactly like any other night's.
+pub fn setup_actions_only(actions: Vec<(PlayerId, Action)>) -> Vec<(PlayerId, Action)> {
+    actions
+        .into_iter()
+        .filter(|(_, action)| {
+            matches!(action, Action::Investigate(_) | Action::Protect(_))
+        })
+        .collect()
+}
+
+/// What happens when the wolf council deadlocks: every wolf abstained, or
+/// every vote was filtered out as illegal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum WolfDeadlock {
+    /// A peaceful night is allowed; the pack simply does not attack.
+    #[default]
+    WolfNoKill,
+    /// The seeded RNG picks a living non-wolf target. The forced kill
+    /// still goes through normal night resolution, so Guard protection
+    /// and Witch saves apply as usual.
+    WolfForcedRandom,
+}
+
+/// Runs the wolves' private coordination sub-step: a chat round where each
+/// living wolf may drop an [`Action::WolfChat`] message on the pack's
+/// channel, then a pack-internal vote on the kill target.
+///
+/// Chat messages and vote targets both stay off the public event log — the
+/// transcript lives on the state behind the wolf-only accessor, and the
+/// vote is tallied here without recording `VoteCast` events. Ties are
+/// broken by the seeded RNG so replays stay stable. An Alpha Werewolf's
+/// vote counts double; a Minion neither chats nor votes, though it reads
+/// the transcript like any wolf. Returns the agreed target, or `None`
+/// when no killing wolf is left.
+///
+/// A council where nobody votes for anyone is resolved by `deadlock`, and
+/// the resolution is recorded as a [`WolfDeadlock`] event so the log is
+/// unambiguous about why the night was peaceful (or why the kill looks
+/// arbitrary).
+///
+/// [`WolfDeadlock`]: GameEventKind::WolfDeadlock
+pub async fn run_wolf_council(
+    state: &mut GameState,
+    players: &HashMap<PlayerId, Box<dyn Player>>,
+    policy: &TurnPolicy,
+    deadlock: WolfDeadlock,
+) -> Option<PlayerId> {
+    // The pack is everyone wolf-aligned; the council — who chats and
+    // votes — is only the killing wolves. A Minion reads the transcript
+    // through its context but holds no vote, and is never a valid target.
+    let pack: Vec<PlayerId> = state
+        .alive_players()
+        .into_iter()
+        .filter(|&id| {
+            state.role_of(id).is_some_and(|r| r.alignment() == Alignment::Wolf)
+        })
+        .collect();
+    let wolves: Vec<PlayerId> = pack
+        .iter()
+        .copied()
+        .filter(|&id| state.role_of(id).is_some_and(|r| r.info().acts_at_night))
+        .collect();
+    if wolves.is_empty() {
+        return None;
+    }
+
+    let mut cache = crate::game::state::ContextCache::new();
+    for &wolf in &wolves {
+        let Some(player) = players.get(&wolf) else { continue };
+        let ctx = cache.context_for(state, wolf);
+        if let Some(Action::WolfChat(text)) =
+            timed_night_action(player.as_ref(), &ctx, state, policy).await
+        {
+            state.record_wolf_chat(wolf, text);
+        }
+    }
+
+    let mut votes: Vec<(PlayerId, Option<PlayerId>)> = Vec::new();
+    for &wolf in &wolves {
+        let Some(player) = players.get(&wolf) else { continue };
+        let ctx = cache.context_for(state, wolf);
+        let target = timed_vote(player.as_ref(), &ctx, state, policy)
+            .await
+            .filter(|&t| state.is_alive(t) && !pack.contains(&t));
+        votes.push((wolf, target));
+        if state.role_of(wolf) == Some(crate::roles::Role::AlphaWerewolf) {
+            // The Alpha's say counts double: a second tally entry is the
+            // whole mechanism, since `tally` weights by entry.
+            votes.push((wolf, target));
+        }
+    }
+    match tally(&votes, TieResolution::Random, state.rng_mut()).outcome {
+        VoteOutcome::Eliminated(target) => Some(target),
+        _ => {
+            let forced_target = match deadlock {
+                WolfDeadlock::WolfNoKill => None,
+                WolfDeadlock::WolfForcedRandom => {
+                    let candidates: Vec<PlayerId> = state
+                        .alive_players()
+                        .into_iter()
+                        .filter(|id| !pack.contains(id))
+                        .collect();
+                    state.rng_mut().choose(&candidates).copied()
+                }
+            };
+            state.record(GameEventKind::WolfDeadlock { forced_target });
+            forced_target
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::state::Phase;
+    use crate::roles::Role;
+
+    /// 0: Guard, 1: Werewolf, 2: Witch, 3/4: Villagers.
+    fn setup() -> GameState {
+        let mut state = GameState::new(0..5, Phase::Night, 0);
+        state.assign_role(PlayerId(0), Role::Guard);
+        state.assign_role(PlayerId(1), Role::Werewolf);
+        state.assign_role(PlayerId(2), Role::Witch);
+        state.assign_role(PlayerId(3), Role::Villager);
+        state.assign_role(PlayerId(4), Role::Villager);
+        state
+    }
+
+    #[test]
+    fn unopposed_wolf_kill_succeeds() {
+        let mut state = setup();
+        let outcome = resolve_night(&mut state, vec![(PlayerId(1), Action::Kill(PlayerId(3)))]);
+        assert_eq!(outcome.deaths, vec![(PlayerId(3), DeathCause::WolfKill)]);
+        assert!(!state.is_alive(PlayerId(3)));
+    }
+
+    #[test]
+    fn guard_protection_cancels_the_kill() {
+        let mut state = setup();
+        let outcome = resolve_night(
+            &mut state,
+            vec![(PlayerId(1), Action::Kill(PlayerId(3))), (PlayerId(0), Action::Protect(PlayerId(3)))],
+        );
+        assert!(outcome.deaths.is_empty());
+        assert_eq!(outcome.saved, vec![PlayerId(3)]);
+        assert!(state.is_alive(PlayerId(3)));
+    }
+
+    #[test]
+    fn witch_save_overrides_the_kill() {
+        let mut state = setup();
+        let outcome = resolve_night(
+            &mut state,
+            vec![(PlayerId(1), Action::Kill(PlayerId(4))), (PlayerId(2), Action::Heal(PlayerId(4)))],
+        );
+        assert!(outcome.deaths.is_empty());
+        assert_eq!(outcome.saved, vec![PlayerId(4)]);
+        assert!(state.is_alive(PlayerId(4)));
+    }
+
+    #[test]
+    fn poison_plus_kill_on_same_player_is_one_death() {
+        let mut state = setup();
+        let outcome = resolve_night(
+            &mut state,
+            vec![(PlayerId(1), Action::Kill(PlayerId(3))), (PlayerId(2), Action::Poison(PlayerId(3)))],
+        );
+        assert_eq!(outcome.deaths, vec![(PlayerId(3), DeathCause::WolfKill)]);
+    }
+
+    #[test]
+    fn poison_and_kill_on_different_players_are_two_deaths() {
+        let mut state = setup();
+        let outcome = resolve_night(
+            &mut state,
+            vec![(PlayerId(2), Action::Poison(PlayerId(4))), (PlayerId(1), Action::Kill(PlayerId(3)))],
+        );
+        assert_eq!(
+            outcome.deaths,
+            vec![(PlayerId(3), DeathCause::WolfKill), (PlayerId(4), DeathCause::Poison)]
+        );
+    }
+
+    #[test]
+    fn guard_cannot_repeat_last_nights_protection() {
+        let mut state = setup();
+        // Night 1: the protection works.
+        resolve_night(&mut state, vec![(PlayerId(0), Action::Protect(PlayerId(3))), (PlayerId(1), Action::Kill(PlayerId(3)))]);
+        assert!(state.is_alive(PlayerId(3)));
+        assert_eq!(state.last_protected_of(PlayerId(0)), Some(PlayerId(3)));
+
+        // Night 2: repeating the target is invalid; the kill lands.
+        let outcome =
+            resolve_night(&mut state, vec![(PlayerId(0), Action::Protect(PlayerId(3))), (PlayerId(1), Action::Kill(PlayerId(3)))]);
+        assert_eq!(outcome.deaths, vec![(PlayerId(3), DeathCause::WolfKill)]);
+        assert!(state.log().iter().any(|e| matches!(
+            e.kind,
+            GameEventKind::InvalidAction { player: PlayerId(0), action: Action::Protect(PlayerId(3)) }
+        )));
+    }
+
+    #[test]
+    fn guard_may_switch_back_after_a_night_away() {
+        let mut state = setup();
+        resolve_night(&mut state, vec![(PlayerId(0), Action::Protect(PlayerId(3)))]);
+        resolve_night(&mut state, vec![(PlayerId(0), Action::Protect(PlayerId(4)))]);
+        // Night 3: player 3 is two nights back, so he's fair game again.
+        let outcome =
+            resolve_night(&mut state, vec![(PlayerId(0), Action::Protect(PlayerId(3))), (PlayerId(1), Action::Kill(PlayerId(3)))]);
+        assert_eq!(outcome.saved, vec![PlayerId(3)]);
+    }
+
+    #[test]
+    fn self_guard_can_be_forbidden() {
+        let mut state = setup();
+        state.set_guard_rules(GuardRules { may_guard_self: false, ..Default::default() });
+        let outcome =
+            resolve_night(&mut state, vec![(PlayerId(0), Action::Protect(PlayerId(0))), (PlayerId(1), Action::Kill(PlayerId(0)))]);
+        assert_eq!(outcome.deaths, vec![(PlayerId(0), DeathCause::WolfKill)]);
+
+        // The permissive variant allows it.
+        let mut state = setup();
+        state.set_guard_rules(GuardRules { may_guard_self: true, ..Default::default() });
+        let outcome =
+            resolve_night(&mut state, vec![(PlayerId(0), Action::Protect(PlayerId(0))), (PlayerId(1), Action::Kill(PlayerId(0)))]);
+        assert_eq!(outcome.saved, vec![PlayerId(0)]);
+    }
+
+    #[test]
+    fn repeat_protection_variant_can_be_allowed() {
+        let mut state = setup();
+        state.set_guard_rules(GuardRules { may_repeat: true, ..Default::default() });
+        resolve_night(&mut state, vec![(PlayerId(0), Action::Protect(PlayerId(3)))]);
+        let outcome =
+            resolve_night(&mut state, vec![(PlayerId(0), Action::Protect(PlayerId(3))), (PlayerId(1), Action::Kill(PlayerId(3)))]);
+        assert_eq!(outcome.saved, vec![PlayerId(3)]);
+    }
+
+    #[test]
+    fn guard_context_surfaces_last_nights_protection() {
+        let mut state = setup();
+        resolve_night(&mut state, vec![(PlayerId(0), Action::Protect(PlayerId(4)))]);
+        assert_eq!(state.context_for(PlayerId(0)).last_protected, Some(PlayerId(4)));
+        assert_eq!(state.context_for(PlayerId(3)).last_protected, None);
+        // A night without a protection clears it.
+        resolve_night(&mut state, vec![(PlayerId(1), Action::Kill(PlayerId(3)))]);
+        assert_eq!(state.context_for(PlayerId(0)).last_protected, None);
+    }
+
+    #[test]
+    fn spent_heal_is_gone_for_the_rest_of_the_game() {
+        let mut state = setup();
+        // Night 1: the heal works and is consumed.
+        let first = resolve_night(
+            &mut state,
+            vec![(PlayerId(1), Action::Kill(PlayerId(3))), (PlayerId(2), Action::Heal(PlayerId(3)))],
+        );
+        assert_eq!(first.saved, vec![PlayerId(3)]);
+        assert!(!state.potions_of(PlayerId(2)).heal_available);
+
+        // Night 3: a second heal is invalid and saves nobody.
+        let second = resolve_night(
+            &mut state,
+            vec![(PlayerId(1), Action::Kill(PlayerId(4))), (PlayerId(2), Action::Heal(PlayerId(4)))],
+        );
+        assert_eq!(second.deaths, vec![(PlayerId(4), DeathCause::WolfKill)]);
+        assert!(state.log().iter().any(|e| matches!(
+            e.kind,
+            GameEventKind::InvalidAction { player: PlayerId(2), action: Action::Heal(PlayerId(4)) }
+        )));
+    }
+
+    #[test]
+    fn spent_poison_is_gone_too() {
+        let mut state = setup();
+        resolve_night(&mut state, vec![(PlayerId(2), Action::Poison(PlayerId(3)))]);
+        assert!(!state.potions_of(PlayerId(2)).poison_available);
+        let outcome = resolve_night(&mut state, vec![(PlayerId(2), Action::Poison(PlayerId(4)))]);
+        assert!(outcome.deaths.is_empty());
+        assert!(state.is_alive(PlayerId(4)));
+    }
+
+    #[test]
+    fn self_heal_can_be_forbidden() {
+        let mut state = setup();
+        state.set_witch_rules(WitchRules { may_self_heal: false, ..Default::default() });
+        let outcome = resolve_night(
+            &mut state,
+            vec![(PlayerId(1), Action::Kill(PlayerId(2))), (PlayerId(2), Action::Heal(PlayerId(2)))],
+        );
+        assert_eq!(outcome.deaths, vec![(PlayerId(2), DeathCause::WolfKill)]);
+        // The potion is not consumed by the rejected attempt.
+        assert!(state.potions_of(PlayerId(2)).heal_available);
+    }
+
+    #[test]
+    fn one_potion_per_night_can_be_enforced() {
+        let mut state = setup();
+        state.set_witch_rules(WitchRules {
+            both_potions_same_night: false,
+            ..Default::default()
+        });
+        let outcome = resolve_night(
+            &mut state,
+            vec![(PlayerId(1), Action::Kill(PlayerId(3))), (PlayerId(2), Action::Heal(PlayerId(3))), (PlayerId(2), Action::Poison(PlayerId(1)))],
+        );
+        // The heal lands; the poison in the same night is rejected.
+        assert_eq!(outcome.saved, vec![PlayerId(3)]);
+        assert!(state.is_alive(PlayerId(1)));
+        assert!(state.potions_of(PlayerId(2)).poison_available);
+    }
+
+    #[test]
+    fn witch_context_states_her_remaining_potions() {
+        let mut state = setup();
+        resolve_night(&mut state, vec![(PlayerId(2), Action::Poison(PlayerId(4)))]);
+        let potions = state.context_for(PlayerId(2)).potions.expect("the Witch carries potions");
+        assert!(potions.heal_available);
+        assert!(!potions.poison_available);
+        // Nobody else sees potion state.
+        assert_eq!(state.context_for(PlayerId(3)).potions, None);
+    }
+
+    #[test]
+    fn seer_investigation_is_stored_privately() {
+        let mut state = setup();
+        state.assign_role(PlayerId(3), crate::roles::Role::Seer);
+        resolve_night(&mut state, vec![(PlayerId(3), Action::Investigate(PlayerId(1)))]);
+        let kb = state.knowledge_of(PlayerId(3));
+        assert_eq!(kb.about(PlayerId(1)), Some(crate::roles::Alignment::Wolf));
+        // Nobody else learns anything.
+        assert_eq!(state.knowledge_of(PlayerId(1)).investigations.len(), 0);
+        assert_eq!(state.knowledge_of(PlayerId(4)).investigations.len(), 0);
+    }
+
+    #[test]
+    fn a_bodyguard_dies_in_place_of_their_charge() {
+        let mut state = setup();
+        state.assign_custom_role(PlayerId(0), "Bodyguard");
+        // The Bodyguard is half of a Lover pair: the redirected death must
+        // go through apply_death, so the grief cascade fires too.
+        state.add_relationship(PlayerId(0), PlayerId(4), crate::game::state::Relationship::Lovers);
+        let outcome = resolve_night(
+            &mut state,
+            vec![(PlayerId(0), Action::Protect(PlayerId(3))), (PlayerId(1), Action::Kill(PlayerId(3)))],
+        );
+        assert_eq!(outcome.saved, vec![PlayerId(3)]);
+        assert_eq!(
+            outcome.deaths,
+            vec![(PlayerId(0), DeathCause::WolfKill), (PlayerId(4), DeathCause::LoverGrief)]
+        );
+        assert!(state.is_alive(PlayerId(3)));
+        assert!(!state.is_alive(PlayerId(0)));
+    }
+
+    #[test]
+    fn a_self_protecting_bodyguard_just_survives() {
+        let mut state = setup();
+        state.assign_custom_role(PlayerId(0), "Bodyguard");
+        let outcome = resolve_night(
+            &mut state,
+            vec![(PlayerId(0), Action::Protect(PlayerId(0))), (PlayerId(1), Action::Kill(PlayerId(0)))],
+        );
+        assert_eq!(outcome.saved, vec![PlayerId(0)]);
+        assert!(outcome.deaths.is_empty());
+        assert!(state.is_alive(PlayerId(0)));
+    }
+
+    #[test]
+    fn a_redirected_kill_and_a_poison_on_the_bodyguard_is_one_death() {
+        let mut state = setup();
+        state.assign_custom_role(PlayerId(0), "Bodyguard");
+        let outcome = resolve_night(
+            &mut state,
+            vec![
+                (PlayerId(0), Action::Protect(PlayerId(3))),
+                (PlayerId(1), Action::Kill(PlayerId(3))),
+                (PlayerId(2), Action::Poison(PlayerId(0))),
+            ],
+        );
+        // The redirect and the poison both land on the Bodyguard; they
+        // die exactly once, attributed to the kill that resolves first.
+        assert_eq!(outcome.deaths, vec![(PlayerId(0), DeathCause::WolfKill)]);
+        assert_eq!(outcome.saved, vec![PlayerId(3)]);
+    }
+
+    #[test]
+    fn plain_alignment_mode_reports_the_true_alignment() {
+        let mut state = setup();
+        state.assign_role(PlayerId(3), crate::roles::Role::Seer);
+        state.set_seer_mode(SeerMode::Alignment);
+        resolve_night(&mut state, vec![(PlayerId(3), Action::Investigate(PlayerId(1)))]);
+        let peek = state.knowledge_of(PlayerId(3)).investigations[0];
+        assert_eq!(peek.revealed_alignment, crate::roles::Alignment::Wolf);
+        assert_eq!(peek.revealed_role, None);
+    }
+
+    #[test]
+    fn role_mode_reveals_the_exact_role() {
+        let mut state = setup();
+        state.assign_role(PlayerId(3), crate::roles::Role::Seer);
+        state.set_seer_mode(SeerMode::Role);
+        resolve_night(&mut state, vec![(PlayerId(3), Action::Investigate(PlayerId(2)))]);
+        let peek = state.knowledge_of(PlayerId(3)).investigations[0];
+        assert_eq!(peek.revealed_role, Some(Role::Witch));
+        assert_eq!(peek.revealed_alignment, crate::roles::Alignment::Town);
+    }
+
+    #[test]
+    fn binary_mode_reads_a_minion_as_town() {
+        let mut state = setup();
+        state.assign_role(PlayerId(3), crate::roles::Role::Seer);
+        state.assign_role(PlayerId(4), crate::roles::Role::Minion);
+        state.set_seer_mode(SeerMode::Binary);
+        resolve_night(&mut state, vec![
+            (PlayerId(3), Action::Investigate(PlayerId(4))),
+        ]);
+        // A wolf-aligned human deceives the werewolf check.
+        let peek = state.knowledge_of(PlayerId(3)).investigations[0];
+        assert_eq!(peek.revealed_alignment, crate::roles::Alignment::Town);
+        // An actual wolf still reads as one.
+        resolve_night(&mut state, vec![
+            (PlayerId(3), Action::Investigate(PlayerId(1))),
+        ]);
+        let peek = *state.knowledge_of(PlayerId(3)).investigations.last().unwrap();
+        assert_eq!(peek.revealed_alignment, crate::roles::Alignment::Wolf);
+    }
+
+    #[test]
+    fn dead_seer_knowledge_is_preserved() {
+        let mut state = setup();
+        state.assign_role(PlayerId(3), crate::roles::Role::Seer);
+        resolve_night(&mut state, vec![(PlayerId(3), Action::Investigate(PlayerId(1)))]);
+        resolve_night(&mut state, vec![(PlayerId(1), Action::Kill(PlayerId(3)))]);
+        assert!(!state.is_alive(PlayerId(3)));
+        assert_eq!(state.knowledge_of(PlayerId(3)).about(PlayerId(1)), Some(crate::roles::Alignment::Wolf));
+    }
+
+    #[test]
+    fn resolution_order_is_independent_of_input_order() {
+        let mut a = setup();
+        let mut b = setup();
+        let actions = vec![
+            (PlayerId(2), Action::Heal(PlayerId(3))),
+            (PlayerId(0), Action::Protect(PlayerId(4))),
+            (PlayerId(1), Action::Kill(PlayerId(3))),
+        ];
+        let mut reversed = actions.clone();
+        reversed.reverse();
+        assert_eq!(resolve_night(&mut a, actions), resolve_night(&mut b, reversed));
+    }
+
+    #[test]
+    fn night0_setup_keeps_peeks_and_drops_kills() {
+        let mut state = setup();
+        state.assign_role(PlayerId(3), Role::Seer);
+        let actions = setup_actions_only(vec![
+            (PlayerId(3), Action::Investigate(PlayerId(1))),
+            (PlayerId(1), Action::Kill(PlayerId(3))),
+            (PlayerId(2), Action::Poison(PlayerId(4))),
+            (PlayerId(0), Action::Protect(PlayerId(4))),
+        ]);
+        let outcome = resolve_night(&mut state, actions);
+        assert!(outcome.deaths.is_empty());
+        assert_eq!(state.alive_players(), vec![PlayerId(0), PlayerId(1), PlayerId(2), PlayerId(3), PlayerId(4)]);
+        // The peek is stored exactly like a normal investigation.
+        assert_eq!(state.knowledge_of(PlayerId(3)).about(PlayerId(1)), Some(Alignment::Wolf));
+        // The dropped poison was never spent.
+        assert!(state.potions_of(PlayerId(2)).poison_available);
+    }
+
+    mod council {
+        use super::*;
+        use crate::player::ScriptedPlayer;
+
+        /// 0/1: Werewolves, 2/3/4: Villagers.
+        fn pack_setup(seed: u64) -> GameState {
+            let mut state = GameState::new(0..5, Phase::Night, seed);
+            state.assign_role(PlayerId(0), Role::Werewolf);
+            state.assign_role(PlayerId(1), Role::Werewolf);
+            state.assign_role(PlayerId(2), Role::Villager);
+            state.assign_role(PlayerId(3), Role::Villager);
+            state.assign_role(PlayerId(4), Role::Villager);
+            state
+        }
+
+        fn roster(
+            scripts: Vec<ScriptedPlayer>,
+        ) -> HashMap<PlayerId, Box<dyn Player>> {
+            scripts
+                .into_iter()
+                .enumerate()
+                .map(|(id, p)| (PlayerId(id as u32), Box::new(p) as Box<dyn Player>))
+                .collect()
+        }
+
+        #[tokio::test]
+        async fn pack_chats_then_votes_a_target() {
+            let mut state = pack_setup(0);
+            let players = roster(vec![
+                ScriptedPlayer::new()
+                    .will_act(Some(Action::WolfChat("take the quiet one".into())))
+                    .will_vote(PlayerId(3)),
+                ScriptedPlayer::new()
+                    .will_act(Some(Action::WolfChat("agreed".into())))
+                    .will_vote(PlayerId(3)),
+                ScriptedPlayer::new(),
+                ScriptedPlayer::new(),
+                ScriptedPlayer::new(),
+            ]);
+            let target =
+                run_wolf_council(&mut state, &players, &TurnPolicy::default(), WolfDeadlock::WolfNoKill).await;
+            assert_eq!(target, Some(PlayerId(3)));
+            assert_eq!(state.wolf_chat().len(), 2);
+            // Both wolves see the transcript; the villager sees nothing.
+            assert_eq!(state.context_for(PlayerId(1)).wolf_chat.len(), 2);
+            assert!(state.context_for(PlayerId(2)).wolf_chat.is_empty());
+        }
+
+        #[tokio::test]
+        async fn council_vote_is_secret_and_targets_no_wolf() {
+            let mut state = pack_setup(0);
+            let players = roster(vec![
+                // A wolf voting a packmate abstains instead.
+                ScriptedPlayer::new().will_vote(PlayerId(1)),
+                ScriptedPlayer::new().will_vote(PlayerId(4)),
+                ScriptedPlayer::new(),
+                ScriptedPlayer::new(),
+                ScriptedPlayer::new(),
+            ]);
+            let target =
+                run_wolf_council(&mut state, &players, &TurnPolicy::default(), WolfDeadlock::WolfNoKill).await;
+            assert_eq!(target, Some(PlayerId(4)));
+            // Nothing about the council reaches the event log.
+            assert!(!state
+                .log()
+                .iter()
+                .any(|e| matches!(e.kind, GameEventKind::VoteCast { .. })));
+        }
+
+        #[tokio::test]
+        async fn split_pack_ties_break_by_the_seeded_rng() {
+            let run = |seed| async move {
+                let mut state = pack_setup(seed);
+                let players = roster(vec![
+                    ScriptedPlayer::new().will_vote(PlayerId(2)),
+                    ScriptedPlayer::new().will_vote(PlayerId(3)),
+                    ScriptedPlayer::new(),
+                    ScriptedPlayer::new(),
+                    ScriptedPlayer::new(),
+                ]);
+                run_wolf_council(&mut state, &players, &TurnPolicy::default(), WolfDeadlock::WolfNoKill).await
+            };
+            let target = run(7).await;
+            assert!(target == Some(PlayerId(2)) || target == Some(PlayerId(3)));
+            // The seed fully determines the coin flip.
+            assert_eq!(run(7).await, run(7).await);
+        }
+
+        #[tokio::test]
+        async fn deadlocked_pack_under_no_kill_stays_home() {
+            let mut state = pack_setup(0);
+            // Nobody scripts a vote: both wolves abstain.
+            let players = roster(vec![
+                ScriptedPlayer::new(),
+                ScriptedPlayer::new(),
+                ScriptedPlayer::new(),
+                ScriptedPlayer::new(),
+                ScriptedPlayer::new(),
+            ]);
+            let target = run_wolf_council(
+                &mut state,
+                &players,
+                &TurnPolicy::default(),
+                WolfDeadlock::WolfNoKill,
+            )
+            .await;
+            assert_eq!(target, None);
+            assert!(state.log().iter().any(|e| matches!(
+                e.kind,
+                GameEventKind::WolfDeadlock { forced_target: None }
+            )));
+        }
+
+        #[tokio::test]
+        async fn an_alpha_vote_outweighs_a_packmates_in_a_tie() {
+            for seed in 0..10 {
+                let mut state = GameState::new(0..5, Phase::Night, seed);
+                state.assign_role(PlayerId(0), Role::AlphaWerewolf);
+                state.assign_role(PlayerId(1), Role::Werewolf);
+                state.assign_role(PlayerId(2), Role::Villager);
+                state.assign_role(PlayerId(3), Role::Villager);
+                state.assign_role(PlayerId(4), Role::Villager);
+                let players = roster(vec![
+                    ScriptedPlayer::new().will_vote(PlayerId(2)),
+                    ScriptedPlayer::new().will_vote(PlayerId(3)),
+                    ScriptedPlayer::new(),
+                    ScriptedPlayer::new(),
+                    ScriptedPlayer::new(),
+                ]);
+                let target = run_wolf_council(
+                    &mut state,
+                    &players,
+                    &TurnPolicy::default(),
+                    WolfDeadlock::WolfNoKill,
+                )
+                .await;
+                // A plain split would be a coin flip; the Alpha's double
+                // weight makes it 2-1, so no seed ever picks 3.
+                assert_eq!(target, Some(PlayerId(2)), "seed {seed}");
+            }
+        }
+
+        #[tokio::test]
+        async fn a_minion_reads_the_channel_but_holds_no_vote() {
+            let mut state = GameState::new(0..5, Phase::Night, 0);
+            state.assign_role(PlayerId(0), Role::Werewolf);
+            state.assign_role(PlayerId(1), Role::Minion);
+            state.assign_role(PlayerId(2), Role::Villager);
+            state.assign_role(PlayerId(3), Role::Villager);
+            state.assign_role(PlayerId(4), Role::Villager);
+            let players = roster(vec![
+                ScriptedPlayer::new()
+                    .will_act(Some(Action::WolfChat("the seer dies tonight".into())))
+                    .will_vote(PlayerId(2)),
+                // Scripted to vote 4; the council never asks.
+                ScriptedPlayer::new().will_vote(PlayerId(4)),
+                ScriptedPlayer::new(),
+                ScriptedPlayer::new(),
+                ScriptedPlayer::new(),
+            ]);
+            let target = run_wolf_council(
+                &mut state,
+                &players,
+                &TurnPolicy::default(),
+                WolfDeadlock::WolfNoKill,
+            )
+            .await;
+            assert_eq!(target, Some(PlayerId(2)));
+            // The Minion sees the transcript; town sees nothing.
+            assert_eq!(state.context_for(PlayerId(1)).wolf_chat.len(), 1);
+            assert!(state.context_for(PlayerId(2)).wolf_chat.is_empty());
+        }
+
+        #[tokio::test]
+        async fn deadlocked_pack_under_forced_random_picks_a_non_wolf() {
+            let mut state = pack_setup(0);
+            let players = roster(vec![
+                ScriptedPlayer::new(),
+                ScriptedPlayer::new(),
+                ScriptedPlayer::new(),
+                ScriptedPlayer::new(),
+                ScriptedPlayer::new(),
+            ]);
+            let target = run_wolf_council(
+                &mut state,
+                &players,
+                &TurnPolicy::default(),
+                WolfDeadlock::WolfForcedRandom,
+            )
+            .await;
+            let target = target.expect("a villager is still alive");
+            assert!(target >= PlayerId(2), "the forced kill must not hit a wolf");
+            assert!(state.log().iter().any(|e| e.kind
+                == GameEventKind::WolfDeadlock { forced_target: Some(target) }));
+        }
+
+        #[tokio::test]
+        async fn a_forced_kill_still_breaks_on_the_guards_protection() {
+            // 0/1: Werewolves, 2: the only other player — the forced
+            // target is deterministic, so the Guard can cover it.
+            let mut state = GameState::new(0..3, Phase::Night, 0);
+            state.assign_role(PlayerId(0), Role::Werewolf);
+            state.assign_role(PlayerId(1), Role::Werewolf);
+            state.assign_role(PlayerId(2), Role::Guard);
+            let players = roster(vec![
+                ScriptedPlayer::new(),
+                ScriptedPlayer::new(),
+                ScriptedPlayer::new(),
+            ]);
+            let target = run_wolf_council(
+                &mut state,
+                &players,
+                &TurnPolicy::default(),
+                WolfDeadlock::WolfForcedRandom,
+            )
+            .await;
+            assert_eq!(target, Some(PlayerId(2)));
+            // The forced kill resolves like any other: the protection wins.
+            let outcome = resolve_night(&mut state, vec![
+                (PlayerId(0), Action::Kill(PlayerId(2))),
+                (PlayerId(2), Action::Protect(PlayerId(2))),
+            ]);
+            assert!(outcome.deaths.is_empty());
+            assert_eq!(outcome.saved, vec![PlayerId(2)]);
+            assert!(state.is_alive(PlayerId(2)));
+        }
+
+        #[tokio::test]
+        async fn wolf_chat_never_leaks_into_a_villagers_view() {
+            let mut state = pack_setup(0);
+            let players = roster(vec![
+                ScriptedPlayer::new()
+                    .will_act(Some(Action::WolfChat("eat player 4 tonight".into())))
+                    .will_vote(PlayerId(4)),
+                ScriptedPlayer::new().will_vote(PlayerId(4)),
+                ScriptedPlayer::new(),
+                ScriptedPlayer::new(),
+                ScriptedPlayer::new(),
+            ]);
+            run_wolf_council(&mut state, &players, &TurnPolicy::default(), WolfDeadlock::WolfNoKill).await;
+            let villager = serde_json::to_string(&state.player_view(PlayerId(2))).unwrap();
+            assert!(!villager.contains("eat player 4"));
+            let wolf = serde_json::to_string(&state.player_view(PlayerId(1))).unwrap();
+            assert!(wolf.contains("eat player 4"));
+        }
+    }
+}
@@ -0,0 +1,59 @@
+//! Observers: read-only hooks fed every [`GameEvent`] as the driver
+//! produces it.
+//!
+//! Observers let metrics, a live UI, and a file log all watch the same
+//! game without the engine knowing about any of them. They are notified
+//! synchronously, in registration order, and each event is delivered
+//! exactly once, in log order.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::game::event::GameEvent;
+
+/// A read-only hook notified of every event a running game records.
+///
+/// Implementations must be cheap and non-blocking: `on_event` runs on the
+/// driver's path, between game steps.
+pub trait GameObserver: Send + Sync {
+    /// Called once per recorded event, in log order.
+    fn on_event(&self, event: &GameEvent);
+}
+
+/// An observer that forwards every event to the [`tracing`] ecosystem,
+/// one `INFO`-level event each, carrying the day and the event kind.
+///
+/// Install a `tracing` subscriber to route these to stderr, a file, or a
+/// collector.
+#[cfg(feature = "tracing")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TracingObserver;
+
+#[cfg(feature = "tracing")]
+impl GameObserver for TracingObserver {
+    fn on_event(&self, event: &GameEvent) {
+        tracing::info!(day = event.day, kind = ?event.kind, "game event");
+    }
+}
+
+/// An observer that only counts events; for tests and smoke checks.
+#[derive(Debug, Default)]
+pub struct CountingObserver {
+    count: AtomicUsize,
+}
+
+impl CountingObserver {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// How many events this observer has seen so far.
+    pub fn count(&self) -> usize {
+        self.count.load(Ordering::Relaxed)
+    }
+}
+
+impl GameObserver for CountingObserver {
+    fn on_event(&self, _event: &GameEvent) {
+        self.count.fetch_add(1, Ordering::Relaxed);
+    }
+}
@@ -0,0 +1,397 @@
+//! Replaying a saved event log against a fresh state, verifying that each
+//! event was legal at the point it happened.
+//!
+//! The engine only ever appends consistent events, so a log that fails
+//! replay was either tampered with or produced by a bug. Replay is also
+//! the cheap way to reconstruct a final roster from a log alone, without
+//! re-running any players.
+
+use crate::config::{FirstPhase, GameConfig};
+use crate::game::action::Action;
+use crate::game::event::{GameEvent, GameEventKind};
+use crate::game::night::DeathCause;
+use crate::game::state::{GameState, Phase, PlayerId};
+use crate::roles::NightEffect;
+
+/// A log inconsistency, naming the first offending event.
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+#[error("event {index} is inconsistent: {reason}")]
+pub struct ReplayError {
+    /// Index into the replayed log.
+    pub index: usize,
+    /// What made the event illegal given the state at that point.
+    pub reason: String,
+}
+
+/// Re-applies a log to a fresh state built from `config`, checking each
+/// event is legal given the state at that point: dead players can't
+/// speak or vote, deaths need a matching recorded cause, a wolf kill
+/// can't land on a player the same night's log shows as protected, and
+/// phase changes must follow the Night → Day → Voting cycle.
+///
+/// On success the returned state carries the same roster, phase, day and
+/// log as the original run. Returns a [`ReplayError`] naming the first
+/// offending event otherwise.
+///
+/// Role assignments are not part of the log; roles learned from revealed
+/// deaths are re-applied, everything else stays unassigned.
+pub fn replay(config: &GameConfig, log: &[GameEvent]) -> Result<GameState, ReplayError> {
+    let first_phase = match config.first_phase {
+        FirstPhase::Night => Phase::Night,
+        FirstPhase::Day => Phase::Day,
+    };
+    let mut state =
+        GameState::new(0..config.player_count as u32, first_phase, 0);
+    let fail = |index: usize, reason: String| Err(ReplayError { index, reason });
+
+    // Per-night bookkeeping, reset at every phase change into Night.
+    let mut kills_tonight: Vec<PlayerId> = Vec::new();
+    let mut poisons_tonight: Vec<PlayerId> = Vec::new();
+    let mut saved_tonight: Vec<PlayerId> = Vec::new();
+    // Hunter-shot victims announced but not yet recorded as dead.
+    let mut pending_shots: Vec<PlayerId> = Vec::new();
+
+    for (index, event) in log.iter().enumerate() {
+        match &event.kind {
+            GameEventKind::PhaseChanged { from, to } => {
+                if *from != state.phase() {
+                    return fail(
+                        index,
+                        format!(
+                            "phase change from {from:?} but the game is in {:?}",
+                            state.phase()
+                        ),
+                    );
+                }
+                if *from == Phase::Night {
+                    state.set_day(state.day() + 1);
+                }
+                if *to == Phase::Night {
+                    kills_tonight.clear();
+                    poisons_tonight.clear();
+                    saved_tonight.clear();
+                }
+                state.set_phase(*to);
+            }
+            GameEventKind::PlayerSpoke { player, .. } => {
+                if !state.is_alive(*player) {
+                    return fail(index, format!("dead player {player} speaks"));
+                }
+            }
+            GameEventKind::GraveyardChat { player, .. } => {
+                if state.is_alive(*player) {
+                    return fail(
+                        index,
+                        format!("living player {player} speaks from the graveyard"),
+                    );
+                }
+            }
+            GameEventKind::Accusation { accuser, accused, .. } => {
+                if !state.is_alive(*accuser) {
+                    return fail(index, format!("dead player {accuser} accuses"));
+                }
+                if !state.is_alive(*accused) {
+                    return fail(index, format!("player {accused} is accused while dead"));
+                }
+            }
+            GameEventKind::Defense { player, .. } => {
+                if !state.is_alive(*player) {
+                    return fail(index, format!("dead player {player} defends"));
+                }
+            }
+            // A lynch victim speaks while still alive; a night victim at
+            // dawn, already dead — liveness says nothing either way.
+            GameEventKind::LastWords { .. } => {}
+            GameEventKind::VoteCast { voter, target, .. } => {
+                if !state.is_alive(*voter) {
+                    return fail(index, format!("dead player {voter} votes"));
+                }
+                if let Some(target) = target {
+                    if !state.is_alive(*target) {
+                        return fail(
+                            index,
+                            format!("vote targets player {target}, who is dead"),
+                        );
+                    }
+                }
+            }
+            GameEventKind::NightAction { actor, action } => {
+                if !state.is_alive(*actor) {
+                    return fail(index, format!("dead player {actor} acts at night"));
+                }
+                match claimed_effect(action) {
+                    NightEffect::Attack(target) => kills_tonight.push(target),
+                    NightEffect::Poison(target) => poisons_tonight.push(target),
+                    NightEffect::Protect(target) | NightEffect::Heal(target) => {
+                        saved_tonight.push(target)
+                    }
+                    // `claimed_effect` is structural and never yields a
+                    // Sacrifice — only a Bodyguard behavior does.
+                    NightEffect::Reveal(_)
+                    | NightEffect::Sacrifice(_)
+                    | NightEffect::None => {}
+                }
+            }
+            GameEventKind::InvalidAction { action, .. } => {
+                // A rejected protection or heal saves nobody after all.
+                if let NightEffect::Protect(target) | NightEffect::Heal(target) =
+                    claimed_effect(action)
+                {
+                    if let Some(pos) = saved_tonight.iter().position(|&t| t == target) {
+                        saved_tonight.remove(pos);
+                    }
+                }
+            }
+            GameEventKind::HunterShot { hunter, target } => {
+                if state.is_alive(*hunter) {
+                    return fail(
+                        index,
+                        format!("player {hunter} takes a dying shot while alive"),
+                    );
+                }
+                if !state.is_alive(*target) {
+                    return fail(index, format!("shot targets player {target}, who is dead"));
+                }
+                pending_shots.push(*target);
+            }
+            GameEventKind::PlayerDied { player, cause, role, .. } => {
+                if !state.is_alive(*player) {
+                    return fail(index, format!("player {player} dies twice"));
+                }
+                match cause {
+                    DeathCause::WolfKill => {
+                        if !kills_tonight.contains(player) {
+                            return fail(
+                                index,
+                                format!("player {player} dies to wolves without a recorded kill"),
+                            );
+                        }
+                        if saved_tonight.contains(player) {
+                            return fail(
+                                index,
+                                format!(
+                                    "player {player} dies to wolves despite a recorded protection"
+                                ),
+                            );
+                        }
+                    }
+                    DeathCause::Poison => {
+                        if !poisons_tonight.contains(player) {
+                            return fail(
+                                index,
+                                format!("player {player} dies to poison nobody threw"),
+                            );
+                        }
+                    }
+                    DeathCause::Vote => {
+                        if state.phase() != Phase::Voting {
+                            return fail(
+                                index,
+                                format!(
+                                    "player {player} is voted out during {:?}",
+                                    state.phase()
+                                ),
+                            );
+                        }
+                    }
+                    DeathCause::HunterShot => {
+                        match pending_shots.iter().position(|&t| t == *player) {
+                            Some(pos) => {
+                                pending_shots.remove(pos);
+                            }
+                            None => {
+                                return fail(
+                                    index,
+                                    format!("player {player} dies to a shot nobody fired"),
+                                );
+                            }
+                        }
+                    }
+                    // Relationships are hidden setup, never part of the
+                    // log, so a grief death cannot be cross-checked here.
+                    DeathCause::LoverGrief => {}
+                }
+                state.kill(*player);
+                if let Some(role) = role {
+                    state.assign_role(*player, *role);
+                }
+            }
+            GameEventKind::SpeakingOrder { order } => {
+                if let Some(dead) = order.iter().find(|id| !state.is_alive(**id)) {
+                    return fail(
+                        index,
+                        format!("speaking order includes dead player {dead}"),
+                    );
+                }
+            }
+            GameEventKind::GameEnded { .. } => {
+                state.set_phase(Phase::GameOver);
+            }
+            GameEventKind::FallbackTriggered { .. }
+            | GameEventKind::PlayerRefused { .. }
+            | GameEventKind::WolfDeadlock { .. }
+            | GameEventKind::BudgetExceeded { .. }
+            | GameEventKind::DiscussionEnded { .. }
+            | GameEventKind::PlayerModel { .. } => {}
+        }
+        state.push_event(event.clone());
+    }
+
+    Ok(state)
+}
+
+/// The structural effect an action claims, independent of any role
+/// behavior — replay has no role assignments to consult.
+fn claimed_effect(action: &Action) -> NightEffect {
+    match action {
+        Action::Kill(t) => NightEffect::Attack(*t),
+        Action::Protect(t) => NightEffect::Protect(*t),
+        Action::Investigate(t) => NightEffect::Reveal(*t),
+        Action::Heal(t) => NightEffect::Heal(*t),
+        Action::Poison(t) => NightEffect::Poison(*t),
+        _ => NightEffect::None,
+    }
+}
+
+/// Convenience check: replays the log and compares the surviving roster
+/// against `expected`, as reported by the original run.
+pub fn verify_survivors(
+    config: &GameConfig,
+    log: &[GameEvent],
+    expected: &[PlayerId],
+) -> Result<(), ReplayError> {
+    let state = replay(config, log)?;
+    if state.alive_players() != expected {
+        return Err(ReplayError {
+            index: log.len(),
+            reason: format!(
+                "log replays to survivors {:?}, expected {expected:?}",
+                state.alive_players()
+            ),
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    use crate::game::builder::GameBuilder;
+    use crate::game::runner::run_game_with;
+    use crate::player::ScriptedPlayer;
+    use crate::roles::Role;
+
+    fn small_config() -> GameConfig {
+        GameConfig {
+            player_count: 4,
+            roles: BTreeMap::from([(Role::Werewolf, 1), (Role::Villager, 3)]),
+            ..GameConfig::default()
+        }
+    }
+
+    async fn real_log() -> (GameConfig, crate::game::runner::GameResult) {
+        let config = small_config();
+        let mut builder = GameBuilder::new().config(config.clone()).seed(3);
+        for id in 0..4 {
+            let p = ScriptedPlayer::new().will_vote(PlayerId(0)).will_vote(PlayerId(1)).will_vote(PlayerId(2));
+            builder = builder.player(PlayerId(id), Box::new(p));
+        }
+        let (state, players) = builder.build_with_players().unwrap();
+        let result = run_game_with(state, players, &config).await.unwrap();
+        (config, result)
+    }
+
+    #[tokio::test]
+    async fn a_real_log_replays_cleanly() {
+        let (config, result) = real_log().await;
+        let state = replay(&config, &result.log).expect("engine logs are consistent");
+        assert_eq!(state.alive_players(), result.survivors);
+        assert_eq!(state.phase(), Phase::GameOver);
+        assert_eq!(state.log().len(), result.log.len());
+        assert!(verify_survivors(&config, &result.log, &result.survivors).is_ok());
+    }
+
+    #[tokio::test]
+    async fn a_tampered_vote_is_caught_with_its_index() {
+        let (config, result) = real_log().await;
+        let mut log = result.log.clone();
+        // Find the first death, then forge a vote by that dead player.
+        let death_at = log
+            .iter()
+            .position(|e| matches!(e.kind, GameEventKind::PlayerDied { .. }))
+            .unwrap();
+        let GameEventKind::PlayerDied { player: dead, .. } = log[death_at].kind else {
+            unreachable!()
+        };
+        let forged = GameEvent::now(1, GameEventKind::VoteCast {
+            voter: dead,
+            target: None,
+            reason: None,
+        });
+        log.insert(death_at + 1, forged);
+        let err = replay(&config, &log).unwrap_err();
+        assert_eq!(err.index, death_at + 1);
+        assert!(err.reason.contains("dead player"));
+    }
+
+    #[test]
+    fn a_kill_through_a_protection_is_caught() {
+        let config = small_config();
+        let log = vec![
+            GameEvent::now(0, GameEventKind::NightAction {
+                actor: PlayerId(1),
+                action: Action::Protect(PlayerId(3)),
+            }),
+            GameEvent::now(0, GameEventKind::NightAction {
+                actor: PlayerId(0),
+                action: Action::Kill(PlayerId(3)),
+            }),
+            GameEvent::now(0, GameEventKind::PlayerDied {
+                player: PlayerId(3),
+                cause: crate::game::night::DeathCause::WolfKill,
+                role: None,
+                alignment: None,
+            }),
+        ];
+        let err = replay(&config, &log).unwrap_err();
+        assert_eq!(err.index, 2);
+        assert!(err.reason.contains("protection"));
+    }
+
+    #[test]
+    fn a_death_without_a_recorded_kill_is_caught() {
+        let config = small_config();
+        let log = vec![GameEvent::now(0, GameEventKind::PlayerDied {
+            player: PlayerId(2),
+            cause: crate::game::night::DeathCause::WolfKill,
+            role: None,
+            alignment: None,
+        })];
+        let err = replay(&config, &log).unwrap_err();
+        assert_eq!(err.index, 0);
+        assert!(err.reason.contains("without a recorded kill"));
+    }
+
+    #[test]
+    fn an_out_of_order_phase_change_is_caught() {
+        let config = small_config();
+        let log = vec![GameEvent::now(1, GameEventKind::PhaseChanged {
+            from: Phase::Voting,
+            to: Phase::Night,
+        })];
+        let err = replay(&config, &log).unwrap_err();
+        assert_eq!(err.index, 0);
+        assert!(err.reason.contains("phase change"));
+    }
+
+    #[tokio::test]
+    async fn replay_is_a_pure_function_of_the_log() {
+        let (config, result) = real_log().await;
+        let a = replay(&config, &result.log).unwrap();
+        let b = replay(&config, &result.log).unwrap();
+        assert_eq!(a.alive_players(), b.alive_players());
+        assert_eq!(a.day(), b.day());
+    }
+}
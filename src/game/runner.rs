@@ -0,0 +1,701 @@
+//! The top-level game driver: one call from built state to final result.
+//!
+//! [`run_game`] is the function most users actually want — it owns the
+//! Night → Day → Voting cycle, dispatches every required decision to the
+//! right [`Player`], and keeps going until a win condition fires. All the
+//! engine's robustness applies: timeouts fall back per the [`TurnPolicy`],
+//! and illegal actions are logged as [`GameEventKind::InvalidAction`] and
+//! skipped rather than crashing the game.
+//!
+//! [`TurnPolicy`]: crate::game::timeout::TurnPolicy
+
+use std::collections::HashMap;
+
+use crate::config::{GameConfig, VotingMode};
+use crate::game::action::Action;
+use crate::game::day::{run_discussion, run_graveyard, run_last_words};
+use crate::game::death::{apply_death, resolve_hunter_shots};
+use crate::game::event::{GameEvent, GameEventKind};
+use crate::game::night::{
+    DeathCause, resolve_night_with, run_wolf_council, setup_actions_only,
+};
+use crate::game::observer::GameObserver;
+use crate::game::state::{GameState, Phase, PlayerId};
+use crate::game::timeout::{timed_night_actions, timed_vote_with_reason};
+use crate::game::validate::validate_action;
+use crate::game::vote::{VoteOutcome, run_runoff, tally};
+use crate::game::win::check_win;
+use crate::player::Player;
+use crate::roles::Alignment;
+
+/// Hard ceiling on phase steps so a pathological game cannot loop forever.
+const MAX_STEPS: u32 = 1_000;
+
+/// Code run at phase boundaries with full mutable access to the state,
+/// for custom rule injection ("on Day 3 a random player goes silent").
+///
+/// Both methods default to no-ops, so a hook implements only the boundary
+/// it cares about. End-of-phase hooks run before the win check that
+/// decides the next phase, so a hook-induced death is evaluated like any
+/// other.
+///
+/// A hook that mutates state without logging it desyncs replays: the log
+/// is supposed to reconstruct the game on its own. Record every effect as
+/// events — kill via [`apply_death`], which logs the death, rather than
+/// [`GameState::kill`].
+///
+/// [`GameState::kill`]: crate::game::state::GameState::kill
+pub trait PhaseHook: Send + Sync {
+    /// Called when `phase` begins, before any player is consulted.
+    fn on_phase_start(&self, state: &mut GameState, phase: Phase) {
+        let _ = (state, phase);
+    }
+
+    /// Called when `phase` ends, before the win check.
+    fn on_phase_end(&self, state: &mut GameState, phase: Phase) {
+        let _ = (state, phase);
+    }
+}
+
+/// How a game concluded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GameOutcome {
+    /// An alignment met its win condition.
+    Win(Alignment),
+    /// The [`GameConfig::max_days`] cap was reached with no winner.
+    ///
+    /// [`GameConfig::max_days`]: crate::config::GameConfig::max_days
+    Draw,
+    /// The engine's internal step ceiling fired before any end condition —
+    /// a bug guard, not a rules outcome.
+    Undecided,
+}
+
+/// The outcome of a completed game.
+#[derive(Debug, Clone)]
+pub struct GameResult {
+    /// How the game concluded: a win, a draw at the day cap, or the step
+    /// ceiling.
+    pub outcome: GameOutcome,
+    /// The winning alignment, or `None` for a draw or a hit step ceiling.
+    pub winner: Option<Alignment>,
+    /// The day the game ended on.
+    pub days: u32,
+    /// The full event log, for narration or analysis.
+    pub log: Vec<GameEvent>,
+    /// Players still alive at the end, in seat order.
+    pub survivors: Vec<PlayerId>,
+}
+
+/// Drives a game to completion under the default [`GameConfig`] knobs:
+/// plurality voting, one discussion round, standard rule variants.
+///
+/// The state should already have roles assigned — build it by hand or with
+/// a [`GameBuilder`](crate::game::builder::GameBuilder).
+pub async fn run_game(
+    state: GameState,
+    players: HashMap<PlayerId, Box<dyn Player>>,
+) -> crate::error::Result<GameResult> {
+    run_game_with(state, players, &GameConfig::default()).await
+}
+
+/// [`run_game`] with explicit [`GameConfig`] knobs: timeout policy,
+/// discussion settings, voting mode, and rule variants all come from the
+/// config. The config's role multiset is not consulted — roles are taken
+/// from the state as built.
+pub async fn run_game_with(
+    state: GameState,
+    players: HashMap<PlayerId, Box<dyn Player>>,
+    config: &GameConfig,
+) -> crate::error::Result<GameResult> {
+    run_game_observed(state, players, config, &[]).await
+}
+
+/// [`run_game_with`] plus a set of [`GameObserver`]s notified of every
+/// recorded event, synchronously and in registration order.
+pub async fn run_game_observed(
+    state: GameState,
+    players: HashMap<PlayerId, Box<dyn Player>>,
+    config: &GameConfig,
+    observers: &[&dyn GameObserver],
+) -> crate::error::Result<GameResult> {
+    run_game_hooked(state, players, config, observers, &[]).await
+}
+
+/// [`run_game_observed`] plus a set of [`PhaseHook`]s invoked at every
+/// phase boundary, in registration order. No hooks run by default; see
+/// [`PhaseHook`] for the replay caveats.
+pub async fn run_game_hooked(
+    mut state: GameState,
+    players: HashMap<PlayerId, Box<dyn Player>>,
+    config: &GameConfig,
+    observers: &[&dyn GameObserver],
+    hooks: &[&dyn PhaseHook],
+) -> crate::error::Result<GameResult> {
+    state.set_death_reveal(config.death_reveal);
+    state.set_witch_rules(config.witch_rules());
+    state.set_guard_rules(config.guard_rules());
+    state.set_win_rules(config.win_rules());
+    state.set_show_suspicion(config.suspicion_scores);
+    state.set_open_voting(config.voting_mode == VotingMode::Open);
+    state.set_max_days(config.max_days);
+    let policy = config.turn_policy();
+    let hunter_rules = config.hunter_rules();
+    let discussion = config.discussion_settings();
+    let mut notified = 0;
+
+    // Tag model-backed seats up front so every later event in the log can
+    // be attributed to a model.
+    let mut seats: Vec<_> = players.keys().copied().collect();
+    seats.sort_unstable();
+    for id in seats {
+        if let Some(model) = players[&id].model_id() {
+            state.record(GameEventKind::PlayerModel { player: id, model });
+        }
+    }
+    notify(&state, &mut notified, observers);
+
+    for _ in 0..MAX_STEPS {
+        let phase = state.phase();
+        if phase != Phase::GameOver {
+            for hook in hooks {
+                hook.on_phase_start(&mut state, phase);
+            }
+        }
+        match state.phase() {
+            Phase::Night => {
+                // A peaceful Night-0 runs setup actions (peeks,
+                // protections) only; there is no kill to coordinate.
+                let peaceful = config.peaceful_night0 && state.day() == 0;
+                let mut actions = Vec::new();
+                if config.wolf_coordination && !peaceful {
+                    // The pack decides together; the agreed kill is filed
+                    // under the lowest living wolf seat.
+                    let speaker = state.alive_players().into_iter().find(|&id| {
+                        state.role_of(id).is_some_and(|r| r.alignment() == Alignment::Wolf)
+                    });
+                    let target =
+                        run_wolf_council(&mut state, &players, &policy, config.wolf_deadlock)
+                            .await;
+                    if let (Some(wolf), Some(target)) = (speaker, target) {
+                        actions.push((wolf, Action::Kill(target)));
+                    }
+                }
+                // Independent night roles (Seer, Guard, Witch, ...) are
+                // queried concurrently; only the interactive wolf council
+                // above stays sequential.
+                let mut actors: Vec<(PlayerId, &dyn Player)> = Vec::new();
+                for &id in &state.alive_players() {
+                    let acts = state
+                        .role_of(id)
+                        .map(|r| r.info().acts_at_night)
+                        .unwrap_or(false);
+                    let in_council = config.wolf_coordination
+                        && state.role_of(id).is_some_and(|r| r.alignment() == Alignment::Wolf);
+                    if !acts || in_council {
+                        continue;
+                    }
+                    let Some(player) = players.get(&id) else { continue };
+                    actors.push((id, player.as_ref()));
+                }
+                for (id, action) in
+                    timed_night_actions(&actors, &mut state, &policy).await
+                {
+                    let Some(action) = action else { continue };
+                    // An illegal action (dead target, role overreach,
+                    // spent potion, ...) is logged and dropped — the
+                    // fallback for a bad answer is no action at all.
+                    if validate_action(&state, id, &action).is_err() {
+                        state.record(GameEventKind::InvalidAction { player: id, action });
+                        continue;
+                    }
+                    actions.push((id, action));
+                }
+                let actions =
+                    if peaceful { setup_actions_only(actions) } else { actions };
+                let outcome = resolve_night_with(&mut state, actions, &config.registry);
+                if config.last_words && !outcome.deaths.is_empty() {
+                    let victims: Vec<PlayerId> =
+                        outcome.deaths.iter().map(|&(id, _)| id).collect();
+                    run_last_words(&mut state, &players, &policy, &victims).await;
+                }
+                resolve_hunter_shots(&mut state, &players, &outcome.deaths, &hunter_rules)
+                    .await;
+            }
+            Phase::Day => {
+                run_discussion(&mut state, &players, &policy, &discussion).await;
+            }
+            Phase::Voting => {
+                let outcome = match config.voting_mode {
+                    VotingMode::Plurality => {
+                        let mut votes = Vec::new();
+                        let mut cache = crate::game::state::ContextCache::new();
+                        for &id in &state.alive_players() {
+                            let Some(player) = players.get(&id) else { continue };
+                            let ctx = cache.context_for(&state, id);
+                            let (target, reason) =
+                                timed_vote_with_reason(player.as_ref(), &ctx, &mut state, &policy)
+                                    .await;
+                            // A vote for a dead or unknown player counts as
+                            // an abstention.
+                            let target = target.filter(|t| state.is_alive(*t));
+                            state.record(GameEventKind::VoteCast {
+                                voter: id,
+                                target,
+                                reason,
+                            });
+                            votes.push((id, target));
+                        }
+                        tally(
+                            &votes,
+                            crate::game::vote::TieResolution::default(),
+                            state.rng_mut(),
+                        )
+                        .outcome
+                    }
+                    VotingMode::Runoff => {
+                        run_runoff(&mut state, &players, &policy, &config.runoff_settings())
+                            .await
+                    }
+                    VotingMode::Open => {
+                        crate::game::vote::run_open_vote(
+                            &mut state,
+                            &players,
+                            &policy,
+                            config.vote_change_rounds,
+                            crate::game::vote::TieResolution::default(),
+                        )
+                        .await
+                    }
+                    VotingMode::Secret => {
+                        crate::game::vote::run_secret_vote(
+                            &mut state,
+                            &players,
+                            &policy,
+                            crate::game::vote::TieResolution::default(),
+                        )
+                        .await
+                    }
+                };
+                if let VoteOutcome::Eliminated(eliminated) = outcome {
+                    if config.last_words {
+                        run_last_words(&mut state, &players, &policy, &[eliminated]).await;
+                    }
+                    let deaths = apply_death(&mut state, eliminated, DeathCause::Vote);
+                    resolve_hunter_shots(&mut state, &players, &deaths, &hunter_rules)
+                        .await;
+                }
+            }
+            Phase::GameOver => break,
+        }
+        for hook in hooks {
+            hook.on_phase_end(&mut state, phase);
+        }
+        if config.graveyard_chat {
+            run_graveyard(&mut state, &players, &policy).await;
+        }
+        state.advance();
+        notify(&state, &mut notified, observers);
+    }
+    notify(&state, &mut notified, observers);
+
+    let winner = check_win(&state);
+    let outcome = match winner {
+        Some(winner) => GameOutcome::Win(winner),
+        // GameOver with no winner only happens when the day cap fired.
+        None if state.phase() == Phase::GameOver => GameOutcome::Draw,
+        None => GameOutcome::Undecided,
+    };
+    Ok(GameResult {
+        outcome,
+        winner,
+        days: state.day(),
+        survivors: state.alive_players(),
+        log: state.log().to_vec(),
+    })
+}
+
+/// Delivers every log event past `notified` to each observer, in log
+/// order then registration order.
+fn notify(state: &GameState, notified: &mut usize, observers: &[&dyn GameObserver]) {
+    for event in &state.log()[*notified..] {
+        for observer in observers {
+            observer.on_event(event);
+        }
+    }
+    *notified = state.log().len();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    use crate::config::FirstPhase;
+    use crate::game::builder::GameBuilder;
+    use crate::player::ScriptedPlayer;
+    use crate::roles::Role;
+
+    /// 0: Werewolf, 1: Seer, 2-4: Villagers; the town lynches seat 2 on
+    /// day 1 and the wolf on day 2.
+    fn night0_config(first_phase: FirstPhase) -> GameConfig {
+        GameConfig {
+            player_count: 5,
+            roles: BTreeMap::from([
+                (Role::Werewolf, 1),
+                (Role::Seer, 1),
+                (Role::Villager, 3),
+            ]),
+            first_phase,
+            peaceful_night0: true,
+            ..GameConfig::default()
+        }
+    }
+
+    fn investigations_in(log: &[crate::game::event::GameEvent]) -> usize {
+        log.iter()
+            .filter(|e| {
+                matches!(e.kind, GameEventKind::NightAction {
+                    action: Action::Investigate(_),
+                    ..
+                })
+            })
+            .count()
+    }
+
+    async fn play(first_phase: FirstPhase) -> GameResult {
+        let config = night0_config(first_phase);
+        let night0 = first_phase == FirstPhase::Night;
+        let mut builder = GameBuilder::new()
+            .config(config.clone())
+            .assign(PlayerId(0), Role::Werewolf)
+            .assign(PlayerId(1), Role::Seer);
+        for id in 0..5 {
+            let mut p = ScriptedPlayer::new().will_vote(PlayerId(2)).will_vote(PlayerId(0));
+            if id == 0 {
+                // The Night-0 kill attempt must be dropped by the variant.
+                if night0 {
+                    p = p.will_act(Some(Action::Kill(PlayerId(1))));
+                }
+                p = p.will_act(Some(Action::Kill(PlayerId(3))));
+            }
+            if id == 1 {
+                if night0 {
+                    p = p.will_act(Some(Action::Investigate(PlayerId(0))));
+                }
+                p = p.will_act(Some(Action::Investigate(PlayerId(4))));
+            }
+            builder = builder.player(PlayerId(id), Box::new(p));
+        }
+        let (state, players) = builder.build_with_players().unwrap();
+        run_game_with(state, players, &config).await.unwrap()
+    }
+
+    /// Votes a fixed target and keeps a copy of the public log it saw at
+    /// vote time, so tests can check what earlier voters made public.
+    struct ProbeVoter {
+        target: PlayerId,
+        seen: std::sync::Arc<std::sync::Mutex<Vec<String>>>,
+    }
+
+    #[async_trait::async_trait]
+    impl crate::player::Player for ProbeVoter {
+        async fn vote(&self, ctx: &crate::player::GameContext) -> PlayerId {
+            self.seen.lock().unwrap().extend(ctx.public_log.iter().cloned());
+            self.target
+        }
+
+        async fn night_action(
+            &self,
+            _ctx: &crate::player::GameContext,
+        ) -> Option<Action> {
+            None
+        }
+
+        async fn speak(&self, _ctx: &crate::player::GameContext) -> String {
+            String::new()
+        }
+    }
+
+    #[tokio::test]
+    async fn a_vote_reason_reaches_later_voters_before_they_vote() {
+        let config = night0_config(FirstPhase::Day);
+        let seen = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let mut builder = GameBuilder::new().config(config.clone()).seed(7);
+        for id in 0..4 {
+            let mut p = ScriptedPlayer::new();
+            p = if id == 0 {
+                p.will_vote_because(PlayerId(2), "Seat 2 dodged every question.")
+            } else {
+                p.will_vote(PlayerId(2))
+            };
+            builder = builder.player(PlayerId(id), Box::new(p.will_vote(PlayerId(0))));
+        }
+        // Seat 4 votes last and records the context it was given.
+        builder = builder.player(PlayerId(4), Box::new(ProbeVoter {
+            target: PlayerId(2),
+            seen: seen.clone(),
+        }));
+        let (state, players) = builder.build_with_players().unwrap();
+
+        let result = run_game_with(state, players, &config).await.unwrap();
+
+        assert!(result.log.iter().any(|e| matches!(
+            &e.kind,
+            GameEventKind::VoteCast { voter: PlayerId(0), reason: Some(r), .. }
+                if r == "Seat 2 dodged every question."
+        )));
+        assert!(seen
+            .lock()
+            .unwrap()
+            .contains(&"Player 0 votes for Player 2: Seat 2 dodged every question.".to_string()));
+    }
+
+    #[tokio::test]
+    async fn a_freshly_lynched_player_speaks_in_the_graveyard_the_same_phase() {
+        let mut config = night0_config(FirstPhase::Day);
+        config.graveyard_chat = true;
+        let mut builder = GameBuilder::new().config(config.clone()).seed(7);
+        for id in 0..5 {
+            let mut p = ScriptedPlayer::new().will_vote(PlayerId(2)).will_vote(PlayerId(0));
+            if id == 2 {
+                // First speech is the day-1 discussion turn; the second is
+                // only reachable from the graveyard after the lynch.
+                p = p.will_say("").will_say("I was innocent.");
+            }
+            builder = builder.player(PlayerId(id), Box::new(p));
+        }
+        let (state, players) = builder.build_with_players().unwrap();
+
+        let result = run_game_with(state, players, &config).await.unwrap();
+
+        let ghost_line = result.log.iter().find_map(|e| match &e.kind {
+            GameEventKind::GraveyardChat { player: PlayerId(2), text } => Some((e.day, text.clone())),
+            _ => None,
+        });
+        assert_eq!(ghost_line, Some((1, "I was innocent.".to_string())));
+    }
+
+    /// Speaks nothing but keeps a copy of the public log it saw at speech
+    /// time, and always votes the same target.
+    struct ProbeSpeaker {
+        target: PlayerId,
+        seen: std::sync::Arc<std::sync::Mutex<Vec<String>>>,
+    }
+
+    #[async_trait::async_trait]
+    impl crate::player::Player for ProbeSpeaker {
+        async fn vote(&self, _ctx: &crate::player::GameContext) -> PlayerId {
+            self.target
+        }
+
+        async fn night_action(
+            &self,
+            _ctx: &crate::player::GameContext,
+        ) -> Option<Action> {
+            None
+        }
+
+        async fn speak(&self, ctx: &crate::player::GameContext) -> String {
+            self.seen.lock().unwrap().extend(ctx.public_log.iter().cloned());
+            String::new()
+        }
+    }
+
+    #[tokio::test]
+    async fn a_lynched_players_last_words_precede_the_death_and_reach_the_next_day() {
+        let mut config = night0_config(FirstPhase::Day);
+        config.last_words = true;
+        let seen = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let mut builder =
+            GameBuilder::new().config(config.clone()).seed(7).assign(PlayerId(0), Role::Werewolf);
+        for id in 0..4 {
+            let mut p = ScriptedPlayer::new().will_vote(PlayerId(2)).will_vote(PlayerId(0));
+            if id == 2 {
+                // First speech is the day-1 discussion turn; the second is
+                // the dying statement after the lynch decision.
+                p = p.will_say("").will_say("The wolf is Player 0.");
+            }
+            builder = builder.player(PlayerId(id), Box::new(p));
+        }
+        // Seat 4 records the public log it speaks from on each day.
+        builder = builder.player(PlayerId(4), Box::new(ProbeSpeaker {
+            target: PlayerId(0),
+            seen: seen.clone(),
+        }));
+        let (state, players) = builder.build_with_players().unwrap();
+
+        let result = run_game_with(state, players, &config).await.unwrap();
+
+        let words = result.log.iter().position(|e| {
+            matches!(&e.kind, GameEventKind::LastWords { player: PlayerId(2), text }
+                if text == "The wolf is Player 0.")
+        });
+        let death = result.log.iter().position(|e| {
+            matches!(e.kind, GameEventKind::PlayerDied { player: PlayerId(2), .. })
+        });
+        assert!(words.expect("last words are logged") < death.expect("the lynch lands"));
+        // Day 2's discussion context still carries the dying statement.
+        assert!(seen
+            .lock()
+            .unwrap()
+            .contains(&"Player 2 (last words): The wolf is Player 0.".to_string()));
+    }
+
+    #[tokio::test]
+    async fn a_night_victim_speaks_last_words_at_dawn() {
+        let mut config = night0_config(FirstPhase::Day);
+        config.last_words = true;
+        let mut builder =
+            GameBuilder::new().config(config.clone()).seed(7).assign(PlayerId(0), Role::Werewolf);
+        for id in 0..5 {
+            let mut p = ScriptedPlayer::new().will_vote(PlayerId(4)).will_vote(PlayerId(0));
+            if id == 0 {
+                p = p.will_act(Some(Action::Kill(PlayerId(3))));
+            }
+            if id == 3 {
+                p = p.will_say("").will_say("You'll regret sparing the quiet one.");
+            }
+            builder = builder.player(PlayerId(id), Box::new(p));
+        }
+        let (state, players) = builder.build_with_players().unwrap();
+
+        let result = run_game_with(state, players, &config).await.unwrap();
+
+        assert!(result.log.iter().any(|e| {
+            matches!(&e.kind, GameEventKind::LastWords { player: PlayerId(3), text }
+                if text == "You'll regret sparing the quiet one.")
+        }));
+    }
+
+    #[tokio::test]
+    async fn every_observer_sees_every_logged_event() {
+        let config = night0_config(FirstPhase::Day);
+        let mut builder = GameBuilder::new().config(config.clone()).seed(7);
+        for id in 0..5 {
+            let p = ScriptedPlayer::new().will_vote(PlayerId(0)).will_vote(PlayerId(1)).will_vote(PlayerId(2));
+            builder = builder.player(PlayerId(id), Box::new(p));
+        }
+        let (state, players) = builder.build_with_players().unwrap();
+
+        let first = crate::game::observer::CountingObserver::new();
+        let second = crate::game::observer::CountingObserver::new();
+        let result = run_game_observed(state, players, &config, &[&first, &second])
+            .await
+            .unwrap();
+
+        assert!(!result.log.is_empty());
+        assert_eq!(first.count(), result.log.len());
+        assert_eq!(second.count(), result.log.len());
+    }
+
+    /// Kills a fixed seat at the start of Night 2, logging the death so
+    /// the game stays replayable.
+    struct NightTwoAssassin {
+        target: PlayerId,
+    }
+
+    impl PhaseHook for NightTwoAssassin {
+        fn on_phase_start(&self, state: &mut GameState, phase: Phase) {
+            if phase == Phase::Night && state.day() == 2 && state.is_alive(self.target) {
+                // The cause is arbitrary — what matters is that the death
+                // goes through apply_death and lands in the log.
+                apply_death(state, self.target, DeathCause::Poison);
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn a_hook_kill_on_night_two_still_ends_the_game_cleanly() {
+        let config = GameConfig {
+            player_count: 5,
+            roles: BTreeMap::from([(Role::Werewolf, 1), (Role::Villager, 4)]),
+            ..GameConfig::default()
+        };
+        let mut builder = GameBuilder::new().config(config.clone()).assign(PlayerId(0), Role::Werewolf);
+        for id in 0..5 {
+            // Two scripted lynches of town seats keep the game alive past
+            // Night 2; the wolf never attacks, so only the hook can end it.
+            builder = builder.player(PlayerId(id), Box::new(ScriptedPlayer::new().will_vote(PlayerId(4)).will_vote(PlayerId(3))));
+        }
+        let (state, players) = builder.build_with_players().unwrap();
+
+        let hook = NightTwoAssassin { target: PlayerId(0) };
+        let result = run_game_hooked(state, players, &config, &[], &[&hook])
+            .await
+            .unwrap();
+
+        // The hook killed the last wolf, and the win check noticed.
+        assert_eq!(result.winner, Some(Alignment::Town));
+        assert!(!result.survivors.contains(&PlayerId(0)));
+        assert!(result.log.iter().any(|e| e.day == 2
+            && matches!(e.kind, GameEventKind::PlayerDied {
+                player: PlayerId(0),
+                cause: DeathCause::Poison,
+                ..
+            })));
+    }
+
+    /// Abstains from every vote (the target is not at the table) and
+    /// never acts at night — the adversarial stalling case.
+    struct Abstainer;
+
+    #[async_trait::async_trait]
+    impl crate::player::Player for Abstainer {
+        async fn vote(&self, _ctx: &crate::player::GameContext) -> PlayerId {
+            PlayerId(99)
+        }
+
+        async fn night_action(
+            &self,
+            _ctx: &crate::player::GameContext,
+        ) -> Option<Action> {
+            None
+        }
+
+        async fn speak(&self, _ctx: &crate::player::GameContext) -> String {
+            String::new()
+        }
+    }
+
+    #[tokio::test]
+    async fn an_abstaining_table_draws_at_the_day_cap() {
+        let config = GameConfig {
+            player_count: 4,
+            roles: BTreeMap::from([(Role::Werewolf, 1), (Role::Villager, 3)]),
+            max_days: Some(3),
+            ..GameConfig::default()
+        };
+        let mut builder = GameBuilder::new().config(config.clone());
+        for id in 0..4 {
+            builder = builder.player(PlayerId(id), Box::new(Abstainer));
+        }
+        let (state, players) = builder.build_with_players().unwrap();
+
+        let result = run_game_with(state, players, &config).await.unwrap();
+
+        assert_eq!(result.outcome, GameOutcome::Draw);
+        assert_eq!(result.winner, None);
+        assert_eq!(result.days, 3);
+        assert_eq!(result.survivors.len(), 4);
+        assert!(result.log.iter().any(|e| matches!(
+            e.kind,
+            GameEventKind::GameEnded { winner: None }
+        )));
+    }
+
+    #[tokio::test]
+    async fn peaceful_night0_gives_the_seer_an_extra_peek() {
+        let with_night0 = play(FirstPhase::Night).await;
+        let without = play(FirstPhase::Day).await;
+
+        // Nobody dies on the peaceful Night-0: the wolf's attempt on the
+        // Seer is dropped and the game plays out identically otherwise.
+        assert_eq!(with_night0.winner, Some(crate::roles::Alignment::Town));
+        assert_eq!(with_night0.survivors, without.survivors);
+        assert_eq!(
+            investigations_in(&with_night0.log),
+            investigations_in(&without.log) + 1
+        );
+    }
+}
@@ -0,0 +1,1153 @@
+//! Game state and the phase state machine.
+//!
+//! The transition logic here is deliberately pure — no I/O, no LLM calls —
+//! so a full game loop can be unit-tested deterministically.
+
+use std::collections::{BTreeMap, HashMap};
+use std::io::{Read, Write};
+
+use serde::{Deserialize, Serialize};
+
+use crate::game::death::DeathReveal;
+use crate::game::event::{GameEvent, GameEventKind};
+use crate::game::knowledge::{Claim, ClaimTracker, Investigation, KnowledgeBase};
+use crate::game::night::{GuardRules, SeerMode, WitchPotions, WitchRules};
+use crate::game::win::WinRules;
+use crate::game::rng::Rng;
+use crate::roles::Role;
+
+/// Identifier for a player, unique within a single game.
+///
+/// A dedicated type rather than a bare integer, so an id can't be mixed
+/// up with a vote count or a day number. Serializes transparently as the
+/// inner number, so previously dumped logs and checkpoints keep loading.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct PlayerId(pub u32);
+
+impl PlayerId {
+    /// The id as a `usize`, for indexing seat-ordered collections.
+    pub const fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+/// Debug is transparent — `Kill(3)`, not `Kill(PlayerId(3))` — so action
+/// and event dumps stay readable.
+impl std::fmt::Debug for PlayerId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl std::fmt::Display for PlayerId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl From<u32> for PlayerId {
+    fn from(id: u32) -> Self {
+        Self(id)
+    }
+}
+
+impl std::str::FromStr for PlayerId {
+    type Err = std::num::ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse().map(Self)
+    }
+}
+
+/// Display names for the seats at the table. Logic runs on [`PlayerId`]s
+/// throughout; a roster exists purely so human-facing output can say
+/// "Alice" instead of "Player 3". Seats without an entry fall back to
+/// their numeric label.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlayerRoster {
+    names: BTreeMap<PlayerId, String>,
+}
+
+impl PlayerRoster {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Associates a display name with a seat, replacing any earlier one.
+    pub fn set_name(&mut self, id: PlayerId, name: impl Into<String>) {
+        self.names.insert(id, name.into());
+    }
+
+    /// The display name for a seat, if one was registered.
+    pub fn name(&self, id: PlayerId) -> Option<&str> {
+        self.names.get(&id).map(String::as_str)
+    }
+}
+
+impl<S: Into<String>> FromIterator<(PlayerId, S)> for PlayerRoster {
+    fn from_iter<I: IntoIterator<Item = (PlayerId, S)>>(iter: I) -> Self {
+        Self { names: iter.into_iter().map(|(id, name)| (id, name.into())).collect() }
+    }
+}
+
+/// The phases a game cycles through: Night → Day → Voting → Night, until
+/// a win condition ends the game.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Phase {
+    Night,
+    Day,
+    Voting,
+    GameOver,
+}
+
+/// Per-player engine state tracked by [`GameState`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlayerState {
+    pub id: PlayerId,
+    pub alive: bool,
+}
+
+impl PlayerState {
+    pub fn new(id: PlayerId) -> Self {
+        Self { id, alive: true }
+    }
+}
+
+/// A hidden pairwise bond between two players, set up before the game
+/// starts and never part of any public record.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Relationship {
+    /// Both partners know each other's identity (and thus innocence) from
+    /// Night-0.
+    Masons,
+    /// When one partner dies, the other immediately dies of grief; see
+    /// [`apply_death`](crate::game::death::apply_death).
+    Lovers,
+}
+
+/// A failure to save or restore a [`GameState`] checkpoint.
+#[derive(Debug, thiserror::Error)]
+#[error("game state (de)serialization failed: {0}")]
+pub struct PersistError(#[from] bincode::Error);
+
+/// The full mutable state of a running game.
+///
+/// `GameState` owns the roster, the current [`Phase`], and a day counter.
+/// Rule sets differ on whether a game opens with a night phase ("Night-0")
+/// or goes straight to day, so the starting phase is configurable via
+/// [`GameState::new`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GameState {
+    players: Vec<PlayerState>,
+    roles: HashMap<PlayerId, Role>,
+    phase: Phase,
+    day: u32,
+    rng: Rng,
+    events: Vec<GameEvent>,
+    #[serde(default)]
+    knowledge: HashMap<PlayerId, KnowledgeBase>,
+    #[serde(default)]
+    claims: ClaimTracker,
+    /// Estimated tokens each player has consumed speaking, for budgeting.
+    #[serde(default)]
+    tokens_used: HashMap<PlayerId, u32>,
+    /// Cached per-day transcript summaries, produced once per rollover by a
+    /// [`Summarizer`](crate::llm::summary::Summarizer).
+    #[serde(default)]
+    day_summaries: HashMap<u32, String>,
+    /// Registry keys of custom (non-enum) roles, for players holding one.
+    #[serde(default)]
+    custom_roles: HashMap<PlayerId, String>,
+    /// Hidden pairwise bonds (Masons, Lovers), in binding order.
+    #[serde(default)]
+    relationships: Vec<(PlayerId, PlayerId, Relationship)>,
+    /// How much of a dead player's role becomes public; see
+    /// [`GameConfig::death_reveal`](crate::config::GameConfig).
+    #[serde(default)]
+    death_reveal: DeathReveal,
+    /// Remaining single-use potions, per potion-carrying player.
+    #[serde(default)]
+    potions: HashMap<PlayerId, WitchPotions>,
+    /// Table-variant rules for the Witch.
+    #[serde(default)]
+    witch_rules: WitchRules,
+    /// Who each Guard protected last night, for the no-repeat rule.
+    #[serde(default)]
+    last_protected: HashMap<PlayerId, PlayerId>,
+    /// Table-variant rules for the Guard.
+    #[serde(default)]
+    guard_rules: GuardRules,
+    /// What the Seer learns from an investigation.
+    #[serde(default)]
+    seer_mode: SeerMode,
+    /// Rule variants for win checking.
+    #[serde(default)]
+    win_rules: WinRules,
+    /// The wolves' private coordination transcript. Only wolf-aligned
+    /// contexts ever see this.
+    #[serde(default)]
+    wolf_chat: Vec<(PlayerId, String)>,
+    /// Accumulated LLM token usage and its estimated dollar cost.
+    #[serde(default)]
+    cost: crate::llm::cost::CostTracker,
+    /// Whether town contexts include the computed suspicion prior; see
+    /// [`GameConfig::suspicion_scores`](crate::config::GameConfig).
+    #[serde(default)]
+    show_suspicion: bool,
+    /// Whether every current-day vote is public speech, not just reasoned
+    /// ones; set under [`VotingMode::Open`](crate::config::VotingMode).
+    #[serde(default)]
+    open_voting: bool,
+    /// Hard cap on the day counter; starting a day past it ends the game
+    /// in a draw. `None` is uncapped. See
+    /// [`GameConfig::max_days`](crate::config::GameConfig::max_days).
+    #[serde(default)]
+    max_days: Option<u32>,
+}
+
+impl GameState {
+    /// Creates a new game with the given roster, starting in `first_phase`,
+    /// with all engine randomness seeded from `seed`.
+    ///
+    /// Day numbering starts at 0 when opening with Night (the "Night-0"
+    /// variant) and at 1 when opening with Day.
+    pub fn new(
+        player_ids: impl IntoIterator<Item: Into<PlayerId>>,
+        first_phase: Phase,
+        seed: u64,
+    ) -> Self {
+        let day = match first_phase {
+            Phase::Day | Phase::Voting => 1,
+            Phase::Night | Phase::GameOver => 0,
+        };
+        Self {
+            players: player_ids.into_iter().map(|id| PlayerState::new(id.into())).collect(),
+            roles: HashMap::new(),
+            phase: first_phase,
+            day,
+            rng: Rng::new(seed),
+            events: Vec::new(),
+            knowledge: HashMap::new(),
+            claims: ClaimTracker::default(),
+            tokens_used: HashMap::new(),
+            day_summaries: HashMap::new(),
+            custom_roles: HashMap::new(),
+            relationships: Vec::new(),
+            death_reveal: DeathReveal::default(),
+            potions: HashMap::new(),
+            witch_rules: WitchRules::default(),
+            last_protected: HashMap::new(),
+            guard_rules: GuardRules::default(),
+            seer_mode: SeerMode::default(),
+            win_rules: WinRules::default(),
+            wolf_chat: Vec::new(),
+            cost: crate::llm::cost::CostTracker::default(),
+            show_suspicion: false,
+            open_voting: false,
+            max_days: None,
+        }
+    }
+
+    /// The game's cost accounting, for reading totals.
+    pub fn cost(&self) -> &crate::llm::cost::CostTracker {
+        &self.cost
+    }
+
+    /// The game's cost accounting, for recording provider-reported usage.
+    pub fn cost_mut(&mut self) -> &mut crate::llm::cost::CostTracker {
+        &mut self.cost
+    }
+
+    /// Sets how much of a dead player's role becomes public. Death-handling
+    /// code consults this when recording [`GameEventKind::PlayerDied`].
+    pub fn set_death_reveal(&mut self, reveal: DeathReveal) {
+        self.death_reveal = reveal;
+    }
+
+    /// Sets whether town contexts include the public-information suspicion
+    /// prior; see [`suspicion_scores`](crate::game::suspicion::suspicion_scores).
+    pub fn set_show_suspicion(&mut self, show: bool) {
+        self.show_suspicion = show;
+    }
+
+    /// Sets whether every current-day vote appears in public contexts.
+    /// Under the default (secret-ballot) modes only votes with a stated
+    /// reason do.
+    pub fn set_open_voting(&mut self, open: bool) {
+        self.open_voting = open;
+    }
+
+    /// Sets the hard cap on days; see
+    /// [`GameConfig`](crate::config::GameConfig).
+    pub fn set_max_days(&mut self, cap: Option<u32>) {
+        self.max_days = cap;
+    }
+
+    /// The role to publish in a death event: the player's exact role under
+    /// [`DeathReveal::Full`], `None` otherwise.
+    pub fn revealed_role_of(&self, id: PlayerId) -> Option<Role> {
+        if self.death_reveal == DeathReveal::Full { self.role_of(id) } else { None }
+    }
+
+    /// The alignment to publish in a death event: the player's alignment
+    /// unless the table hides deaths entirely ([`DeathReveal::Hidden`]).
+    pub fn revealed_alignment_of(&self, id: PlayerId) -> Option<crate::roles::Alignment> {
+        if self.death_reveal == DeathReveal::Hidden {
+            None
+        } else {
+            self.role_of(id).map(|r| r.alignment())
+        }
+    }
+
+    /// The Witch rule variants in force for this game.
+    pub fn witch_rules(&self) -> WitchRules {
+        self.witch_rules
+    }
+
+    /// Sets the Witch rule variants; see
+    /// [`GameConfig`](crate::config::GameConfig).
+    pub fn set_witch_rules(&mut self, rules: WitchRules) {
+        self.witch_rules = rules;
+    }
+
+    /// The potions `id` still carries. Players who never spent one hold the
+    /// full set.
+    pub fn potions_of(&self, id: PlayerId) -> WitchPotions {
+        self.potions.get(&id).copied().unwrap_or_default()
+    }
+
+    /// Marks `id`'s single-use heal as spent.
+    pub fn spend_heal(&mut self, id: PlayerId) {
+        self.potions.entry(id).or_default().heal_available = false;
+    }
+
+    /// Marks `id`'s single-use poison as spent.
+    pub fn spend_poison(&mut self, id: PlayerId) {
+        self.potions.entry(id).or_default().poison_available = false;
+    }
+
+    /// The Guard rule variants in force for this game.
+    pub fn guard_rules(&self) -> GuardRules {
+        self.guard_rules
+    }
+
+    /// Sets the Guard rule variants; see
+    /// [`GameConfig`](crate::config::GameConfig).
+    pub fn set_guard_rules(&mut self, rules: GuardRules) {
+        self.guard_rules = rules;
+    }
+
+    /// The investigation semantics in force for this game.
+    pub fn seer_mode(&self) -> SeerMode {
+        self.seer_mode
+    }
+
+    /// Sets the investigation semantics; see
+    /// [`GameConfig`](crate::config::GameConfig).
+    pub fn set_seer_mode(&mut self, mode: SeerMode) {
+        self.seer_mode = mode;
+    }
+
+    /// The win-rule variants in force for this game.
+    pub fn win_rules(&self) -> WinRules {
+        self.win_rules
+    }
+
+    /// Sets the win-rule variants; see
+    /// [`GameConfig`](crate::config::GameConfig).
+    pub fn set_win_rules(&mut self, rules: WinRules) {
+        self.win_rules = rules;
+    }
+
+    /// Whom `id` successfully protected last night, if anyone.
+    pub fn last_protected_of(&self, id: PlayerId) -> Option<PlayerId> {
+        self.last_protected.get(&id).copied()
+    }
+
+    /// Replaces the last-night protection record with tonight's successful
+    /// protections. Guards who sat the night out drop off the record, so
+    /// only directly consecutive repeats are forbidden.
+    pub fn set_last_protections(&mut self, protections: Vec<(PlayerId, PlayerId)>) {
+        self.last_protected = protections.into_iter().collect();
+    }
+
+    /// The wolves' private transcript so far. Callers building anything a
+    /// town player can see must not touch this; [`context_for`] already
+    /// applies the alignment check.
+    ///
+    /// [`context_for`]: GameState::context_for
+    pub fn wolf_chat(&self) -> &[(PlayerId, String)] {
+        &self.wolf_chat
+    }
+
+    /// Appends a message to the wolves' private transcript. Deliberately
+    /// not an event: the public log must never carry wolf chat.
+    pub fn record_wolf_chat(&mut self, wolf: PlayerId, text: String) {
+        self.wolf_chat.push((wolf, text));
+    }
+
+    /// The graveyard transcript so far: everything the dead have said
+    /// among themselves. Stored as [`GraveyardChat`] events, which
+    /// [`context_for`] keeps out of every living player's view.
+    ///
+    /// [`GraveyardChat`]: GameEventKind::GraveyardChat
+    /// [`context_for`]: GameState::context_for
+    pub fn graveyard_chat(&self) -> Vec<(PlayerId, String)> {
+        self.events
+            .iter()
+            .filter_map(|e| match &e.kind {
+                GameEventKind::GraveyardChat { player, text } => {
+                    Some((*player, text.clone()))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// The full event log so far.
+    pub fn log(&self) -> &[GameEvent] {
+        &self.events
+    }
+
+    /// Appends an event, stamping it with the current day and wall time.
+    pub fn record(&mut self, kind: GameEventKind) {
+        self.events.push(GameEvent::now(self.day, kind));
+    }
+
+    /// Appends an already-stamped event verbatim, preserving its original
+    /// day and timestamp. For log reconstruction (replay) only.
+    pub(crate) fn push_event(&mut self, event: GameEvent) {
+        self.events.push(event);
+    }
+
+    /// Forces the phase, bypassing the transition machine. For log
+    /// reconstruction (replay) only.
+    pub(crate) fn set_phase(&mut self, phase: Phase) {
+        self.phase = phase;
+    }
+
+    /// Forces the day counter. For log reconstruction (replay) only.
+    pub(crate) fn set_day(&mut self, day: u32) {
+        self.day = day;
+    }
+
+    /// Serializes the full hidden state — roles, phase, RNG state, log —
+    /// so a game can be checkpointed mid-session.
+    ///
+    /// Player implementations are not part of the saved state; after
+    /// [`GameState::load`] the caller re-attaches players by `PlayerId`.
+    pub fn save(&self, writer: impl Write) -> Result<(), PersistError> {
+        bincode::serialize_into(writer, self).map_err(PersistError::from)
+    }
+
+    /// Restores a game saved with [`GameState::save`].
+    ///
+    /// The RNG's internal state round-trips exactly, so resuming continues
+    /// the same random stream as the uninterrupted run would have.
+    pub fn load(reader: impl Read) -> Result<GameState, PersistError> {
+        bincode::deserialize_from(reader).map_err(PersistError::from)
+    }
+
+    /// The seed this game's RNG was created with, for logging and replay.
+    pub fn seed(&self) -> u64 {
+        self.rng.seed()
+    }
+
+    /// The game's single source of randomness. All random decisions in the
+    /// engine must go through this.
+    pub fn rng_mut(&mut self) -> &mut Rng {
+        &mut self.rng
+    }
+
+    /// What `id` privately knows. Only `id`'s own `GameContext` may ever
+    /// be built from this; it survives the player's death for post-game
+    /// analysis.
+    pub fn knowledge_of(&self, id: PlayerId) -> KnowledgeBase {
+        self.knowledge.get(&id).cloned().unwrap_or_default()
+    }
+
+    /// Stores an investigation result in `seer`'s private knowledge.
+    pub fn record_investigation(&mut self, seer: PlayerId, investigation: Investigation) {
+        self.knowledge.entry(seer).or_default().investigations.push(investigation);
+    }
+
+    /// The public role-claim record.
+    pub fn claims(&self) -> &ClaimTracker {
+        &self.claims
+    }
+
+    /// Records a public role claim.
+    pub fn record_claim(&mut self, claimant: PlayerId, role: Role) {
+        self.claims.record(Claim { day: self.day, claimant, role });
+    }
+
+    /// Estimated tokens `id` has consumed speaking so far this game.
+    pub fn tokens_used(&self, id: PlayerId) -> u32 {
+        self.tokens_used.get(&id).copied().unwrap_or(0)
+    }
+
+    /// Adds to `id`'s running token total.
+    pub fn add_tokens(&mut self, id: PlayerId, tokens: u32) {
+        *self.tokens_used.entry(id).or_default() += tokens;
+    }
+
+    /// The cached transcript summary for a finished day, if one was made.
+    pub fn day_summary(&self, day: u32) -> Option<&str> {
+        self.day_summaries.get(&day).map(String::as_str)
+    }
+
+    /// Caches a finished day's transcript summary; [`context_for`]
+    /// prefers it over the bare statement/death counts.
+    ///
+    /// [`context_for`]: GameState::context_for
+    pub fn set_day_summary(&mut self, day: u32, summary: String) {
+        self.day_summaries.insert(day, summary);
+    }
+
+    /// Builds the redacted per-player view handed to a [`Player`]
+    /// implementation: public information plus `id`'s own private
+    /// knowledge, and nothing else.
+    ///
+    /// The transcript doesn't grow without bound: the current day's
+    /// discussion appears verbatim, while each earlier day is collapsed
+    /// into a one-line summary.
+    ///
+    /// Building contexts for many players in a row repeats the shared
+    /// transcript work each time; engine loops go through a
+    /// [`ContextCache`] instead, which produces identical contexts.
+    ///
+    /// [`Player`]: crate::player::Player
+    pub fn context_for(&self, id: PlayerId) -> crate::player::GameContext {
+        self.specialize_context(&self.shared_context(), id)
+    }
+
+    /// The player-independent half of a context build: everything every
+    /// seat sees the same way.
+    fn shared_context(&self) -> SharedContext {
+        // (statements, deaths) per prior day.
+        let mut prior: BTreeMap<u32, (usize, usize)> = BTreeMap::new();
+        let mut public_log = Vec::new();
+        for e in &self.events {
+            match &e.kind {
+                GameEventKind::PlayerSpoke { player, text } => {
+                    if e.day == self.day {
+                        public_log.push(format!("Player {player}: {text}"));
+                    } else {
+                        prior.entry(e.day).or_default().0 += 1;
+                    }
+                }
+                GameEventKind::Accusation { accuser, accused, text } if e.day == self.day => {
+                    public_log
+                        .push(format!("Player {accuser} accuses Player {accused}: {text}"));
+                }
+                GameEventKind::Defense { player, text } if e.day == self.day => {
+                    public_log.push(format!("Player {player} (in defense): {text}"));
+                }
+                // A dying statement stays visible one day past the death,
+                // so survivors can react to it the next morning.
+                GameEventKind::LastWords { player, text } if e.day + 1 >= self.day => {
+                    public_log.push(format!("Player {player} (last words): {text}"));
+                }
+                GameEventKind::VoteCast { voter, target, reason }
+                    if e.day == self.day && (reason.is_some() || self.open_voting) =>
+                {
+                    // A stated vote reason is public speech: later voters
+                    // see it and can react before they vote. Under open
+                    // voting the vote itself is public too.
+                    let line = match (target, reason) {
+                        (Some(target), Some(reason)) => {
+                            format!("Player {voter} votes for Player {target}: {reason}")
+                        }
+                        (Some(target), None) => {
+                            format!("Player {voter} votes for Player {target}.")
+                        }
+                        (None, Some(reason)) => {
+                            format!("Player {voter} abstains: {reason}")
+                        }
+                        (None, None) => format!("Player {voter} abstains."),
+                    };
+                    public_log.push(line);
+                }
+                GameEventKind::PlayerDied { .. } if e.day < self.day => {
+                    prior.entry(e.day).or_default().1 += 1;
+                }
+                _ => {}
+            }
+        }
+        for day in self.day_summaries.keys().filter(|&&day| day < self.day) {
+            prior.entry(*day).or_default();
+        }
+        let summaries: Vec<String> = prior
+            .iter()
+            .map(|(day, (statements, deaths))| match self.day_summaries.get(day) {
+                Some(summary) => format!("Day {day} summary: {summary}"),
+                None => format!("Day {day} summary: {statements} statements, {deaths} deaths."),
+            })
+            .collect();
+        let mut log = summaries;
+        log.append(&mut public_log);
+        // The suspicion inputs are only derived when the prior is shown at
+        // all; whether a given seat sees it is decided per player.
+        let (vote_history, accusation_pairs, revealed_alignments) = if self.show_suspicion {
+            (self.vote_history(), self.accusation_pairs(), self.revealed_alignments())
+        } else {
+            (Vec::new(), Vec::new(), Vec::new())
+        };
+        SharedContext {
+            day: self.day,
+            phase: self.phase,
+            log_len: self.events.len(),
+            claim_count: self.claims.all().len(),
+            summary_count: self.day_summaries.len(),
+            alive_players: self.alive_players(),
+            public_log: log,
+            vote_history,
+            accusation_pairs,
+            revealed_alignments,
+        }
+    }
+
+    /// Every public line of the game so far, verbatim and in order: the
+    /// same rendering as [`shared_context`], minus the day gating and
+    /// the summary collapse, plus a line per death announcement. Nothing
+    /// here is private to any seat, so it can go into any redacted view.
+    ///
+    /// [`shared_context`]: GameState::shared_context
+    pub(crate) fn public_transcript(&self) -> Vec<String> {
+        self.events
+            .iter()
+            .filter_map(|e| match &e.kind {
+                GameEventKind::PlayerSpoke { player, text } => {
+                    Some(format!("Player {player}: {text}"))
+                }
+                GameEventKind::Accusation { accuser, accused, text } => {
+                    Some(format!("Player {accuser} accuses Player {accused}: {text}"))
+                }
+                GameEventKind::Defense { player, text } => {
+                    Some(format!("Player {player} (in defense): {text}"))
+                }
+                GameEventKind::LastWords { player, text } => {
+                    Some(format!("Player {player} (last words): {text}"))
+                }
+                GameEventKind::VoteCast { voter, target, reason }
+                    if reason.is_some() || self.open_voting =>
+                {
+                    Some(match (target, reason) {
+                        (Some(target), Some(reason)) => {
+                            format!("Player {voter} votes for Player {target}: {reason}")
+                        }
+                        (Some(target), None) => {
+                            format!("Player {voter} votes for Player {target}.")
+                        }
+                        (None, Some(reason)) => {
+                            format!("Player {voter} abstains: {reason}")
+                        }
+                        (None, None) => format!("Player {voter} abstains."),
+                    })
+                }
+                // Deaths are announced at the table; the role and
+                // alignment on the event stay out — the death-reveal
+                // redaction surfaces them separately when the table
+                // allows.
+                GameEventKind::PlayerDied { player, .. } => {
+                    Some(format!("Player {player} died."))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Layers `id`'s private knowledge and redactions over the shared
+    /// half, completing the context.
+    fn specialize_context(
+        &self,
+        shared: &SharedContext,
+        id: PlayerId,
+    ) -> crate::player::GameContext {
+        let mut public_log = shared.public_log.clone();
+        // The suspicion prior is a town-only reasoning aid; wolves already
+        // know who the wolves are.
+        if self.show_suspicion
+            && self.is_alive(id)
+            && self
+                .role_of(id)
+                .is_some_and(|r| r.alignment() == crate::roles::Alignment::Town)
+        {
+            let scores = crate::game::suspicion::scores_from_public(
+                id,
+                &shared.alive_players,
+                self.claims.all(),
+                &shared.vote_history,
+                &shared.accusation_pairs,
+                &shared.revealed_alignments,
+            );
+            if !scores.is_empty() {
+                public_log.push(crate::game::suspicion::format_scores(&scores));
+            }
+        }
+        crate::player::GameContext {
+            player: id,
+            role: self.role_of(id).unwrap_or(crate::roles::Role::Villager),
+            day: self.day,
+            phase: self.phase,
+            alive_players: shared.alive_players.clone(),
+            public_log,
+            knowledge: self.knowledge_of(id),
+            claims: self.claims.all().to_vec(),
+            tokens_used: self.tokens_used(id),
+            potions: (self.role_of(id) == Some(Role::Witch))
+                .then(|| self.potions_of(id)),
+            last_protected: self.last_protected_of(id),
+            wolf_chat: if self
+                .role_of(id)
+                .is_some_and(|r| r.alignment() == crate::roles::Alignment::Wolf)
+            {
+                self.wolf_chat.clone()
+            } else {
+                Vec::new()
+            },
+            graveyard: if self.is_alive(id) {
+                Vec::new()
+            } else {
+                self.graveyard_chat()
+            },
+            cost_so_far: self.cost.total_cost(),
+        }
+    }
+
+    /// Binds two players in a hidden relationship and tells each partner
+    /// about the other: the bond lands in both partners' private
+    /// [`KnowledgeBase`]s (and nobody else's), so from Night-0 on each
+    /// partner's `GameContext` names the other.
+    pub fn add_relationship(&mut self, a: PlayerId, b: PlayerId, relationship: Relationship) {
+        self.relationships.push((a, b, relationship));
+        self.knowledge.entry(a).or_default().partners.push((b, relationship));
+        self.knowledge.entry(b).or_default().partners.push((a, relationship));
+    }
+
+    /// Everyone bound to `id` by the given relationship, in binding order.
+    pub fn partners_of(&self, id: PlayerId, relationship: Relationship) -> Vec<PlayerId> {
+        self.relationships
+            .iter()
+            .filter(|(_, _, r)| *r == relationship)
+            .filter_map(|(a, b, _)| {
+                if *a == id {
+                    Some(*b)
+                } else if *b == id {
+                    Some(*a)
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+
+    /// Assigns a role to a player. Re-assigning overwrites.
+    pub fn assign_role(&mut self, id: PlayerId, role: Role) {
+        self.roles.insert(id, role);
+    }
+
+    /// The role assigned to a player, if any.
+    pub fn role_of(&self, id: PlayerId) -> Option<Role> {
+        self.roles.get(&id).copied()
+    }
+
+    /// Assigns a custom role by its [`RoleRegistry`] key, for roles that
+    /// exist outside the built-in [`Role`] enum.
+    ///
+    /// [`RoleRegistry`]: crate::roles::RoleRegistry
+    pub fn assign_custom_role(&mut self, id: PlayerId, key: impl Into<String>) {
+        self.custom_roles.insert(id, key.into());
+    }
+
+    /// The custom role key assigned to a player, if any.
+    pub fn custom_role_of(&self, id: PlayerId) -> Option<&str> {
+        self.custom_roles.get(&id).map(String::as_str)
+    }
+
+    /// The registry key for a player's role: the custom key if one was
+    /// assigned, otherwise the built-in role's display name.
+    pub fn role_key(&self, id: PlayerId) -> Option<String> {
+        if let Some(key) = self.custom_roles.get(&id) {
+            return Some(key.clone());
+        }
+        self.role_of(id).map(|r| r.info().display_name.to_string())
+    }
+
+    /// The current phase.
+    pub fn phase(&self) -> Phase {
+        self.phase
+    }
+
+    /// The current day number.
+    pub fn day(&self) -> u32 {
+        self.day
+    }
+
+    /// The full roster, including dead players.
+    pub fn players(&self) -> &[PlayerState] {
+        &self.players
+    }
+
+    /// Ids of all living players, in seat order.
+    pub fn alive_players(&self) -> Vec<PlayerId> {
+        self.players
+            .iter()
+            .filter(|p| p.alive)
+            .map(|p| p.id)
+            .collect()
+    }
+
+    /// Whether the given player exists and is alive.
+    pub fn is_alive(&self, id: PlayerId) -> bool {
+        self.players.iter().any(|p| p.id == id && p.alive)
+    }
+
+    /// Marks a player dead. Unknown ids are ignored.
+    pub fn kill(&mut self, id: PlayerId) {
+        if let Some(p) = self.players.iter_mut().find(|p| p.id == id) {
+            p.alive = false;
+        }
+    }
+
+    /// Advances to the next phase and returns it.
+    ///
+    /// Transitions Night → Day → Voting → Night, incrementing the day
+    /// counter on each Voting → Night rollover. Win conditions are checked
+    /// at every boundary; once the game is over, further calls are no-ops
+    /// and keep returning [`Phase::GameOver`].
+    pub fn advance(&mut self) -> Phase {
+        if self.phase == Phase::GameOver {
+            return Phase::GameOver;
+        }
+        let from = self.phase;
+        if let Some(winner) = crate::game::win::check_win(self) {
+            self.phase = Phase::GameOver;
+            self.record(GameEventKind::GameEnded { winner: Some(winner) });
+            return Phase::GameOver;
+        }
+        if self.phase == Phase::Night
+            && let Some(cap) = self.max_days
+            && self.day >= cap
+        {
+            // Starting another day would blow the cap: the game is a draw.
+            self.phase = Phase::GameOver;
+            self.record(GameEventKind::GameEnded { winner: None });
+            return Phase::GameOver;
+        }
+        self.phase = match self.phase {
+            Phase::Night => {
+                self.day += 1;
+                Phase::Day
+            }
+            Phase::Day => Phase::Voting,
+            Phase::Voting => Phase::Night,
+            Phase::GameOver => Phase::GameOver,
+        };
+        self.record(GameEventKind::PhaseChanged { from, to: self.phase });
+        self.phase
+    }
+
+}
+
+/// The player-independent half of a context build, plus the fingerprint
+/// of the state it was computed from.
+#[derive(Debug, Clone)]
+struct SharedContext {
+    // Fingerprint: the shared half only depends on the state through
+    // these, and each of them only ever moves when its input changes.
+    day: u32,
+    phase: Phase,
+    log_len: usize,
+    claim_count: usize,
+    summary_count: usize,
+    // The shared products themselves. The suspicion inputs stay empty
+    // when the prior is disabled.
+    alive_players: Vec<PlayerId>,
+    public_log: Vec<String>,
+    vote_history: Vec<(u32, PlayerId, Option<PlayerId>)>,
+    accusation_pairs: Vec<(PlayerId, PlayerId)>,
+    revealed_alignments: Vec<(PlayerId, crate::roles::Alignment)>,
+}
+
+impl SharedContext {
+    /// Whether this shared half still matches `state`.
+    fn is_current(&self, state: &GameState) -> bool {
+        self.day == state.day
+            && self.phase == state.phase
+            && self.log_len == state.events.len()
+            && self.claim_count == state.claims.all().len()
+            && self.summary_count == state.day_summaries.len()
+            && self.alive_players.len()
+                == state.players.iter().filter(|p| p.alive).count()
+    }
+}
+
+/// Reuses the player-independent half of [`GameState::context_for`] —
+/// the collapsed transcript, the living roster, the suspicion inputs —
+/// across several builds, so asking for every seat's context in a row
+/// does the shared work once instead of once per seat.
+///
+/// The cached half carries a fingerprint of the state it came from and is
+/// rebuilt the moment the state moves on, so a cached context is always
+/// byte-identical to an uncached one; holding a cache across mutations is
+/// safe, just less effective.
+#[derive(Debug, Default)]
+pub struct ContextCache {
+    shared: Option<SharedContext>,
+}
+
+impl ContextCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// [`GameState::context_for`], reusing the shared half when the state
+    /// hasn't changed since the last build.
+    pub fn context_for(
+        &mut self,
+        state: &GameState,
+        id: PlayerId,
+    ) -> crate::player::GameContext {
+        if !self.shared.as_ref().is_some_and(|shared| shared.is_current(state)) {
+            self.shared = Some(state.shared_context());
+        }
+        state.specialize_context(self.shared.as_ref().expect("just rebuilt"), id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fresh(first: Phase) -> GameState {
+        let mut state = GameState::new(0..5, first, 0);
+        state.assign_role(PlayerId(0), Role::Werewolf);
+        for id in 1..5 {
+            state.assign_role(PlayerId(id), Role::Villager);
+        }
+        state
+    }
+
+    #[test]
+    fn cycles_night_day_voting() {
+        let mut state = fresh(Phase::Night);
+        assert_eq!(state.phase(), Phase::Night);
+        assert_eq!(state.advance(), Phase::Day);
+        assert_eq!(state.advance(), Phase::Voting);
+        assert_eq!(state.advance(), Phase::Night);
+        assert_eq!(state.advance(), Phase::Day);
+    }
+
+    #[test]
+    fn day_counter_increments_on_night_to_day() {
+        let mut state = fresh(Phase::Night);
+        assert_eq!(state.day(), 0);
+        state.advance(); // Day 1
+        assert_eq!(state.day(), 1);
+        state.advance(); // Voting
+        state.advance(); // Night
+        assert_eq!(state.day(), 1);
+        state.advance(); // Day 2
+        assert_eq!(state.day(), 2);
+    }
+
+    #[test]
+    fn first_phase_is_configurable() {
+        let night_start = fresh(Phase::Night);
+        assert_eq!(night_start.phase(), Phase::Night);
+        assert_eq!(night_start.day(), 0);
+
+        let day_start = fresh(Phase::Day);
+        assert_eq!(day_start.phase(), Phase::Day);
+        assert_eq!(day_start.day(), 1);
+    }
+
+    #[test]
+    fn advancing_past_game_over_is_a_noop() {
+        let mut state = GameState::new(0..2, Phase::Night, 0);
+        state.assign_role(PlayerId(0), Role::Werewolf);
+        state.assign_role(PlayerId(1), Role::Villager);
+        state.kill(PlayerId(0));
+        assert_eq!(state.advance(), Phase::GameOver);
+        assert_eq!(state.advance(), Phase::GameOver);
+        assert_eq!(state.phase(), Phase::GameOver);
+    }
+
+    #[test]
+    fn a_stated_vote_reason_is_public_context() {
+        let mut state = fresh(Phase::Day);
+        state.record(GameEventKind::VoteCast {
+            voter: PlayerId(0),
+            target: Some(PlayerId(2)),
+            reason: Some("Seat 2 dodged every question.".into()),
+        });
+        state.record(GameEventKind::VoteCast { voter: PlayerId(1), target: Some(PlayerId(2)), reason: None });
+        let ctx = state.context_for(PlayerId(3));
+        assert!(ctx
+            .public_log
+            .contains(&"Player 0 votes for Player 2: Seat 2 dodged every question.".to_string()));
+        // An unreasoned vote adds no transcript line.
+        assert!(!ctx.public_log.iter().any(|l| l.starts_with("Player 1 votes")));
+    }
+
+    #[test]
+    fn last_words_stay_visible_through_the_next_day() {
+        let mut state = fresh(Phase::Day);
+        state.record(GameEventKind::LastWords {
+            player: PlayerId(2),
+            text: "The wolf is Player 0.".into(),
+        });
+        let line = "Player 2 (last words): The wolf is Player 0.".to_string();
+        assert!(state.context_for(PlayerId(3)).public_log.contains(&line));
+        // Day 2: survivors can still react to the dying statement.
+        state.advance(); // Voting
+        state.advance(); // Night
+        state.advance(); // Day 2
+        assert!(state.context_for(PlayerId(3)).public_log.contains(&line));
+        // By day 3 it has aged out of the transcript.
+        state.advance();
+        state.advance();
+        state.advance();
+        assert!(!state.context_for(PlayerId(3)).public_log.contains(&line));
+    }
+
+    #[test]
+    fn save_load_round_trips_mid_night() {
+        let mut original = fresh(Phase::Night);
+        crate::game::night::resolve_night(&mut original, vec![(PlayerId(0), crate::game::Action::Kill(PlayerId(2)))]);
+        // Consume some of the RNG stream before checkpointing.
+        original.rng_mut().index(10);
+
+        let mut buf = Vec::new();
+        original.save(&mut buf).unwrap();
+        let mut restored = GameState::load(buf.as_slice()).unwrap();
+
+        assert_eq!(restored.phase(), original.phase());
+        assert_eq!(restored.day(), original.day());
+        assert_eq!(restored.alive_players(), original.alive_players());
+        assert_eq!(restored.seed(), original.seed());
+        assert_eq!(restored.log().len(), original.log().len());
+        // The RNG state must round-trip exactly: both continuations draw
+        // the same stream.
+        for _ in 0..8 {
+            assert_eq!(restored.rng_mut().index(1000), original.rng_mut().index(1000));
+        }
+    }
+
+    #[test]
+    fn load_rejects_garbage() {
+        assert!(GameState::load(&b"definitely not a checkpoint"[..]).is_err());
+    }
+
+    #[test]
+    fn player_ids_serialize_as_bare_numbers() {
+        assert_eq!(serde_json::to_string(&PlayerId(7)).unwrap(), "7");
+        assert_eq!(serde_json::from_str::<PlayerId>("7").unwrap(), PlayerId(7));
+    }
+
+    #[test]
+    fn roster_resolves_names_and_leaves_unnamed_seats_empty() {
+        let mut roster = PlayerRoster::new();
+        roster.set_name(PlayerId(0), "Alice");
+        assert_eq!(roster.name(PlayerId(0)), Some("Alice"));
+        assert_eq!(roster.name(PlayerId(1)), None);
+    }
+
+    #[test]
+    fn kill_and_alive_tracking() {
+        let mut state = fresh(Phase::Night);
+        assert!(state.is_alive(PlayerId(3)));
+        state.kill(PlayerId(3));
+        assert!(!state.is_alive(PlayerId(3)));
+        assert_eq!(state.alive_players(), vec![PlayerId(0), PlayerId(1), PlayerId(2), PlayerId(4)]);
+    }
+
+    /// A busy 12-player day 2: prior-day history (with and without a
+    /// cached summary), today's speeches, accusations, defenses and
+    /// reasoned votes, the suspicion prior, wolf chat, claims, a Witch,
+    /// and a dead seat — every branch of the context build.
+    fn busy_twelve_player_state() -> GameState {
+        let mut state = GameState::new(0..12, Phase::Day, 0);
+        state.assign_role(PlayerId(0), Role::Werewolf);
+        state.assign_role(PlayerId(1), Role::Werewolf);
+        state.assign_role(PlayerId(2), Role::Witch);
+        for id in 3..12 {
+            state.assign_role(PlayerId(id), Role::Villager);
+        }
+        state.set_show_suspicion(true);
+        state.record(GameEventKind::PlayerSpoke { player: PlayerId(3), text: "day one".into() });
+        state.record_claim(PlayerId(4), Role::Seer);
+        state.kill(PlayerId(11));
+        state.record(GameEventKind::PlayerDied {
+            player: PlayerId(11),
+            cause: crate::game::night::DeathCause::WolfKill,
+            role: None,
+            alignment: Some(crate::roles::Alignment::Town),
+        });
+        state.set_day(2);
+        state.set_day_summary(1, "A quiet opening day.".into());
+        state.record(GameEventKind::PlayerSpoke { player: PlayerId(4), text: "I saw nothing".into() });
+        state.record(GameEventKind::Accusation {
+            accuser: PlayerId(5),
+            accused: PlayerId(6),
+            text: "too quiet".into(),
+        });
+        state.record(GameEventKind::Defense { player: PlayerId(6), text: "I was thinking".into() });
+        state.record(GameEventKind::VoteCast {
+            voter: PlayerId(7),
+            target: Some(PlayerId(6)),
+            reason: Some("the defense was weak".into()),
+        });
+        state.record_wolf_chat(PlayerId(0), "take the seer claim".into());
+        state.add_tokens(PlayerId(4), 25);
+        state
+    }
+
+    #[test]
+    fn cached_contexts_are_identical_to_uncached_ones() {
+        let state = busy_twelve_player_state();
+        let mut cache = ContextCache::new();
+        for id in 0..12 {
+            assert_eq!(cache.context_for(&state, PlayerId(id)), state.context_for(PlayerId(id)), "seat {id}");
+        }
+    }
+
+    /// Not a correctness test: a rough before/after timing of the cached
+    /// context path over a 12-player day. Run with
+    /// `cargo test --release context_cache_timing -- --ignored --nocapture`.
+    #[test]
+    #[ignore = "timing smoke check, run manually"]
+    fn context_cache_timing_over_a_twelve_player_day() {
+        let state = busy_twelve_player_state();
+        let rounds = 1_000;
+        let uncached = std::time::Instant::now();
+        for _ in 0..rounds {
+            for id in 0..12 {
+                std::hint::black_box(state.context_for(PlayerId(id)));
+            }
+        }
+        let uncached = uncached.elapsed();
+        let cached = std::time::Instant::now();
+        for _ in 0..rounds {
+            let mut cache = ContextCache::new();
+            for id in 0..12 {
+                std::hint::black_box(cache.context_for(&state, PlayerId(id)));
+            }
+        }
+        let cached = cached.elapsed();
+        println!("uncached: {uncached:?}, cached: {cached:?}");
+    }
+
+    #[test]
+    fn a_stale_cache_rebuilds_instead_of_serving_old_context() {
+        let mut state = busy_twelve_player_state();
+        let mut cache = ContextCache::new();
+        let before = cache.context_for(&state, PlayerId(3));
+        state.record(GameEventKind::PlayerSpoke { player: PlayerId(8), text: "late word".into() });
+        let after = cache.context_for(&state, PlayerId(3));
+        assert_ne!(before.public_log, after.public_log);
+        assert_eq!(after, state.context_for(PlayerId(3)));
+    }
+}
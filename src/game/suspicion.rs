@@ -0,0 +1,233 @@
+//! A public-information suspicion prior for town players.
+//!
+//! [`suspicion_scores`] turns what everyone at the table can see — voting
+//! patterns, role claims, accusations, and death-revealed alignments — into a
+//! normalized distribution over the living players. It is computed from
+//! the redacted [`PlayerView`] only, never from hidden roles, so showing
+//! it to a model is a fair reasoning aid rather than a leak. Inclusion in
+//! player contexts is toggled by `GameConfig::suspicion_scores`.
+
+use std::collections::HashMap;
+
+use crate::game::knowledge::Claim;
+use crate::game::state::PlayerId;
+use crate::game::view::PlayerView;
+use crate::roles::{Alignment, Role};
+
+/// Weight applied per vote or accusation against a player later revealed
+/// to be Town — the classic wolf tell of pushing mis-lynches.
+const AGAINST_TOWN: f32 = 1.5;
+/// Weight applied per vote or accusation against a revealed Wolf — town
+/// credit for pushing the right target.
+const AGAINST_WOLF: f32 = 0.5;
+/// Weight applied to every claimant of a power role claimed more than
+/// once — at most one of them is telling the truth.
+const CONFLICTING_CLAIM: f32 = 1.5;
+
+/// Computes a normalized suspicion distribution over the living players
+/// (the viewer excluded — players need no prior on themselves), from
+/// public information only. Scores sum to 1; with no signal at all the
+/// distribution is uniform.
+pub fn suspicion_scores(view: &PlayerView) -> HashMap<PlayerId, f32> {
+    scores_from_public(
+        view.player,
+        &view.alive_players,
+        &view.claims,
+        &view.vote_history,
+        &view.accusations,
+        &view.revealed_alignments,
+    )
+}
+
+/// The actual computation, shared with `GameState::context_for` so the
+/// engine can inject the same scores without building a full view.
+pub(crate) fn scores_from_public(
+    viewer: PlayerId,
+    alive: &[PlayerId],
+    claims: &[Claim],
+    votes: &[(u32, PlayerId, Option<PlayerId>)],
+    accusations: &[(PlayerId, PlayerId)],
+    revealed: &[(PlayerId, Alignment)],
+) -> HashMap<PlayerId, f32> {
+    let mut scores: HashMap<PlayerId, f32> =
+        alive.iter().filter(|&&id| id != viewer).map(|&id| (id, 1.0)).collect();
+    if scores.is_empty() {
+        return scores;
+    }
+
+    let revealed_alignment = |id: PlayerId| {
+        revealed.iter().find(|(dead, _)| *dead == id).map(|(_, alignment)| *alignment)
+    };
+    let pushes = votes
+        .iter()
+        .filter_map(|(_, voter, target)| target.map(|t| (*voter, t)))
+        .chain(accusations.iter().copied());
+    for (pusher, target) in pushes {
+        let Some(score) = scores.get_mut(&pusher) else { continue };
+        match revealed_alignment(target) {
+            Some(Alignment::Town) => *score *= AGAINST_TOWN,
+            Some(Alignment::Wolf) => *score *= AGAINST_WOLF,
+            _ => {}
+        }
+    }
+
+    let mut claim_counts: HashMap<Role, usize> = HashMap::new();
+    for claim in claims {
+        *claim_counts.entry(claim.role).or_default() += 1;
+    }
+    for claim in claims {
+        if claim.role != Role::Villager && claim_counts[&claim.role] > 1
+            && let Some(score) = scores.get_mut(&claim.claimant)
+        {
+            *score *= CONFLICTING_CLAIM;
+        }
+    }
+
+    let total: f32 = scores.values().sum();
+    for score in scores.values_mut() {
+        *score /= total;
+    }
+    scores
+}
+
+/// Renders scores as one transcript line, highest first, for inclusion in
+/// a player context.
+pub(crate) fn format_scores(scores: &HashMap<PlayerId, f32>) -> String {
+    let mut ranked: Vec<(PlayerId, f32)> = scores.iter().map(|(&id, &s)| (id, s)).collect();
+    ranked.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap().then(a.0.cmp(&b.0)));
+    let entries: Vec<String> = ranked
+        .into_iter()
+        .map(|(id, score)| format!("Player {id} {score:.2}"))
+        .collect();
+    format!("Suspicion prior: {}.", entries.join(", "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::event::GameEventKind;
+    use crate::game::night::DeathCause;
+    use crate::game::state::{GameState, Phase};
+
+    /// 0: Werewolf, 1: Seer, 2/3/4: Villagers.
+    fn setup() -> GameState {
+        let mut state = GameState::new(0..5, Phase::Day, 0);
+        state.assign_role(PlayerId(0), Role::Werewolf);
+        state.assign_role(PlayerId(1), Role::Seer);
+        for id in 2..5 {
+            state.assign_role(PlayerId(id), Role::Villager);
+        }
+        state
+    }
+
+    #[test]
+    fn no_signal_yields_a_uniform_distribution() {
+        let scores = suspicion_scores(&setup().player_view(PlayerId(2)));
+        assert_eq!(scores.len(), 4);
+        for &score in scores.values() {
+            assert!((score - 0.25).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn scores_always_sum_to_one() {
+        let mut state = setup();
+        state.record_claim(PlayerId(0), Role::Seer);
+        state.record_claim(PlayerId(1), Role::Seer);
+        state.record(GameEventKind::VoteCast { voter: PlayerId(0), target: Some(PlayerId(3)), reason: None });
+        state.kill(PlayerId(3));
+        state.record(GameEventKind::PlayerDied {
+            player: PlayerId(3),
+            cause: DeathCause::Vote,
+            role: state.revealed_role_of(PlayerId(3)),
+            alignment: state.revealed_alignment_of(PlayerId(3)),
+        });
+        let scores = suspicion_scores(&state.player_view(PlayerId(2)));
+        let total: f32 = scores.values().sum();
+        assert!((total - 1.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn voting_out_a_revealed_townie_raises_suspicion() {
+        let mut state = setup();
+        state.record(GameEventKind::VoteCast { voter: PlayerId(0), target: Some(PlayerId(3)), reason: None });
+        state.record(GameEventKind::VoteCast { voter: PlayerId(4), target: Some(PlayerId(0)), reason: None });
+        state.kill(PlayerId(3));
+        state.record(GameEventKind::PlayerDied {
+            player: PlayerId(3),
+            cause: DeathCause::Vote,
+            role: state.revealed_role_of(PlayerId(3)),
+            alignment: state.revealed_alignment_of(PlayerId(3)),
+        });
+        let scores = suspicion_scores(&state.player_view(PlayerId(2)));
+        assert!(scores[&PlayerId(0)] > scores[&PlayerId(4)], "the mis-lyncher should stand out");
+    }
+
+    #[test]
+    fn conflicting_power_claims_implicate_both_claimants() {
+        let mut state = setup();
+        state.record_claim(PlayerId(0), Role::Seer);
+        state.record_claim(PlayerId(1), Role::Seer);
+        let scores = suspicion_scores(&state.player_view(PlayerId(2)));
+        assert!(scores[&PlayerId(0)] > scores[&PlayerId(4)]);
+        assert!(scores[&PlayerId(1)] > scores[&PlayerId(4)]);
+        assert_eq!(scores[&PlayerId(0)], scores[&PlayerId(1)]);
+    }
+
+    #[test]
+    fn hidden_information_never_moves_the_scores() {
+        let mut state = setup();
+        state.record(GameEventKind::VoteCast { voter: PlayerId(0), target: Some(PlayerId(4)), reason: None });
+        // The Seer privately knows seat 0 is a wolf; a plain villager does
+        // not. Their priors must still agree — the model only sees public
+        // information.
+        crate::game::night::resolve_night(
+            &mut state,
+            vec![(PlayerId(1), crate::game::Action::Investigate(PlayerId(0)))],
+        );
+        let mut seer = suspicion_scores(&state.player_view(PlayerId(1)));
+        let mut villager = suspicion_scores(&state.player_view(PlayerId(2)));
+        // Remove the viewer-dependent entries: each excludes themselves.
+        seer.remove(&PlayerId(2));
+        villager.remove(&PlayerId(1));
+        let renorm = |scores: &mut HashMap<PlayerId, f32>| {
+            let total: f32 = scores.values().sum();
+            for s in scores.values_mut() {
+                *s /= total;
+            }
+        };
+        renorm(&mut seer);
+        renorm(&mut villager);
+        for (id, score) in seer {
+            assert!((score - villager[&id]).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn the_toggle_injects_the_prior_into_town_contexts_only() {
+        let mut state = setup();
+        state.set_show_suspicion(true);
+        let villager = state.context_for(PlayerId(2));
+        assert!(villager
+            .public_log
+            .last()
+            .is_some_and(|line| line.starts_with("Suspicion prior:")));
+        // The wolf gets no prior — it knows where the wolves are.
+        let wolf = state.context_for(PlayerId(0));
+        assert!(!wolf.public_log.iter().any(|l| l.starts_with("Suspicion prior:")));
+        // And the default table shows nobody anything.
+        let quiet = setup().context_for(PlayerId(2));
+        assert!(!quiet.public_log.iter().any(|l| l.starts_with("Suspicion prior:")));
+    }
+
+    #[test]
+    fn formatted_line_ranks_highest_first() {
+        let mut state = setup();
+        state.record_claim(PlayerId(0), Role::Seer);
+        state.record_claim(PlayerId(1), Role::Seer);
+        let scores = suspicion_scores(&state.player_view(PlayerId(2)));
+        let line = format_scores(&scores);
+        assert!(line.starts_with("Suspicion prior: Player 0"));
+        assert!(line.ends_with("."));
+    }
+}
@@ -0,0 +1,553 @@
+//! Per-action timeouts so a hung player degrades the game instead of
+//! blocking it forever.
+//!
+//! Every call into a [`Player`] goes through one of the `timed_*` wrappers
+//! here. On timeout the configured [`FallbackStrategy`] decides the answer
+//! and a [`GameEventKind::FallbackTriggered`] event records that (and why)
+//! in the log.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::clock::{Clock, SystemClock};
+use crate::game::action::Action;
+use crate::game::event::GameEventKind;
+use crate::game::state::{GameState, PlayerId};
+use crate::player::{GameContext, Player};
+
+/// What to answer on behalf of a player who didn't answer in time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize)]
+pub enum FallbackStrategy {
+    /// Abstain from votes, skip night actions, say nothing.
+    #[default]
+    Skip,
+    /// Pick a random legal target from the seeded RNG (votes and night
+    /// actions); speeches still fall back to silence.
+    RandomLegal,
+}
+
+/// Which player call fell back, for the event log.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum ActionKind {
+    Vote,
+    Speech,
+    NightAction,
+    HunterShot,
+    Accusation,
+}
+
+/// Why a fallback was applied.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum FallbackReason {
+    Timeout,
+    /// The player's per-game token budget is spent; see
+    /// [`DiscussionSettings`](crate::game::day::DiscussionSettings).
+    TokenBudget,
+}
+
+/// Timeout and fallback configuration for one game.
+///
+/// Equality compares the knobs only, never the clock.
+#[derive(Debug, Clone)]
+pub struct TurnPolicy {
+    /// How long each individual player call may take.
+    pub timeout: Duration,
+    /// What happens when it doesn't.
+    pub fallback: FallbackStrategy,
+    /// The clock timeouts are measured on; tests swap in a
+    /// [`MockClock`](crate::clock::MockClock) to trigger timeouts without
+    /// real waiting.
+    pub clock: Arc<dyn Clock>,
+}
+
+impl PartialEq for TurnPolicy {
+    fn eq(&self, other: &Self) -> bool {
+        self.timeout == other.timeout && self.fallback == other.fallback
+    }
+}
+
+impl Default for TurnPolicy {
+    fn default() -> Self {
+        Self {
+            timeout: Duration::from_secs(60),
+            fallback: FallbackStrategy::Skip,
+            clock: Arc::new(SystemClock::default()),
+        }
+    }
+}
+
+/// Races a player call against the policy's timeout on the policy's
+/// clock; `None` means the timeout fired first.
+async fn with_timeout<T>(
+    policy: &TurnPolicy,
+    call: impl std::future::Future<Output = T>,
+) -> Option<T> {
+    tokio::select! {
+        out = call => Some(out),
+        () = policy.clock.sleep(policy.timeout) => None,
+    }
+}
+
+fn record_fallback(state: &mut GameState, player: PlayerId, action: ActionKind) {
+    state.record(GameEventKind::FallbackTriggered {
+        player,
+        action,
+        reason: FallbackReason::Timeout,
+    });
+}
+
+/// A random living target other than the actor, if any.
+fn random_other(state: &mut GameState, actor: PlayerId) -> Option<PlayerId> {
+    let candidates: Vec<PlayerId> =
+        state.alive_players().into_iter().filter(|&id| id != actor).collect();
+    state.rng_mut().choose(&candidates).copied()
+}
+
+/// Asks for a vote, applying the fallback on timeout. `None` is an
+/// abstention.
+pub async fn timed_vote(
+    player: &dyn Player,
+    ctx: &GameContext,
+    state: &mut GameState,
+    policy: &TurnPolicy,
+) -> Option<PlayerId> {
+    match with_timeout(policy, player.vote(ctx)).await {
+        Some(target) => Some(target),
+        None => {
+            record_fallback(state, ctx.player, ActionKind::Vote);
+            match policy.fallback {
+                FallbackStrategy::Skip => None,
+                FallbackStrategy::RandomLegal => random_other(state, ctx.player),
+            }
+        }
+    }
+}
+
+/// Asks for a vote with an optional public reason, applying the fallback
+/// on timeout. A fallback vote never carries a reason.
+pub async fn timed_vote_with_reason(
+    player: &dyn Player,
+    ctx: &GameContext,
+    state: &mut GameState,
+    policy: &TurnPolicy,
+) -> (Option<PlayerId>, Option<String>) {
+    match with_timeout(policy, player.vote_with_reason(ctx)).await {
+        Some((target, reason)) => (Some(target), reason),
+        None => {
+            record_fallback(state, ctx.player, ActionKind::Vote);
+            let target = match policy.fallback {
+                FallbackStrategy::Skip => None,
+                FallbackStrategy::RandomLegal => random_other(state, ctx.player),
+            };
+            (target, None)
+        }
+    }
+}
+
+/// Asks for a speech, falling back to silence on timeout.
+pub async fn timed_speak(
+    player: &dyn Player,
+    ctx: &GameContext,
+    state: &mut GameState,
+    policy: &TurnPolicy,
+) -> String {
+    match with_timeout(policy, player.speak(ctx)).await {
+        Some(text) => text,
+        None => {
+            record_fallback(state, ctx.player, ActionKind::Speech);
+            String::new()
+        }
+    }
+}
+
+/// Asks for a speech while forwarding streamed fragments to `observer`,
+/// falling back to silence on timeout.
+pub async fn timed_speak_observed(
+    player: &dyn Player,
+    ctx: &GameContext,
+    state: &mut GameState,
+    policy: &TurnPolicy,
+    observer: &crate::game::day::SpeechObserver,
+) -> String {
+    let on_chunk = |chunk: &str| observer(ctx.player, chunk);
+    match with_timeout(policy, player.speak_streaming(ctx, &on_chunk)).await {
+        Some(text) => text,
+        None => {
+            record_fallback(state, ctx.player, ActionKind::Speech);
+            String::new()
+        }
+    }
+}
+
+/// Asks for a formal accusation, declining it on timeout.
+pub async fn timed_accuse(
+    player: &dyn Player,
+    ctx: &GameContext,
+    state: &mut GameState,
+    policy: &TurnPolicy,
+) -> Option<(PlayerId, String)> {
+    match with_timeout(policy, player.accuse(ctx)).await {
+        Some(accusation) => accusation,
+        None => {
+            record_fallback(state, ctx.player, ActionKind::Accusation);
+            None
+        }
+    }
+}
+
+/// The fallback answer for a timed-out night action.
+fn night_fallback(
+    state: &mut GameState,
+    actor: PlayerId,
+    policy: &TurnPolicy,
+) -> Option<Action> {
+    match policy.fallback {
+        FallbackStrategy::Skip => None,
+        FallbackStrategy::RandomLegal => {
+            // A random legal target for the role's default action; only
+            // roles with a night action get one synthesized.
+            let role = state.role_of(actor)?;
+            if !role.info().acts_at_night {
+                return None;
+            }
+            let target = random_other(state, actor)?;
+            Some(match role {
+                crate::roles::Role::Werewolf | crate::roles::Role::AlphaWerewolf => {
+                    Action::Kill(target)
+                }
+                crate::roles::Role::Seer => Action::Investigate(target),
+                crate::roles::Role::Guard => Action::Protect(target),
+                // The Witch's potions are too situational to spend on her
+                // behalf.
+                _ => return None,
+            })
+        }
+    }
+}
+
+/// Asks for a night action, applying the fallback on timeout.
+pub async fn timed_night_action(
+    player: &dyn Player,
+    ctx: &GameContext,
+    state: &mut GameState,
+    policy: &TurnPolicy,
+) -> Option<Action> {
+    match with_timeout(policy, player.night_action(ctx)).await {
+        Some(action) => action,
+        None => {
+            record_fallback(state, ctx.player, ActionKind::NightAction);
+            night_fallback(state, ctx.player, policy)
+        }
+    }
+}
+
+/// Asks every listed actor for their night action concurrently, cutting
+/// latency when several independent roles (Seer, Guard, Witch) are waiting
+/// on model calls at once.
+///
+/// Only the *gathering* overlaps: actors are sorted by seat first,
+/// contexts are snapshotted up front, every query races the same
+/// per-action timeout, and fallbacks (with their events and RNG draws)
+/// are applied sequentially in seat order — so the result, the log, and
+/// the RNG stream depend only on who was asked, never on completion
+/// timing or the order the caller listed the actors in.
+pub async fn timed_night_actions(
+    actors: &[(PlayerId, &dyn Player)],
+    state: &mut GameState,
+    policy: &TurnPolicy,
+) -> Vec<(PlayerId, Option<Action>)> {
+    let mut actors: Vec<(PlayerId, &dyn Player)> = actors.to_vec();
+    actors.sort_by_key(|&(id, _)| id);
+    let actors = &actors[..];
+    let mut cache = crate::game::state::ContextCache::new();
+    let contexts: Vec<GameContext> =
+        actors.iter().map(|(id, _)| cache.context_for(state, *id)).collect();
+    let queries = actors
+        .iter()
+        .zip(&contexts)
+        .map(|((_, player), ctx)| with_timeout(policy, player.night_action(ctx)));
+    let results = futures::future::join_all(queries).await;
+
+    let mut collected = Vec::with_capacity(actors.len());
+    for ((id, _), result) in actors.iter().zip(results) {
+        let action = match result {
+            Some(action) => action,
+            None => {
+                record_fallback(state, *id, ActionKind::NightAction);
+                night_fallback(state, *id, policy)
+            }
+        };
+        collected.push((*id, action));
+    }
+    collected
+}
+
+#[cfg(test)]
+mod tests {
+    use async_trait::async_trait;
+
+    use super::*;
+    use crate::game::state::Phase;
+    use crate::roles::Role;
+
+    /// Never answers anything.
+    struct HungPlayer;
+
+    #[async_trait]
+    impl Player for HungPlayer {
+        async fn vote(&self, _ctx: &GameContext) -> PlayerId {
+            std::future::pending().await
+        }
+
+        async fn night_action(&self, _ctx: &GameContext) -> Option<Action> {
+            std::future::pending().await
+        }
+
+        async fn speak(&self, _ctx: &GameContext) -> String {
+            std::future::pending().await
+        }
+    }
+
+    fn state_and_ctx(role: Role) -> (GameState, GameContext) {
+        let mut state = GameState::new(0..4, Phase::Night, 1);
+        state.assign_role(PlayerId(0), role);
+        state.assign_role(PlayerId(1), Role::Werewolf);
+        state.assign_role(PlayerId(2), Role::Villager);
+        state.assign_role(PlayerId(3), Role::Villager);
+        let ctx = GameContext {
+            player: PlayerId(0),
+            role,
+            day: 0,
+            phase: Phase::Night,
+            alive_players: state.alive_players(),
+            public_log: Vec::new(),
+            knowledge: Default::default(),
+            claims: Vec::new(),
+            tokens_used: 0,
+            potions: None,
+            last_protected: None,
+            wolf_chat: Vec::new(),
+            graveyard: Vec::new(),
+            cost_so_far: 0.0,
+        };
+        (state, ctx)
+    }
+
+    fn fast(fallback: FallbackStrategy) -> TurnPolicy {
+        TurnPolicy { timeout: Duration::from_millis(5), fallback, ..TurnPolicy::default() }
+    }
+
+    #[tokio::test]
+    async fn timed_out_vote_abstains_under_skip() {
+        let (mut state, ctx) = state_and_ctx(Role::Villager);
+        let vote =
+            timed_vote(&HungPlayer, &ctx, &mut state, &fast(FallbackStrategy::Skip)).await;
+        assert_eq!(vote, None);
+        assert!(state.log().iter().any(|e| matches!(
+            e.kind,
+            GameEventKind::FallbackTriggered {
+                player: PlayerId(0),
+                action: ActionKind::Vote,
+                reason: FallbackReason::Timeout,
+            }
+        )));
+    }
+
+    #[tokio::test]
+    async fn timed_out_vote_picks_random_legal_target() {
+        let (mut state, ctx) = state_and_ctx(Role::Villager);
+        let vote =
+            timed_vote(&HungPlayer, &ctx, &mut state, &fast(FallbackStrategy::RandomLegal))
+                .await;
+        let target = vote.expect("random fallback should pick someone");
+        assert_ne!(target, PlayerId(0));
+        assert!(state.is_alive(target));
+    }
+
+    #[tokio::test]
+    async fn timed_out_speech_is_empty() {
+        let (mut state, ctx) = state_and_ctx(Role::Villager);
+        let text =
+            timed_speak(&HungPlayer, &ctx, &mut state, &fast(FallbackStrategy::Skip)).await;
+        assert_eq!(text, "");
+    }
+
+    #[tokio::test]
+    async fn timed_out_seer_gets_a_random_investigation() {
+        let (mut state, ctx) = state_and_ctx(Role::Seer);
+        let action = timed_night_action(
+            &HungPlayer,
+            &ctx,
+            &mut state,
+            &fast(FallbackStrategy::RandomLegal),
+        )
+        .await;
+        assert!(matches!(action, Some(Action::Investigate(t)) if t != PlayerId(0)));
+    }
+
+    #[tokio::test]
+    async fn concurrent_collection_matches_the_sequential_path() {
+        // 0: Seer, 1: Guard, 2: Witch (hung, falls back), 3: Villager.
+        let build = || {
+            let mut state = GameState::new(0..4, Phase::Night, 9);
+            state.assign_role(PlayerId(0), Role::Seer);
+            state.assign_role(PlayerId(1), Role::Guard);
+            state.assign_role(PlayerId(2), Role::Witch);
+            state.assign_role(PlayerId(3), Role::Villager);
+            state
+        };
+        let scripted = |will: Action| {
+            Box::new(crate::player::ScriptedPlayer::new().will_act(Some(will)))
+                as Box<dyn Player>
+        };
+        let players = || {
+            vec![
+                scripted(Action::Investigate(PlayerId(3))),
+                scripted(Action::Protect(PlayerId(0))),
+                Box::new(HungPlayer) as Box<dyn Player>,
+            ]
+        };
+        let policy = fast(FallbackStrategy::RandomLegal);
+
+        let mut sequential_state = build();
+        let mut sequential = Vec::new();
+        for (id, player) in players().iter().enumerate() {
+            let id = PlayerId(id as u32);
+            let ctx = sequential_state.context_for(id);
+            let action =
+                timed_night_action(player.as_ref(), &ctx, &mut sequential_state, &policy)
+                    .await;
+            sequential.push((id, action));
+        }
+
+        let mut concurrent_state = build();
+        let players = players();
+        let actors: Vec<(PlayerId, &dyn Player)> = players
+            .iter()
+            .enumerate()
+            .map(|(id, p)| (PlayerId(id as u32), p.as_ref()))
+            .collect();
+        let concurrent =
+            timed_night_actions(&actors, &mut concurrent_state, &policy).await;
+
+        assert_eq!(concurrent, sequential);
+        let kinds = |state: &GameState| -> Vec<GameEventKind> {
+            state.log().iter().map(|e| e.kind.clone()).collect()
+        };
+        assert_eq!(kinds(&concurrent_state), kinds(&sequential_state));
+    }
+
+    /// Answers one fixed action after a fixed delay.
+    struct DelayedPlayer {
+        delay: Duration,
+        action: Action,
+    }
+
+    #[async_trait]
+    impl Player for DelayedPlayer {
+        async fn vote(&self, _ctx: &GameContext) -> PlayerId {
+            unreachable!("only night actions are queried")
+        }
+
+        async fn night_action(&self, _ctx: &GameContext) -> Option<Action> {
+            tokio::time::sleep(self.delay).await;
+            Some(self.action.clone())
+        }
+
+        async fn speak(&self, _ctx: &GameContext) -> String {
+            unreachable!("only night actions are queried")
+        }
+    }
+
+    #[tokio::test]
+    async fn completion_timing_never_reaches_the_outcome_or_the_log() {
+        // 0: Guard, 1: Seer, 2: Werewolf, 3/4: Villagers.
+        let build = || {
+            let mut state = GameState::new(0..5, Phase::Night, 1);
+            state.assign_role(PlayerId(0), Role::Guard);
+            state.assign_role(PlayerId(1), Role::Seer);
+            state.assign_role(PlayerId(2), Role::Werewolf);
+            state.assign_role(PlayerId(3), Role::Villager);
+            state.assign_role(PlayerId(4), Role::Villager);
+            state
+        };
+        let script = [
+            (PlayerId(0), Action::Protect(PlayerId(4))),
+            (PlayerId(1), Action::Investigate(PlayerId(2))),
+            (PlayerId(2), Action::Kill(PlayerId(3))),
+        ];
+        let policy = TurnPolicy {
+            timeout: Duration::from_secs(5),
+            fallback: FallbackStrategy::Skip,
+            ..TurnPolicy::default()
+        };
+        // Each run hands the same seats a different delay assignment, so
+        // the queries complete in a different order every time; the actors
+        // are also presented in reverse seat order to exercise the sort.
+        let mut runs = Vec::new();
+        for delays in [[0u64, 4, 8], [8, 4, 0], [4, 8, 0]] {
+            let players: Vec<(PlayerId, DelayedPlayer)> = script
+                .iter()
+                .zip(delays)
+                .map(|((id, action), ms)| {
+                    (*id, DelayedPlayer {
+                        delay: Duration::from_millis(ms),
+                        action: action.clone(),
+                    })
+                })
+                .rev()
+                .collect();
+            let actors: Vec<(PlayerId, &dyn Player)> =
+                players.iter().map(|(id, p)| (*id, p as &dyn Player)).collect();
+            let mut state = build();
+            let collected = timed_night_actions(&actors, &mut state, &policy).await;
+            let actions: Vec<(PlayerId, Action)> = collected
+                .iter()
+                .filter_map(|(id, action)| action.clone().map(|a| (*id, a)))
+                .collect();
+            let outcome = crate::game::night::resolve_night(&mut state, actions);
+            let log: Vec<GameEventKind> =
+                state.log().iter().map(|e| e.kind.clone()).collect();
+            runs.push((collected, outcome, log));
+        }
+        // Gathered in seat order despite the reversed presentation.
+        let seats: Vec<PlayerId> = runs[0].0.iter().map(|(id, _)| *id).collect();
+        assert_eq!(seats, vec![PlayerId(0), PlayerId(1), PlayerId(2)]);
+        assert_eq!(runs[1], runs[0]);
+        assert_eq!(runs[2], runs[0]);
+    }
+
+    #[tokio::test]
+    async fn a_mock_clock_advance_triggers_the_timeout_without_waiting() {
+        let (mut state, ctx) = state_and_ctx(Role::Villager);
+        let clock = Arc::new(crate::clock::MockClock::new());
+        let policy = TurnPolicy {
+            timeout: Duration::from_secs(3600),
+            fallback: FallbackStrategy::Skip,
+            clock: clock.clone(),
+        };
+        // The hour-long timeout would stall a real clock; advancing the
+        // mock (once the sleep has registered) fires it instantly.
+        let (vote, ()) = tokio::join!(
+            timed_vote(&HungPlayer, &ctx, &mut state, &policy),
+            async {
+                tokio::task::yield_now().await;
+                clock.advance(Duration::from_secs(3600));
+            }
+        );
+        assert_eq!(vote, None);
+        assert!(state.log().iter().any(|e| matches!(
+            e.kind,
+            GameEventKind::FallbackTriggered { player: PlayerId(0), action: ActionKind::Vote, .. }
+        )));
+    }
+
+    #[tokio::test]
+    async fn responsive_player_is_untouched() {
+        let (mut state, ctx) = state_and_ctx(Role::Villager);
+        let p = crate::player::ScriptedPlayer::new().will_vote(PlayerId(2));
+        let vote = timed_vote(&p, &ctx, &mut state, &fast(FallbackStrategy::Skip)).await;
+        assert_eq!(vote, Some(PlayerId(2)));
+        assert!(state.log().is_empty());
+    }
+}
@@ -0,0 +1,267 @@
+//! Action legality checks, separate from resolution.
+//!
+//! [`validate_action`] answers one question — may this actor take this
+//! action right now? — without applying anything. Every entry point
+//! (scripted players, LLM replies, the WebSocket server) runs submissions
+//! through the same check, so an illegal action is rejected identically no
+//! matter where it came from, and [`resolve_night_with`] can assume its
+//! inputs are plausible.
+//!
+//! [`resolve_night_with`]: crate::game::night::resolve_night_with
+
+use crate::game::action::Action;
+use crate::game::state::{GameState, PlayerId};
+use crate::roles::{Alignment, Role};
+
+/// Why an action was rejected.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum InvalidAction {
+    /// The actor is not a player in this game.
+    #[error("player {0} is not in this game")]
+    UnknownActor(PlayerId),
+    /// The actor is dead (and the action is not a dying Hunter's shot).
+    #[error("player {0} is dead")]
+    DeadActor(PlayerId),
+    /// The actor's role does not grant this action.
+    #[error("player {actor}'s role does not allow {action:?}")]
+    RoleForbids { actor: PlayerId, action: Action },
+    /// The target is not a player in this game.
+    #[error("target {0} is not in this game")]
+    UnknownTarget(PlayerId),
+    /// The target is already dead.
+    #[error("target {0} is dead")]
+    DeadTarget(PlayerId),
+    /// The Witch's heal potion is already spent.
+    #[error("the heal potion is already spent")]
+    HealSpent,
+    /// The Witch's poison potion is already spent.
+    #[error("the poison potion is already spent")]
+    PoisonSpent,
+    /// The table forbids the Witch healing herself.
+    #[error("the Witch may not heal herself at this table")]
+    SelfHealForbidden,
+    /// The table forbids the Guard protecting himself.
+    #[error("the Guard may not protect himself at this table")]
+    SelfGuardForbidden,
+    /// The table forbids protecting the same target two nights running.
+    #[error("player {0} was already protected last night")]
+    RepeatedProtection(PlayerId),
+}
+
+/// Checks that `actor` may legally take `action` against the current
+/// state. `Ok(())` means resolution can proceed; `Err` names the first
+/// rule the action breaks, so callers can log it and fall back.
+///
+/// The checks, in order: the actor must be seated and alive (a dying
+/// Hunter's shot is the one action allowed from the dead), the actor's
+/// role must grant the action, any target must be seated and alive, and
+/// the Witch/Guard table rules must hold. Same-night interactions (e.g.
+/// one potion per night) depend on the rest of tonight's actions and stay
+/// in resolution.
+pub fn validate_action(
+    state: &GameState,
+    actor: PlayerId,
+    action: &Action,
+) -> Result<(), InvalidAction> {
+    if !state.players().iter().any(|p| p.id == actor) {
+        return Err(InvalidAction::UnknownActor(actor));
+    }
+    if !state.is_alive(actor) && !matches!(action, Action::HunterShot(_)) {
+        return Err(InvalidAction::DeadActor(actor));
+    }
+
+    let role = state.role_of(actor);
+    let permitted = match action {
+        // A Minion is wolf-aligned but has no kill: the chat check is by
+        // alignment, the kill check also requires a night action.
+        Action::Kill(_) => role
+            .is_some_and(|r| r.alignment() == Alignment::Wolf && r.info().acts_at_night),
+        Action::WolfChat(_) => role.is_some_and(|r| r.alignment() == Alignment::Wolf),
+        Action::Protect(_) => role == Some(Role::Guard),
+        Action::Investigate(_) => role == Some(Role::Seer),
+        Action::Heal(_) | Action::Poison(_) => role == Some(Role::Witch),
+        Action::HunterShot(_) => role == Some(Role::Hunter),
+        Action::Vote(_) | Action::Accuse(_) | Action::Claim(_) | Action::Pass => true,
+    };
+    if !permitted {
+        return Err(InvalidAction::RoleForbids { actor, action: action.clone() });
+    }
+
+    if let Some(target) = action.target() {
+        if !state.players().iter().any(|p| p.id == target) {
+            return Err(InvalidAction::UnknownTarget(target));
+        }
+        if !state.is_alive(target) {
+            return Err(InvalidAction::DeadTarget(target));
+        }
+    }
+
+    match action {
+        Action::Heal(target) => {
+            if !state.potions_of(actor).heal_available {
+                return Err(InvalidAction::HealSpent);
+            }
+            if !state.witch_rules().may_self_heal && *target == actor {
+                return Err(InvalidAction::SelfHealForbidden);
+            }
+        }
+        Action::Poison(_) if !state.potions_of(actor).poison_available => {
+            return Err(InvalidAction::PoisonSpent);
+        }
+        Action::Protect(target) => {
+            let rules = state.guard_rules();
+            if !rules.may_guard_self && *target == actor {
+                return Err(InvalidAction::SelfGuardForbidden);
+            }
+            if !rules.may_repeat && state.last_protected_of(actor) == Some(*target) {
+                return Err(InvalidAction::RepeatedProtection(*target));
+            }
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::night::{GuardRules, WitchRules, resolve_night};
+    use crate::game::state::Phase;
+
+    /// 0: Guard, 1: Werewolf, 2: Witch, 3: Hunter, 4: Seer.
+    fn setup() -> GameState {
+        let mut state = GameState::new(0..5, Phase::Night, 0);
+        state.assign_role(PlayerId(0), Role::Guard);
+        state.assign_role(PlayerId(1), Role::Werewolf);
+        state.assign_role(PlayerId(2), Role::Witch);
+        state.assign_role(PlayerId(3), Role::Hunter);
+        state.assign_role(PlayerId(4), Role::Seer);
+        state
+    }
+
+    #[test]
+    fn a_legal_kill_passes() {
+        let state = setup();
+        assert_eq!(validate_action(&state, PlayerId(1), &Action::Kill(PlayerId(3))), Ok(()));
+    }
+
+    #[test]
+    fn an_unknown_actor_is_rejected() {
+        let state = setup();
+        assert_eq!(
+            validate_action(&state, PlayerId(9), &Action::Vote(PlayerId(1))),
+            Err(InvalidAction::UnknownActor(PlayerId(9)))
+        );
+    }
+
+    #[test]
+    fn a_dead_actor_is_rejected() {
+        let mut state = setup();
+        state.kill(PlayerId(4));
+        assert_eq!(
+            validate_action(&state, PlayerId(4), &Action::Investigate(PlayerId(1))),
+            Err(InvalidAction::DeadActor(PlayerId(4)))
+        );
+    }
+
+    #[test]
+    fn a_dead_hunter_may_still_shoot() {
+        let mut state = setup();
+        state.kill(PlayerId(3));
+        assert_eq!(validate_action(&state, PlayerId(3), &Action::HunterShot(PlayerId(1))), Ok(()));
+    }
+
+    #[test]
+    fn a_role_without_the_power_is_rejected() {
+        let state = setup();
+        assert_eq!(
+            validate_action(&state, PlayerId(4), &Action::Kill(PlayerId(1))),
+            Err(InvalidAction::RoleForbids { actor: PlayerId(4), action: Action::Kill(PlayerId(1)) })
+        );
+        assert_eq!(
+            validate_action(&state, PlayerId(0), &Action::HunterShot(PlayerId(1))),
+            Err(InvalidAction::RoleForbids { actor: PlayerId(0), action: Action::HunterShot(PlayerId(1)) })
+        );
+    }
+
+    #[test]
+    fn day_actions_need_no_special_role() {
+        let state = setup();
+        assert_eq!(validate_action(&state, PlayerId(4), &Action::Vote(PlayerId(1))), Ok(()));
+        assert_eq!(validate_action(&state, PlayerId(1), &Action::Claim(Role::Villager)), Ok(()));
+        assert_eq!(validate_action(&state, PlayerId(0), &Action::Pass), Ok(()));
+    }
+
+    #[test]
+    fn an_unknown_target_is_rejected() {
+        let state = setup();
+        assert_eq!(
+            validate_action(&state, PlayerId(1), &Action::Kill(PlayerId(9))),
+            Err(InvalidAction::UnknownTarget(PlayerId(9)))
+        );
+    }
+
+    #[test]
+    fn a_dead_target_is_rejected() {
+        let mut state = setup();
+        state.kill(PlayerId(4));
+        assert_eq!(
+            validate_action(&state, PlayerId(1), &Action::Kill(PlayerId(4))),
+            Err(InvalidAction::DeadTarget(PlayerId(4)))
+        );
+    }
+
+    #[test]
+    fn a_spent_heal_is_rejected() {
+        let mut state = setup();
+        state.spend_heal(PlayerId(2));
+        assert_eq!(
+            validate_action(&state, PlayerId(2), &Action::Heal(PlayerId(3))),
+            Err(InvalidAction::HealSpent)
+        );
+    }
+
+    #[test]
+    fn a_spent_poison_is_rejected() {
+        let mut state = setup();
+        state.spend_poison(PlayerId(2));
+        assert_eq!(
+            validate_action(&state, PlayerId(2), &Action::Poison(PlayerId(3))),
+            Err(InvalidAction::PoisonSpent)
+        );
+    }
+
+    #[test]
+    fn forbidden_self_heal_is_rejected() {
+        let mut state = setup();
+        state.set_witch_rules(WitchRules { may_self_heal: false, ..Default::default() });
+        assert_eq!(
+            validate_action(&state, PlayerId(2), &Action::Heal(PlayerId(2))),
+            Err(InvalidAction::SelfHealForbidden)
+        );
+        // The permissive default allows it.
+        assert_eq!(validate_action(&setup(), PlayerId(2), &Action::Heal(PlayerId(2))), Ok(()));
+    }
+
+    #[test]
+    fn forbidden_self_guard_is_rejected() {
+        let mut state = setup();
+        state.set_guard_rules(GuardRules { may_guard_self: false, ..Default::default() });
+        assert_eq!(
+            validate_action(&state, PlayerId(0), &Action::Protect(PlayerId(0))),
+            Err(InvalidAction::SelfGuardForbidden)
+        );
+    }
+
+    #[test]
+    fn repeated_protection_is_rejected() {
+        let mut state = setup();
+        resolve_night(&mut state, vec![(PlayerId(0), Action::Protect(PlayerId(3)))]);
+        assert_eq!(
+            validate_action(&state, PlayerId(0), &Action::Protect(PlayerId(3))),
+            Err(InvalidAction::RepeatedProtection(PlayerId(3)))
+        );
+        assert_eq!(validate_action(&state, PlayerId(0), &Action::Protect(PlayerId(4))), Ok(()));
+    }
+}
@@ -0,0 +1,428 @@
+//! Serializable snapshots of a running game, for dashboards and replays.
+//!
+//! [`GameSnapshot`] is the omniscient God view; [`PlayerView`] is the
+//! redacted view for one seat, containing only what that player
+//! legitimately knows. Both are plain data, cheap to build every phase and
+//! serialize to JSON for polling clients.
+
+use serde::{Deserialize, Serialize};
+
+use crate::game::event::GameEventKind;
+use crate::game::knowledge::{Claim, KnowledgeBase};
+use crate::game::state::{GameState, Phase, PlayerId};
+use crate::roles::{Alignment, Role};
+
+/// One seat in the omniscient snapshot.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlayerSnapshot {
+    pub id: PlayerId,
+    pub alive: bool,
+    /// The built-in role, if one is assigned.
+    pub role: Option<Role>,
+    /// The registry key of a custom role, if one is assigned.
+    pub custom_role: Option<String>,
+}
+
+/// The full, hidden-information view of a game. Never show this to a
+/// player.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GameSnapshot {
+    pub day: u32,
+    pub phase: Phase,
+    pub players: Vec<PlayerSnapshot>,
+    /// Votes cast since the current phase began, in cast order. `None`
+    /// targets are abstentions.
+    pub votes_this_phase: Vec<(PlayerId, Option<PlayerId>)>,
+    /// The graveyard transcript — dead players talking among themselves.
+    /// God view only; living players never see it.
+    pub graveyard: Vec<(PlayerId, String)>,
+}
+
+/// What one player legitimately knows: public information plus their own
+/// role and private knowledge.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlayerView {
+    pub player: PlayerId,
+    /// This player's own role only.
+    pub role: Option<Role>,
+    pub day: u32,
+    pub phase: Phase,
+    pub alive_players: Vec<PlayerId>,
+    /// The public transcript, as in `GameContext`.
+    pub public_log: Vec<String>,
+    /// This player's own private knowledge.
+    pub knowledge: KnowledgeBase,
+    /// Public role claims — common knowledge, not verified roles.
+    pub claims: Vec<Claim>,
+    /// Votes cast since the current phase began; votes are public.
+    pub votes_this_phase: Vec<(PlayerId, Option<PlayerId>)>,
+    /// Every vote cast in the game as `(day, voter, target)`; votes are
+    /// public record.
+    pub vote_history: Vec<(u32, PlayerId, Option<PlayerId>)>,
+    /// Every formal accusation as `(accuser, accused)` — public record.
+    pub accusations: Vec<(PlayerId, PlayerId)>,
+    /// Exact roles made public by death, under [`DeathReveal::Full`] only.
+    /// Empty otherwise.
+    ///
+    /// [`DeathReveal::Full`]: crate::game::death::DeathReveal
+    pub revealed_roles: Vec<(PlayerId, Role)>,
+    /// Alignments made public by death, under both [`DeathReveal::Full`]
+    /// and [`DeathReveal::AlignmentOnly`]. Empty under
+    /// [`DeathReveal::Hidden`].
+    ///
+    /// [`DeathReveal::Full`]: crate::game::death::DeathReveal
+    /// [`DeathReveal::AlignmentOnly`]: crate::game::death::DeathReveal
+    /// [`DeathReveal::Hidden`]: crate::game::death::DeathReveal
+    #[serde(default)]
+    pub revealed_alignments: Vec<(PlayerId, Alignment)>,
+    /// The wolves' private transcript — populated only when this seat is
+    /// wolf-aligned, always empty for town.
+    pub wolf_chat: Vec<(PlayerId, String)>,
+    /// The graveyard transcript — populated only once this seat is dead,
+    /// always empty for the living.
+    pub graveyard: Vec<(PlayerId, String)>,
+}
+
+/// The rehydration bundle for a player reconnecting mid-game.
+///
+/// [`PlayerView::public_log`] collapses earlier days into one-line
+/// summaries to keep prompts short; a frontend replaying the whole game
+/// for a returning human needs every line, so `history` keeps them all.
+/// The same redaction rules apply: `history` is strictly public, and the
+/// embedded view carries only the reconnecting seat's own hidden
+/// information.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CatchupView {
+    /// The current redacted snapshot, exactly as
+    /// [`GameState::player_view`] builds it — the seat's own role,
+    /// investigation results, and wolf chat when wolf-aligned.
+    pub view: PlayerView,
+    /// Every public line of the game in order: speech, accusations,
+    /// defenses, last words, open votes, and death announcements.
+    pub history: Vec<String>,
+}
+
+impl GameState {
+    /// Votes cast since the last phase change, in cast order.
+    fn votes_this_phase(&self) -> Vec<(PlayerId, Option<PlayerId>)> {
+        let mut votes: Vec<(PlayerId, Option<PlayerId>)> = Vec::new();
+        for event in self.log().iter().rev() {
+            match &event.kind {
+                GameEventKind::VoteCast { voter, target, .. } => votes.push((*voter, *target)),
+                GameEventKind::PhaseChanged { .. } => break,
+                _ => {}
+            }
+        }
+        votes.reverse();
+        votes
+    }
+
+    /// Every vote cast over the whole game, in cast order. Votes are
+    /// public, so this may appear in redacted views.
+    pub(crate) fn vote_history(&self) -> Vec<(u32, PlayerId, Option<PlayerId>)> {
+        self.log()
+            .iter()
+            .filter_map(|e| match e.kind {
+                GameEventKind::VoteCast { voter, target, .. } => {
+                    Some((e.day, voter, target))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Every formal accusation made over the whole game, in order.
+    pub(crate) fn accusation_pairs(&self) -> Vec<(PlayerId, PlayerId)> {
+        self.log()
+            .iter()
+            .filter_map(|e| match e.kind {
+                GameEventKind::Accusation { accuser, accused, .. } => {
+                    Some((accuser, accused))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Roles that became common knowledge through death events. Only
+    /// populated when deaths were recorded with the role revealed.
+    pub(crate) fn revealed_roles(&self) -> Vec<(PlayerId, Role)> {
+        self.log()
+            .iter()
+            .filter_map(|e| match e.kind {
+                GameEventKind::PlayerDied { player, role: Some(role), .. } => {
+                    Some((player, role))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Alignments that became common knowledge through death events,
+    /// populated whenever deaths were recorded with at least the alignment
+    /// revealed.
+    pub(crate) fn revealed_alignments(&self) -> Vec<(PlayerId, Alignment)> {
+        self.log()
+            .iter()
+            .filter_map(|e| match e.kind {
+                GameEventKind::PlayerDied { player, alignment: Some(alignment), .. } => {
+                    Some((player, alignment))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// The omniscient snapshot: every role, alive flag, and the votes
+    /// accumulated this phase. For spectator dashboards and replays only —
+    /// never hand this to a player.
+    pub fn snapshot(&self) -> GameSnapshot {
+        GameSnapshot {
+            day: self.day(),
+            phase: self.phase(),
+            players: self
+                .players()
+                .iter()
+                .map(|p| PlayerSnapshot {
+                    id: p.id,
+                    alive: p.alive,
+                    role: self.role_of(p.id),
+                    custom_role: self.custom_role_of(p.id).map(str::to_string),
+                })
+                .collect(),
+            votes_this_phase: self.votes_this_phase(),
+            graveyard: self.graveyard_chat(),
+        }
+    }
+
+    /// The redacted view for one seat: public information, the seat's own
+    /// role, and its private knowledge. No other player's role is
+    /// reachable from here.
+    pub fn player_view(&self, id: PlayerId) -> PlayerView {
+        let ctx = self.context_for(id);
+        PlayerView {
+            player: id,
+            role: self.role_of(id),
+            day: self.day(),
+            phase: self.phase(),
+            alive_players: ctx.alive_players,
+            public_log: ctx.public_log,
+            knowledge: ctx.knowledge,
+            claims: ctx.claims,
+            votes_this_phase: self.votes_this_phase(),
+            vote_history: self.vote_history(),
+            accusations: self.accusation_pairs(),
+            revealed_roles: self.revealed_roles(),
+            revealed_alignments: self.revealed_alignments(),
+            wolf_chat: ctx.wolf_chat,
+            graveyard: ctx.graveyard,
+        }
+    }
+
+    /// The catch-up bundle for a player reconnecting mid-game: the
+    /// redacted view they would get right now, plus the full public
+    /// transcript from day 0 on so the frontend can replay the game.
+    /// Contains `id`'s own hidden information and nobody else's.
+    pub fn catchup_view(&self, id: PlayerId) -> CatchupView {
+        CatchupView { view: self.player_view(id), history: self.public_transcript() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::death::DeathReveal;
+    use crate::game::night::DeathCause;
+
+    /// 0: Werewolf, 1: Seer, 2/3: Villagers.
+    fn setup() -> GameState {
+        let mut state = GameState::new(0..4, Phase::Night, 0);
+        state.assign_role(PlayerId(0), Role::Werewolf);
+        state.assign_role(PlayerId(1), Role::Seer);
+        state.assign_role(PlayerId(2), Role::Villager);
+        state.assign_role(PlayerId(3), Role::Villager);
+        state
+    }
+
+    #[test]
+    fn snapshot_is_omniscient() {
+        let mut state = setup();
+        state.kill(PlayerId(3));
+        state.record(GameEventKind::PlayerDied {
+            player: PlayerId(3),
+            cause: DeathCause::WolfKill,
+            role: state.revealed_role_of(PlayerId(3)),
+            alignment: state.revealed_alignment_of(PlayerId(3)),
+        });
+        let snapshot = state.snapshot();
+        assert_eq!(snapshot.phase, Phase::Night);
+        assert_eq!(snapshot.players.len(), 4);
+        assert_eq!(snapshot.players[0].role, Some(Role::Werewolf));
+        assert!(!snapshot.players[3].alive);
+    }
+
+    #[test]
+    fn snapshot_collects_only_this_phases_votes() {
+        let mut state = setup();
+        state.record(GameEventKind::VoteCast { voter: PlayerId(0), target: Some(PlayerId(2)), reason: None });
+        state.advance(); // Night -> Day: resets the window
+        state.record(GameEventKind::VoteCast { voter: PlayerId(1), target: Some(PlayerId(3)), reason: None });
+        state.record(GameEventKind::VoteCast { voter: PlayerId(2), target: None, reason: None });
+        assert_eq!(state.snapshot().votes_this_phase, vec![(PlayerId(1), Some(PlayerId(3))), (PlayerId(2), None)]);
+    }
+
+    #[test]
+    fn villager_view_leaks_no_other_roles() {
+        let state = setup();
+        let view = state.player_view(PlayerId(2));
+        assert_eq!(view.role, Some(Role::Villager));
+        let json = serde_json::to_string(&view).unwrap();
+        assert!(!json.contains("Werewolf"));
+        assert!(!json.contains("Seer"));
+    }
+
+    #[test]
+    fn death_reveals_the_role_only_when_the_table_says_so() {
+        // Revealing table: the Seer's death makes her role common knowledge.
+        let mut open = setup();
+        open.kill(PlayerId(1));
+        open.record(GameEventKind::PlayerDied {
+            player: PlayerId(1),
+            cause: DeathCause::WolfKill,
+            role: open.revealed_role_of(PlayerId(1)),
+            alignment: open.revealed_alignment_of(PlayerId(1)),
+        });
+        assert_eq!(open.player_view(PlayerId(2)).revealed_roles, vec![(PlayerId(1), Role::Seer)]);
+
+        // Hidden table: the same death leaks nothing.
+        let mut closed = setup();
+        closed.set_death_reveal(DeathReveal::Hidden);
+        closed.kill(PlayerId(1));
+        closed.record(GameEventKind::PlayerDied {
+            player: PlayerId(1),
+            cause: DeathCause::WolfKill,
+            role: closed.revealed_role_of(PlayerId(1)),
+            alignment: closed.revealed_alignment_of(PlayerId(1)),
+        });
+        let view = closed.player_view(PlayerId(2));
+        assert!(view.revealed_roles.is_empty());
+        assert!(view.revealed_alignments.is_empty());
+        assert!(!serde_json::to_string(&view).unwrap().contains("Seer"));
+    }
+
+    #[test]
+    fn alignment_only_shows_the_side_but_not_the_role() {
+        let mut state = setup();
+        state.set_death_reveal(DeathReveal::AlignmentOnly);
+        state.kill(PlayerId(0));
+        state.record(GameEventKind::PlayerDied {
+            player: PlayerId(0),
+            cause: DeathCause::Vote,
+            role: state.revealed_role_of(PlayerId(0)),
+            alignment: state.revealed_alignment_of(PlayerId(0)),
+        });
+        let view = state.player_view(PlayerId(2));
+        assert!(view.revealed_roles.is_empty());
+        assert_eq!(view.revealed_alignments, vec![(PlayerId(0), Alignment::Wolf)]);
+        assert!(!serde_json::to_string(&view).unwrap().contains("Werewolf"));
+        // The God view is unaffected: it reads roles from the state, not
+        // from the redaction.
+        assert_eq!(state.snapshot().players[0].role, Some(Role::Werewolf));
+    }
+
+    #[test]
+    fn masons_know_each_other_and_nobody_else_does() {
+        use crate::game::state::Relationship;
+        let mut state = setup();
+        state.add_relationship(PlayerId(2), PlayerId(3), Relationship::Masons);
+        // Mutual: each partner's private knowledge names the other.
+        assert_eq!(state.player_view(PlayerId(2)).knowledge.partners, vec![(PlayerId(3), Relationship::Masons)]);
+        assert_eq!(state.player_view(PlayerId(3)).knowledge.partners, vec![(PlayerId(2), Relationship::Masons)]);
+        // Hidden: no other seat's view carries a trace of the bond.
+        for outsider in [PlayerId(0), PlayerId(1)] {
+            let view = state.player_view(outsider);
+            assert!(view.knowledge.partners.is_empty());
+            assert!(!serde_json::to_string(&view).unwrap().contains("Masons"));
+        }
+    }
+
+    #[test]
+    fn graveyard_chat_reaches_only_god_and_the_dead() {
+        let mut state = setup();
+        state.kill(PlayerId(3));
+        state.record(GameEventKind::GraveyardChat {
+            player: PlayerId(3),
+            text: "Watch seat 0.".into(),
+        });
+        assert_eq!(state.snapshot().graveyard, vec![(PlayerId(3), "Watch seat 0.".to_string())]);
+        assert_eq!(state.player_view(PlayerId(3)).graveyard, vec![(PlayerId(3), "Watch seat 0.".to_string())]);
+        let living = state.player_view(PlayerId(2));
+        assert!(living.graveyard.is_empty());
+        assert!(!serde_json::to_string(&living).unwrap().contains("Watch seat 0"));
+    }
+
+    #[test]
+    fn seer_view_keeps_own_investigations() {
+        let mut state = setup();
+        crate::game::night::resolve_night(
+            &mut state,
+            vec![(PlayerId(1), crate::game::Action::Investigate(PlayerId(0)))],
+        );
+        let view = state.player_view(PlayerId(1));
+        assert_eq!(view.knowledge.about(PlayerId(0)), Some(crate::roles::Alignment::Wolf));
+        // And the villager still learns nothing from it.
+        assert!(state.player_view(PlayerId(2)).knowledge.investigations.is_empty());
+    }
+
+    #[test]
+    fn a_reconnecting_wolf_recovers_wolf_chat_and_a_villager_does_not() {
+        let mut state = setup();
+        state.record_wolf_chat(PlayerId(0), "Take the Seer tonight.".into());
+        let wolf = state.catchup_view(PlayerId(0));
+        assert_eq!(wolf.view.wolf_chat, vec![(PlayerId(0), "Take the Seer tonight.".to_string())]);
+        let villager = state.catchup_view(PlayerId(2));
+        assert!(villager.view.wolf_chat.is_empty());
+        assert!(!serde_json::to_string(&villager).unwrap().contains("Take the Seer"));
+    }
+
+    #[test]
+    fn catchup_history_keeps_earlier_days_verbatim() {
+        let mut state = setup();
+        state.advance(); // Night -> Day 1
+        state.record(GameEventKind::PlayerSpoke {
+            player: PlayerId(2),
+            text: "Seat 0 is quiet.".into(),
+        });
+        state.advance(); // Day -> Voting
+        state.advance(); // Voting -> Night
+        state.advance(); // Night -> Day 2
+        // The prompt context has collapsed day 1 into a summary line; the
+        // catch-up history still carries the statement itself.
+        let view = state.catchup_view(PlayerId(2));
+        assert!(!view.view.public_log.iter().any(|l| l.contains("Seat 0 is quiet.")));
+        assert!(view.history.contains(&"Player 2: Seat 0 is quiet.".to_string()));
+    }
+
+    #[test]
+    fn catchup_history_announces_deaths_without_naming_roles() {
+        let mut state = setup();
+        state.set_death_reveal(DeathReveal::Hidden);
+        state.kill(PlayerId(1));
+        state.record(GameEventKind::PlayerDied {
+            player: PlayerId(1),
+            cause: DeathCause::WolfKill,
+            role: state.revealed_role_of(PlayerId(1)),
+            alignment: state.revealed_alignment_of(PlayerId(1)),
+        });
+        let view = state.catchup_view(PlayerId(2));
+        assert!(view.history.contains(&"Player 1 died.".to_string()));
+        assert!(!serde_json::to_string(&view).unwrap().contains("Seer"));
+    }
+
+    #[test]
+    fn both_views_serialize_to_json() {
+        let state = setup();
+        assert!(serde_json::to_string(&state.snapshot()).is_ok());
+        assert!(serde_json::to_string(&state.player_view(PlayerId(0))).is_ok());
+    }
+}
@@ -0,0 +1,677 @@
+//! Day-phase vote tallying.
+
+use std::collections::{BTreeMap, HashMap};
+
+use serde::{Deserialize, Serialize};
+
+use crate::game::event::GameEventKind;
+use crate::game::rng::Rng;
+use crate::game::state::{GameState, PlayerId};
+use crate::game::timeout::{TurnPolicy, timed_vote, timed_vote_with_reason};
+use crate::player::Player;
+
+/// What to do when the top of the tally is shared.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum TieResolution {
+    /// Nobody is eliminated on a tie.
+    #[default]
+    NoElimination,
+    /// The seeded RNG picks one of the tied players.
+    Random,
+    /// Signal the caller to run another voting round among the tied.
+    Revote,
+    /// The tied player with the numerically smallest [`PlayerId`] is
+    /// eliminated. Fully deterministic without touching the RNG stream,
+    /// which keeps golden tests stable even when other RNG draws move.
+    LowestSeat,
+}
+
+/// The decision a tally produced.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum VoteOutcome {
+    /// A single player received the most votes and is eliminated.
+    Eliminated(PlayerId),
+    /// Nobody is eliminated (tie under `NoElimination`, or no votes cast).
+    NoElimination,
+    /// The caller should run a runoff among these tied players.
+    Revote(Vec<PlayerId>),
+}
+
+/// A completed tally with the full per-candidate board.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VoteResult {
+    /// Votes received per candidate, every candidate included.
+    pub counts: BTreeMap<PlayerId, usize>,
+    /// Number of voters who abstained.
+    pub abstentions: usize,
+    /// The resulting decision.
+    pub outcome: VoteOutcome,
+}
+
+/// Counts votes and determines the eliminated player.
+///
+/// Each entry is `(voter, target)`; a `None` target is an abstention, which
+/// is counted and reported but never elects anyone. Ties at the top are
+/// settled by `tie`; `Random` draws from the game's seeded RNG so replays
+/// stay deterministic, while `LowestSeat` picks the smallest tied
+/// `PlayerId` without consuming the RNG stream at all.
+pub fn tally(
+    votes: &[(PlayerId, Option<PlayerId>)],
+    tie: TieResolution,
+    rng: &mut Rng,
+) -> VoteResult {
+    let mut counts: BTreeMap<PlayerId, usize> = BTreeMap::new();
+    let mut abstentions = 0usize;
+    for (_, target) in votes {
+        match target {
+            Some(candidate) => *counts.entry(*candidate).or_default() += 1,
+            None => abstentions += 1,
+        }
+    }
+
+    let top = counts.values().copied().max().unwrap_or(0);
+    // BTreeMap iteration keeps the tied list sorted by PlayerId, so the
+    // random draw below consumes the RNG stream deterministically.
+    let leaders: Vec<PlayerId> = counts
+        .iter()
+        .filter(|&(_, &count)| count == top)
+        .map(|(id, _)| *id)
+        .collect();
+
+    let outcome = match leaders.as_slice() {
+        [] => VoteOutcome::NoElimination,
+        [single] => VoteOutcome::Eliminated(*single),
+        tied => match tie {
+            TieResolution::NoElimination => VoteOutcome::NoElimination,
+            TieResolution::Random => VoteOutcome::Eliminated(*rng.choose(tied).unwrap()),
+            TieResolution::Revote => VoteOutcome::Revote(tied.to_vec()),
+            // `leaders` is ascending (BTreeMap order), so the first tied
+            // entry is the smallest PlayerId.
+            TieResolution::LowestSeat => VoteOutcome::Eliminated(tied[0]),
+        },
+    };
+
+    VoteResult { counts, abstentions, outcome }
+}
+
+/// Knobs for [`run_runoff`] voting.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RunoffSettings {
+    /// Fraction of living voters a candidate must exceed to win outright
+    /// (0.5 is a strict majority).
+    pub threshold: f64,
+    /// Hard cap on voting rounds, so an all-tie table can't loop forever.
+    /// The final round falls back to a plurality tally.
+    pub max_rounds: u32,
+    /// How ties are settled, both at the bottom when choosing whom to drop
+    /// from candidacy and in the final plurality fallback.
+    pub tie: TieResolution,
+}
+
+impl Default for RunoffSettings {
+    fn default() -> Self {
+        Self { threshold: 0.5, max_rounds: 3, tie: TieResolution::default() }
+    }
+}
+
+/// Runs a runoff vote: every living player votes among the remaining
+/// candidates; a candidate exceeding the threshold is eliminated outright,
+/// otherwise the weakest candidate is dropped and the table revotes.
+///
+/// Each round records its own set of [`GameEventKind::VoteCast`] events.
+/// Votes for dead players or dropped candidates count as abstentions. A
+/// bottom tie drops one of the tied under [`TieResolution::Random`] (the
+/// seeded draw), the lowest-seat one under [`TieResolution::LowestSeat`],
+/// and all of them otherwise; when every candidate is tied no drop can
+/// make progress, so the round's plurality tally decides.
+pub async fn run_runoff(
+    state: &mut GameState,
+    players: &HashMap<PlayerId, Box<dyn Player>>,
+    policy: &TurnPolicy,
+    settings: &RunoffSettings,
+) -> VoteOutcome {
+    let mut candidates = state.alive_players();
+    for round in 0..settings.max_rounds.max(1) {
+        let mut votes = Vec::new();
+        let mut cache = crate::game::state::ContextCache::new();
+        for &id in &state.alive_players() {
+            let Some(player) = players.get(&id) else { continue };
+            let ctx = cache.context_for(state, id);
+            let target = timed_vote(player.as_ref(), &ctx, state, policy).await;
+            let target = target.filter(|t| candidates.contains(t));
+            state.record(GameEventKind::VoteCast { voter: id, target, reason: None });
+            votes.push((id, target));
+        }
+
+        let result = tally(&votes, settings.tie, state.rng_mut());
+        let voters = votes.len();
+        if let VoteOutcome::Eliminated(leader) = result.outcome {
+            let count = result.counts.get(&leader).copied().unwrap_or(0);
+            if count as f64 > settings.threshold * voters as f64 {
+                return VoteOutcome::Eliminated(leader);
+            }
+        }
+        if round + 1 == settings.max_rounds.max(1) {
+            // Cap reached: the mandate stays weak, settle by plurality.
+            return result.outcome;
+        }
+
+        // Drop the weakest candidate(s) and revote among the rest.
+        let low = candidates
+            .iter()
+            .map(|id| result.counts.get(id).copied().unwrap_or(0))
+            .min()
+            .unwrap_or(0);
+        let bottom: Vec<PlayerId> = candidates
+            .iter()
+            .copied()
+            .filter(|id| result.counts.get(id).copied().unwrap_or(0) == low)
+            .collect();
+        if bottom.len() == candidates.len() {
+            // Everyone is tied; dropping can't make progress.
+            return result.outcome;
+        }
+        let dropped: Vec<PlayerId> = match settings.tie {
+            TieResolution::Random if bottom.len() > 1 => {
+                vec![*state.rng_mut().choose(&bottom).unwrap()]
+            }
+            TieResolution::LowestSeat if bottom.len() > 1 => {
+                vec![*bottom.iter().min().unwrap()]
+            }
+            _ => bottom,
+        };
+        candidates.retain(|id| !dropped.contains(id));
+    }
+    VoteOutcome::NoElimination
+}
+
+/// Runs an open-ballot vote: living players vote in seat order with every
+/// vote public the moment it is cast, then up to `change_rounds` revision
+/// passes let players react to the board and change their vote.
+///
+/// Each change is recorded as its own [`GameEventKind::VoteCast`] event,
+/// superseding the voter's earlier one; only the last vote per player
+/// reaches the tally. A revision pass in which nobody changes ends the
+/// revisions early, so the bound is a cap, not a fixed count.
+///
+/// Public visibility of unreasoned votes comes from the state's open-voting
+/// flag ([`GameState::set_open_voting`]), which the runner sets alongside
+/// choosing this mode.
+pub async fn run_open_vote(
+    state: &mut GameState,
+    players: &HashMap<PlayerId, Box<dyn Player>>,
+    policy: &TurnPolicy,
+    change_rounds: u32,
+    tie: TieResolution,
+) -> VoteOutcome {
+    let voters = state.alive_players();
+    let mut current: BTreeMap<PlayerId, Option<PlayerId>> = BTreeMap::new();
+    let mut cache = crate::game::state::ContextCache::new();
+    for &id in &voters {
+        let Some(player) = players.get(&id) else { continue };
+        let ctx = cache.context_for(state, id);
+        let (target, reason) =
+            timed_vote_with_reason(player.as_ref(), &ctx, state, policy).await;
+        let target = target.filter(|t| state.is_alive(*t));
+        state.record(GameEventKind::VoteCast { voter: id, target, reason });
+        current.insert(id, target);
+    }
+    for _ in 0..change_rounds {
+        let mut changed = false;
+        for &id in &voters {
+            let Some(player) = players.get(&id) else { continue };
+            let ctx = cache.context_for(state, id);
+            let (target, reason) =
+                timed_vote_with_reason(player.as_ref(), &ctx, state, policy).await;
+            let target = target.filter(|t| state.is_alive(*t));
+            if current.get(&id) == Some(&target) {
+                // Standing by the current vote leaves no event behind.
+                continue;
+            }
+            state.record(GameEventKind::VoteCast { voter: id, target, reason });
+            current.insert(id, target);
+            changed = true;
+        }
+        if !changed {
+            break;
+        }
+    }
+    let votes: Vec<(PlayerId, Option<PlayerId>)> = current.into_iter().collect();
+    tally(&votes, tie, state.rng_mut()).outcome
+}
+
+/// Runs a secret ballot: every living player votes against the same
+/// pre-vote view of the table, and the [`GameEventKind::VoteCast`] events
+/// land in the log together only after the last voter has answered — all
+/// votes reveal simultaneously and nobody reacts to anybody else's.
+pub async fn run_secret_vote(
+    state: &mut GameState,
+    players: &HashMap<PlayerId, Box<dyn Player>>,
+    policy: &TurnPolicy,
+    tie: TieResolution,
+) -> VoteOutcome {
+    let mut pending = Vec::new();
+    let mut cache = crate::game::state::ContextCache::new();
+    for &id in &state.alive_players() {
+        let Some(player) = players.get(&id) else { continue };
+        let ctx = cache.context_for(state, id);
+        let (target, reason) =
+            timed_vote_with_reason(player.as_ref(), &ctx, state, policy).await;
+        let target = target.filter(|t| state.is_alive(*t));
+        pending.push((id, target, reason));
+    }
+    let mut votes = Vec::with_capacity(pending.len());
+    for (voter, target, reason) in pending {
+        state.record(GameEventKind::VoteCast { voter, target, reason });
+        votes.push((voter, target));
+    }
+    tally(&votes, tie, state.rng_mut()).outcome
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rng() -> Rng {
+        Rng::new(0)
+    }
+
+    #[test]
+    fn plurality_winner_is_eliminated() {
+        let votes = [(PlayerId(0), Some(PlayerId(2))), (PlayerId(1), Some(PlayerId(2))), (PlayerId(2), Some(PlayerId(0))), (PlayerId(3), Some(PlayerId(2)))];
+        let result = tally(&votes, TieResolution::NoElimination, &mut rng());
+        assert_eq!(result.outcome, VoteOutcome::Eliminated(PlayerId(2)));
+        assert_eq!(result.counts[&PlayerId(2)], 3);
+        assert_eq!(result.counts[&PlayerId(0)], 1);
+        assert_eq!(result.abstentions, 0);
+    }
+
+    #[test]
+    fn abstentions_are_counted_but_elect_nobody() {
+        let votes = [(PlayerId(0), None), (PlayerId(1), None), (PlayerId(2), Some(PlayerId(0)))];
+        let result = tally(&votes, TieResolution::NoElimination, &mut rng());
+        assert_eq!(result.abstentions, 2);
+        assert_eq!(result.outcome, VoteOutcome::Eliminated(PlayerId(0)));
+    }
+
+    #[test]
+    fn all_abstain_means_no_elimination() {
+        let votes = [(PlayerId(0), None), (PlayerId(1), None)];
+        let result = tally(&votes, TieResolution::Random, &mut rng());
+        assert_eq!(result.outcome, VoteOutcome::NoElimination);
+    }
+
+    #[test]
+    fn tie_with_no_elimination_spares_everyone() {
+        let votes = [(PlayerId(0), Some(PlayerId(1))), (PlayerId(1), Some(PlayerId(0)))];
+        let result = tally(&votes, TieResolution::NoElimination, &mut rng());
+        assert_eq!(result.outcome, VoteOutcome::NoElimination);
+    }
+
+    #[test]
+    fn tie_with_random_is_seed_deterministic() {
+        let votes = [(PlayerId(0), Some(PlayerId(1))), (PlayerId(1), Some(PlayerId(0)))];
+        let a = tally(&votes, TieResolution::Random, &mut Rng::new(7));
+        let b = tally(&votes, TieResolution::Random, &mut Rng::new(7));
+        assert_eq!(a.outcome, b.outcome);
+        assert!(matches!(a.outcome, VoteOutcome::Eliminated(PlayerId(0) | PlayerId(1))));
+    }
+
+    #[test]
+    fn a_three_way_tie_resolves_to_the_lowest_seat() {
+        // Seats 1, 4, and 7 share the top of the board; 1 is eliminated,
+        // and the RNG is untouched so the draw is seed-independent.
+        let votes = [
+            (PlayerId(0), Some(PlayerId(4))),
+            (PlayerId(1), Some(PlayerId(7))),
+            (PlayerId(2), Some(PlayerId(1))),
+            (PlayerId(3), Some(PlayerId(4))),
+            (PlayerId(4), Some(PlayerId(7))),
+            (PlayerId(5), Some(PlayerId(1))),
+        ];
+        let a = tally(&votes, TieResolution::LowestSeat, &mut Rng::new(7));
+        let b = tally(&votes, TieResolution::LowestSeat, &mut Rng::new(999));
+        assert_eq!(a.outcome, VoteOutcome::Eliminated(PlayerId(1)));
+        assert_eq!(b.outcome, VoteOutcome::Eliminated(PlayerId(1)));
+    }
+
+    #[test]
+    fn tie_with_revote_reports_the_tied_players() {
+        let votes = [(PlayerId(0), Some(PlayerId(1))), (PlayerId(1), Some(PlayerId(0))), (PlayerId(2), Some(PlayerId(1))), (PlayerId(3), Some(PlayerId(0)))];
+        let result = tally(&votes, TieResolution::Revote, &mut rng());
+        assert_eq!(result.outcome, VoteOutcome::Revote(vec![PlayerId(0), PlayerId(1)]));
+    }
+
+    use crate::game::state::Phase;
+    use crate::player::ScriptedPlayer;
+    use crate::roles::Role;
+
+    /// A voting-phase table of `scripts.len()` seats, seat 0 the wolf.
+    fn setup(
+        scripts: Vec<ScriptedPlayer>,
+    ) -> (GameState, HashMap<PlayerId, Box<dyn Player>>) {
+        let n = scripts.len() as u32;
+        let mut state = GameState::new(0..n, Phase::Voting, 0);
+        state.assign_role(PlayerId(0), Role::Werewolf);
+        for id in 1..n {
+            state.assign_role(PlayerId(id), Role::Villager);
+        }
+        let players = scripts
+            .into_iter()
+            .enumerate()
+            .map(|(id, p)| (PlayerId(id as u32), Box::new(p) as Box<dyn Player>))
+            .collect();
+        (state, players)
+    }
+
+    mod runoff {
+        use super::*;
+
+        #[tokio::test]
+        async fn outright_majority_ends_in_one_round() {
+            let (mut state, players) = setup(vec![
+                ScriptedPlayer::new().will_vote(PlayerId(2)),
+                ScriptedPlayer::new().will_vote(PlayerId(2)),
+                ScriptedPlayer::new().will_vote(PlayerId(0)),
+                ScriptedPlayer::new().will_vote(PlayerId(2)),
+            ]);
+            let outcome = run_runoff(
+                &mut state,
+                &players,
+                &TurnPolicy::default(),
+                &RunoffSettings::default(),
+            )
+            .await;
+            assert_eq!(outcome, VoteOutcome::Eliminated(PlayerId(2)));
+            let cast = state
+                .log()
+                .iter()
+                .filter(|e| matches!(e.kind, GameEventKind::VoteCast { .. }))
+                .count();
+            assert_eq!(cast, 4);
+        }
+
+        #[tokio::test]
+        async fn weak_mandate_drops_the_bottom_and_revotes() {
+            // Round 1 splits 2/1/1; nobody has a majority of 5 voters. The
+            // zero-vote candidates drop out; round 2 consolidates on 1.
+            let (mut state, players) = setup(vec![
+                ScriptedPlayer::new().will_vote(PlayerId(1)).will_vote(PlayerId(1)),
+                ScriptedPlayer::new().will_vote(PlayerId(2)).will_vote(PlayerId(2)),
+                ScriptedPlayer::new().will_vote(PlayerId(1)).will_vote(PlayerId(1)),
+                ScriptedPlayer::new().will_vote(PlayerId(3)).will_vote(PlayerId(1)),
+                ScriptedPlayer::new().will_vote(PlayerId(2)).will_vote(PlayerId(1)),
+            ]);
+            let outcome = run_runoff(
+                &mut state,
+                &players,
+                &TurnPolicy::default(),
+                &RunoffSettings::default(),
+            )
+            .await;
+            assert_eq!(outcome, VoteOutcome::Eliminated(PlayerId(1)));
+            // Two rounds of five votes each, each its own events.
+            let cast = state
+                .log()
+                .iter()
+                .filter(|e| matches!(e.kind, GameEventKind::VoteCast { .. }))
+                .count();
+            assert_eq!(cast, 10);
+        }
+
+        #[tokio::test]
+        async fn votes_for_dropped_candidates_become_abstentions() {
+            // Player 3 votes for candidate 0 after 0 has been dropped.
+            let (mut state, players) = setup(vec![
+                ScriptedPlayer::new().will_vote(PlayerId(2)).will_vote(PlayerId(1)),
+                ScriptedPlayer::new().will_vote(PlayerId(2)).will_vote(PlayerId(2)),
+                ScriptedPlayer::new().will_vote(PlayerId(1)).will_vote(PlayerId(1)),
+                ScriptedPlayer::new().will_vote(PlayerId(1)).will_vote(PlayerId(0)),
+                ScriptedPlayer::new().will_vote(PlayerId(3)).will_vote(PlayerId(1)),
+            ]);
+            let outcome = run_runoff(
+                &mut state,
+                &players,
+                &TurnPolicy::default(),
+                &RunoffSettings::default(),
+            )
+            .await;
+            assert_eq!(outcome, VoteOutcome::Eliminated(PlayerId(1)));
+            assert!(state.log().iter().any(|e| matches!(
+                e.kind,
+                GameEventKind::VoteCast { voter: PlayerId(3), target: None, .. }
+            )));
+        }
+
+        #[tokio::test]
+        async fn lowest_seat_settles_a_runoff_deadlock_without_rng() {
+            // Seats 0 and 1 split the table 2-2; with the round cap at 1
+            // the plurality fallback fires and LowestSeat eliminates 0.
+            let (mut state, players) = setup(vec![
+                ScriptedPlayer::new().will_vote(PlayerId(1)),
+                ScriptedPlayer::new().will_vote(PlayerId(0)),
+                ScriptedPlayer::new().will_vote(PlayerId(1)),
+                ScriptedPlayer::new().will_vote(PlayerId(0)),
+            ]);
+            let outcome = run_runoff(
+                &mut state,
+                &players,
+                &TurnPolicy::default(),
+                &RunoffSettings {
+                    max_rounds: 1,
+                    tie: TieResolution::LowestSeat,
+                    ..Default::default()
+                },
+            )
+            .await;
+            assert_eq!(outcome, VoteOutcome::Eliminated(PlayerId(0)));
+        }
+
+        #[tokio::test]
+        async fn all_tie_rounds_are_bounded() {
+            // Two players voting for each other forever: every round is a
+            // full tie, so the runoff must terminate without eliminating.
+            let (mut state, players) = setup(vec![
+                ScriptedPlayer::new().will_vote(PlayerId(1)).will_vote(PlayerId(1)).will_vote(PlayerId(1)),
+                ScriptedPlayer::new().will_vote(PlayerId(0)).will_vote(PlayerId(0)).will_vote(PlayerId(0)),
+            ]);
+            let outcome = run_runoff(
+                &mut state,
+                &players,
+                &TurnPolicy::default(),
+                &RunoffSettings { max_rounds: 3, ..Default::default() },
+            )
+            .await;
+            assert_eq!(outcome, VoteOutcome::NoElimination);
+            let cast = state
+                .log()
+                .iter()
+                .filter(|e| matches!(e.kind, GameEventKind::VoteCast { .. }))
+                .count();
+            // One round was enough to see no progress is possible.
+            assert_eq!(cast, 2);
+        }
+    }
+
+    mod open {
+        use super::*;
+
+        #[tokio::test]
+        async fn a_changed_vote_flips_the_elimination() {
+            // First pass: 4 leads 3-2. The revision pass turns seats 0
+            // and 1 around, and the final board reads 4-1 against 3.
+            let (mut state, players) = setup(vec![
+                ScriptedPlayer::new().will_vote(PlayerId(4)).will_vote(PlayerId(3)),
+                ScriptedPlayer::new().will_vote(PlayerId(4)).will_vote(PlayerId(3)),
+                ScriptedPlayer::new().will_vote(PlayerId(3)).will_vote(PlayerId(3)),
+                ScriptedPlayer::new().will_vote(PlayerId(4)).will_vote(PlayerId(4)),
+                ScriptedPlayer::new().will_vote(PlayerId(3)).will_vote(PlayerId(3)),
+            ]);
+            let outcome = run_open_vote(
+                &mut state,
+                &players,
+                &TurnPolicy::default(),
+                1,
+                TieResolution::default(),
+            )
+            .await;
+            assert_eq!(outcome, VoteOutcome::Eliminated(PlayerId(3)));
+            // Five initial votes plus the two changes; standing by a vote
+            // records nothing.
+            let cast = state
+                .log()
+                .iter()
+                .filter(|e| matches!(e.kind, GameEventKind::VoteCast { .. }))
+                .count();
+            assert_eq!(cast, 7);
+        }
+
+        #[tokio::test]
+        async fn without_change_rounds_the_first_pass_decides() {
+            let (mut state, players) = setup(vec![
+                ScriptedPlayer::new().will_vote(PlayerId(4)),
+                ScriptedPlayer::new().will_vote(PlayerId(4)),
+                ScriptedPlayer::new().will_vote(PlayerId(3)),
+                ScriptedPlayer::new().will_vote(PlayerId(4)),
+                ScriptedPlayer::new().will_vote(PlayerId(3)),
+            ]);
+            let outcome = run_open_vote(
+                &mut state,
+                &players,
+                &TurnPolicy::default(),
+                0,
+                TieResolution::default(),
+            )
+            .await;
+            assert_eq!(outcome, VoteOutcome::Eliminated(PlayerId(4)));
+        }
+
+        #[tokio::test]
+        async fn a_pass_without_changes_ends_the_revisions_early() {
+            // Everyone stands by their vote, so of the five allowed
+            // revision passes only the first runs — and records nothing.
+            let (mut state, players) = setup(vec![
+                ScriptedPlayer::new().will_vote(PlayerId(2)).will_vote(PlayerId(2)),
+                ScriptedPlayer::new().will_vote(PlayerId(2)).will_vote(PlayerId(2)),
+                ScriptedPlayer::new().will_vote(PlayerId(0)).will_vote(PlayerId(0)),
+            ]);
+            let outcome = run_open_vote(
+                &mut state,
+                &players,
+                &TurnPolicy::default(),
+                5,
+                TieResolution::default(),
+            )
+            .await;
+            assert_eq!(outcome, VoteOutcome::Eliminated(PlayerId(2)));
+            let cast = state
+                .log()
+                .iter()
+                .filter(|e| matches!(e.kind, GameEventKind::VoteCast { .. }))
+                .count();
+            assert_eq!(cast, 3);
+        }
+
+        #[tokio::test]
+        async fn open_votes_are_public_without_a_stated_reason() {
+            let (mut state, players) = setup(vec![
+                ScriptedPlayer::new().will_vote(PlayerId(2)).will_vote(PlayerId(2)),
+                ScriptedPlayer::new().will_vote(PlayerId(2)).will_vote(PlayerId(2)),
+                ScriptedPlayer::new().will_vote(PlayerId(0)).will_vote(PlayerId(0)),
+            ]);
+            state.set_open_voting(true);
+            run_open_vote(
+                &mut state,
+                &players,
+                &TurnPolicy::default(),
+                1,
+                TieResolution::default(),
+            )
+            .await;
+            let ctx = state.context_for(PlayerId(1));
+            assert!(ctx.public_log.contains(&"Player 0 votes for Player 2.".to_string()));
+        }
+
+    }
+
+    mod secret {
+        use super::*;
+        use crate::game::action::Action;
+        use crate::player::GameContext;
+
+        /// Votes for seat 1 the moment any vote is visible in the public
+        /// transcript, for seat 2 otherwise — a pile-on strategy that only
+        /// works when the ballot is open.
+        struct Bandwagoner;
+
+        #[async_trait::async_trait]
+        impl Player for Bandwagoner {
+            async fn vote(&self, ctx: &GameContext) -> PlayerId {
+                if ctx.public_log.iter().any(|line| line.contains("votes for")) {
+                    PlayerId(1)
+                } else {
+                    PlayerId(2)
+                }
+            }
+
+            async fn night_action(&self, _ctx: &GameContext) -> Option<Action> {
+                None
+            }
+
+            async fn speak(&self, _ctx: &GameContext) -> String {
+                String::new()
+            }
+        }
+
+        fn bandwagon_table(n: u32) -> HashMap<PlayerId, Box<dyn Player>> {
+            (0..n).map(|id| (PlayerId(id), Box::new(Bandwagoner) as Box<dyn Player>)).collect()
+        }
+
+        #[tokio::test]
+        async fn secret_votes_reveal_simultaneously() {
+            // Nobody sees anybody's vote, so every Bandwagoner falls back
+            // to seat 2 — under an open ballot the same table piles on 1.
+            let mut state = GameState::new(0..4, Phase::Voting, 0);
+            state.assign_role(PlayerId(0), Role::Werewolf);
+            for id in 1..4 {
+                state.assign_role(PlayerId(id), Role::Villager);
+            }
+            let players = bandwagon_table(4);
+            let outcome = run_secret_vote(
+                &mut state,
+                &players,
+                &TurnPolicy::default(),
+                TieResolution::default(),
+            )
+            .await;
+            assert_eq!(outcome, VoteOutcome::Eliminated(PlayerId(2)));
+            let cast = state
+                .log()
+                .iter()
+                .filter(|e| matches!(e.kind, GameEventKind::VoteCast { .. }))
+                .count();
+            assert_eq!(cast, 4);
+        }
+
+        #[tokio::test]
+        async fn the_same_table_piles_on_under_an_open_ballot() {
+            let mut state = GameState::new(0..4, Phase::Voting, 0);
+            state.assign_role(PlayerId(0), Role::Werewolf);
+            for id in 1..4 {
+                state.assign_role(PlayerId(id), Role::Villager);
+            }
+            state.set_open_voting(true);
+            let players = bandwagon_table(4);
+            let outcome = run_open_vote(
+                &mut state,
+                &players,
+                &TurnPolicy::default(),
+                0,
+                TieResolution::default(),
+            )
+            .await;
+            // Seat 0 opens on 2; everyone after sees it and dogpiles 1.
+            assert_eq!(outcome, VoteOutcome::Eliminated(PlayerId(1)));
+        }
+    }
+}
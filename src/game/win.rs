@@ -0,0 +1,181 @@
+//! Win-condition checking, decoupled from the phase machine.
+//!
+//! [`check_win`] must be called after every individual death, not just at
+//! phase boundaries: a Hunter's dying shot that removes the last wolf ends
+//! the game immediately, mid-resolution.
+
+use serde::{Deserialize, Serialize};
+
+use crate::game::state::GameState;
+use crate::roles::Alignment;
+
+/// Knobs for rule variants that change when a side has won.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WinRules {
+    /// When true (the common rule), wolves win as soon as they *equal* the
+    /// living town; when false they must strictly outnumber it.
+    pub wolves_win_on_parity: bool,
+    /// When true, town must eliminate the whole wolf team including the
+    /// Minion; when false (the common rule), killing every *killing* wolf
+    /// is enough and a surviving Minion merely loses with the pack.
+    pub minion_blocks_town_win: bool,
+}
+
+impl Default for WinRules {
+    fn default() -> Self {
+        Self { wolves_win_on_parity: true, minion_blocks_town_win: false }
+    }
+}
+
+/// Checks whether either side has won under the state's [`WinRules`].
+///
+/// Returns `Some(Wolf)` when the wolf team reaches parity with (or strictly
+/// outnumbers, per [`WinRules`]) the rest of the table, `Some(Town)` when no
+/// killing wolves remain, and `None` while the game is still live.
+///
+/// Town's win is checked first: if a night wipes out the last wolf and the
+/// last villager simultaneously, eliminating all wolves means town has met
+/// its goal, so the result is `Some(Town)`.
+pub fn check_win(state: &GameState) -> Option<Alignment> {
+    check_win_with(state, state.win_rules())
+}
+
+/// [`check_win`] with explicit rule variants.
+///
+/// Wolf-aligned roles split into *killing wolves* (those with a night
+/// action, e.g. Werewolf and Alpha Werewolf) and harmless ones (the
+/// Minion). Town's goal is the killing wolves; a surviving Minion only
+/// stands in the way under `minion_blocks_town_win`. For parity the whole
+/// wolf team counts — a Minion at the table is still a vote the wolves
+/// control — but wolves cannot win with no killing wolf left alive.
+pub fn check_win_with(state: &GameState, rules: WinRules) -> Option<Alignment> {
+    let mut killing_wolves = 0usize;
+    let mut minions = 0usize;
+    let mut others = 0usize;
+    for p in state.players().iter().filter(|p| p.alive) {
+        match state.role_of(p.id) {
+            Some(role) if role.alignment() == Alignment::Wolf => {
+                if role.info().acts_at_night {
+                    killing_wolves += 1;
+                } else {
+                    minions += 1;
+                }
+            }
+            _ => others += 1,
+        }
+    }
+
+    if killing_wolves == 0 && (!rules.minion_blocks_town_win || minions == 0) {
+        return Some(Alignment::Town);
+    }
+    let wolves = killing_wolves + minions;
+    let wolves_win = if rules.wolves_win_on_parity {
+        wolves >= others
+    } else {
+        wolves > others
+    };
+    if killing_wolves > 0 && wolves_win {
+        return Some(Alignment::Wolf);
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::state::{Phase, PlayerId};
+    use crate::roles::Role;
+
+    /// n wolves then m villagers, ids 0..n+m.
+    fn state(wolves: u32, villagers: u32) -> GameState {
+        let mut s = GameState::new(0..wolves + villagers, Phase::Night, 0);
+        for id in 0..wolves {
+            s.assign_role(PlayerId(id), Role::Werewolf);
+        }
+        for id in wolves..wolves + villagers {
+            s.assign_role(PlayerId(id), Role::Villager);
+        }
+        s
+    }
+
+    #[test]
+    fn live_game_has_no_winner() {
+        assert_eq!(check_win(&state(2, 5)), None);
+    }
+
+    #[test]
+    fn town_wins_when_no_wolves_remain() {
+        let mut s = state(1, 4);
+        s.kill(PlayerId(0));
+        assert_eq!(check_win(&s), Some(Alignment::Town));
+    }
+
+    #[test]
+    fn wolves_win_on_parity_by_default() {
+        let mut s = state(2, 3);
+        s.kill(PlayerId(3));
+        assert_eq!(check_win(&s), Some(Alignment::Wolf));
+    }
+
+    #[test]
+    fn strict_majority_variant_needs_one_more() {
+        let mut s = state(2, 3);
+        s.kill(PlayerId(3));
+        let strict = WinRules { wolves_win_on_parity: false, ..WinRules::default() };
+        assert_eq!(check_win_with(&s, strict), None);
+        s.kill(PlayerId(4));
+        assert_eq!(check_win_with(&s, strict), Some(Alignment::Wolf));
+    }
+
+    #[test]
+    fn simultaneous_last_wolf_and_last_villager_death_is_a_town_win() {
+        let mut s = state(1, 1);
+        s.kill(PlayerId(0));
+        s.kill(PlayerId(1));
+        assert_eq!(check_win(&s), Some(Alignment::Town));
+    }
+
+    /// A wolf at 0, a Minion at 1, villagers after.
+    fn state_with_minion(villagers: u32) -> GameState {
+        let mut s = GameState::new(0..2 + villagers, Phase::Night, 0);
+        s.assign_role(PlayerId(0), Role::Werewolf);
+        s.assign_role(PlayerId(1), Role::Minion);
+        for id in 2..2 + villagers {
+            s.assign_role(PlayerId(id), Role::Villager);
+        }
+        s
+    }
+
+    #[test]
+    fn a_surviving_minion_does_not_stop_the_town_win() {
+        let mut s = state_with_minion(4);
+        s.kill(PlayerId(0));
+        assert_eq!(check_win(&s), Some(Alignment::Town));
+    }
+
+    #[test]
+    fn minion_blocks_town_win_variant_keeps_the_game_alive() {
+        let mut s = state_with_minion(4);
+        s.kill(PlayerId(0));
+        let rules = WinRules { minion_blocks_town_win: true, ..WinRules::default() };
+        assert_eq!(check_win_with(&s, rules), None);
+        s.kill(PlayerId(1));
+        assert_eq!(check_win_with(&s, rules), Some(Alignment::Town));
+    }
+
+    #[test]
+    fn a_minion_counts_toward_wolf_parity() {
+        // Wolf + Minion vs two villagers: parity, wolves win.
+        let mut s = state_with_minion(3);
+        s.kill(PlayerId(2));
+        assert_eq!(check_win(&s), Some(Alignment::Wolf));
+    }
+
+    #[test]
+    fn a_lone_minion_never_wins_for_the_wolves() {
+        let mut s = state_with_minion(1);
+        s.kill(PlayerId(0));
+        s.kill(PlayerId(2));
+        assert_eq!(check_win(&s), Some(Alignment::Town));
+    }
+}
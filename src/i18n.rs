@@ -0,0 +1,229 @@
+//! Locale-aware string tables: role names, narration templates, and
+//! prompt templates.
+//!
+//! The engine itself is language-free — it logs structured events. This
+//! module supplies the words: [`Locale::role_name`] for role display
+//! names, [`Locale::narration_templates`] for the [`Narrator`], and
+//! [`Locale::prompt_set`] so an LLM player is addressed in the target
+//! language. English is the default everywhere.
+//!
+//! [`Narrator`]: crate::narrate::Narrator
+
+use serde::{Deserialize, Serialize};
+
+use crate::game::night::DeathCause;
+use crate::llm::prompt::{PromptSet, PromptTemplate};
+use crate::narrate::NarrationTemplates;
+use crate::roles::{Alignment, Role};
+
+/// A supported output language.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Locale {
+    /// English (the default).
+    #[default]
+    En,
+    /// Traditional Chinese (Taiwan).
+    ZhTw,
+}
+
+impl Locale {
+    /// The localized display name for a built-in role. For [`Locale::En`]
+    /// this matches `RoleInfo::display_name`.
+    pub fn role_name(&self, role: Role) -> &'static str {
+        match self {
+            Self::En => role.info().display_name,
+            Self::ZhTw => match role {
+                Role::Villager => "村民",
+                Role::Werewolf => "狼人",
+                Role::Seer => "預言家",
+                Role::Witch => "女巫",
+                Role::Hunter => "獵人",
+                Role::Guard => "守衛",
+                Role::AlphaWerewolf => "狼王",
+                Role::Minion => "爪牙",
+            },
+        }
+    }
+
+    /// The localized display name for an alignment, as used in
+    /// alignment-only death lines.
+    pub fn alignment_name(&self, alignment: Alignment) -> &'static str {
+        match self {
+            Self::En => match alignment {
+                Alignment::Town => "Town",
+                Alignment::Wolf => "Wolf",
+                Alignment::Neutral => "Neutral",
+            },
+            Self::ZhTw => match alignment {
+                Alignment::Town => "好人陣營",
+                Alignment::Wolf => "狼人陣營",
+                Alignment::Neutral => "中立陣營",
+            },
+        }
+    }
+
+    /// The localized numeric label for a seat — "Player 3" / "玩家 3" —
+    /// used wherever no [`PlayerRoster`] name is registered.
+    ///
+    /// [`PlayerRoster`]: crate::game::state::PlayerRoster
+    pub fn player_label(&self, id: crate::game::state::PlayerId) -> String {
+        match self {
+            Self::En => format!("Player {id}"),
+            Self::ZhTw => format!("玩家 {id}"),
+        }
+    }
+
+    /// The localized phrase for a cause of death, as used in death lines.
+    pub fn cause_phrase(&self, cause: DeathCause) -> &'static str {
+        match self {
+            Self::En => match cause {
+                DeathCause::WolfKill => "torn apart by the wolves",
+                DeathCause::Poison => "poisoned in the night",
+                DeathCause::Vote => "voted out by the village",
+                DeathCause::HunterShot => "shot by the Hunter",
+                DeathCause::LoverGrief => "dead of grief beside their lover",
+            },
+            Self::ZhTw => match cause {
+                DeathCause::WolfKill => "被狼人撕碎",
+                DeathCause::Poison => "在夜裡被毒死",
+                DeathCause::Vote => "被村民投票放逐",
+                DeathCause::HunterShot => "被獵人開槍帶走",
+                DeathCause::LoverGrief => "為愛殉情",
+            },
+        }
+    }
+
+    /// The narration line templates for this locale. Hand the result to
+    /// [`Narrator::with_templates`], or use [`Narrator::locale`] which
+    /// does both template and role-name lookup.
+    ///
+    /// [`Narrator::with_templates`]: crate::narrate::Narrator::with_templates
+    /// [`Narrator::locale`]: crate::narrate::Narrator::locale
+    pub fn narration_templates(&self) -> NarrationTemplates {
+        match self {
+            Self::En => NarrationTemplates::default(),
+            Self::ZhTw => NarrationTemplates {
+                night_falls: PromptTemplate::new("\u{1f319} 第 {day} 夜，天黑請閉眼。"),
+                day_breaks: PromptTemplate::new("\u{2600}\u{fe0f} 第 {day} 天，天亮了。"),
+                voting_begins: PromptTemplate::new("\u{1f5f3}\u{fe0f} 村民開始投票。"),
+                game_over: PromptTemplate::new("遊戲結束。"),
+                spoke: PromptTemplate::new("{player} 說：{text}"),
+                vote_cast: PromptTemplate::new("{voter} 投給{target}。"),
+                vote_cast_reasoned: PromptTemplate::new(
+                    "{voter} 投給{target}：{text}",
+                ),
+                abstained: PromptTemplate::new("{voter} 棄票。"),
+                player_died: PromptTemplate::new("{player} 死了 —— {cause}。"),
+                player_died_revealed: PromptTemplate::new(
+                    "{player} 死了 —— {cause}。他的身分是{role}。",
+                ),
+                player_died_alignment: PromptTemplate::new(
+                    "{player} 死了 —— {cause}。他屬於{alignment}。",
+                ),
+                night_action: PromptTemplate::new("（夜晚）{actor}：{action}"),
+                game_ended: PromptTemplate::new("\u{1f3c1} {winner} 陣營獲勝。"),
+                game_drawn: PromptTemplate::new("\u{1f3c1} 遊戲以和局收場。"),
+                fallback: PromptTemplate::new("{player} 未能行動（{action}）。"),
+                player_refused: PromptTemplate::new("{player} 拒絕回答。"),
+                hunter_shot: PromptTemplate::new(
+                    "\u{1f3f9} {hunter} 在臨死前開槍帶走了{target}。",
+                ),
+                last_words: PromptTemplate::new("{player} 的遺言：{text}"),
+                invalid_action: PromptTemplate::new(
+                    "（夜晚）{player} 的 {action} 被判定無效。",
+                ),
+                accusation: PromptTemplate::new(
+                    "\u{2696}\u{fe0f} {accuser} 指控{accused}：{text}",
+                ),
+                defense: PromptTemplate::new("{player} 辯護：{text}"),
+                speaking_order: PromptTemplate::new("今天的發言順序：{order}。"),
+                budget_exceeded: PromptTemplate::new(
+                    "\u{1f4b8} 預算已用完（${cost}／${max_cost}）—— 直接進入投票。",
+                ),
+                discussion_ended: PromptTemplate::new("討論告一段落（{reason}）。"),
+                graveyard_chat: PromptTemplate::new("\u{1f47b}（墓地）{player}：{text}"),
+                wolf_no_kill: PromptTemplate::new("（夜晚）狼群意見分歧，今晚無人遇害。"),
+                wolf_forced_kill: PromptTemplate::new(
+                    "（夜晚）狼群意見分歧，命運選中了{target}。",
+                ),
+                player_model: PromptTemplate::new("{player} 由 {model} 扮演。"),
+            },
+        }
+    }
+
+    /// The prompt templates an LLM player should use for this locale, so
+    /// the model is addressed — and answers — in the target language.
+    pub fn prompt_set(&self) -> PromptSet {
+        match self {
+            Self::En => PromptSet::default(),
+            Self::ZhTw => PromptSet {
+                system: PromptTemplate::new(
+                    "你正在玩狼人殺，你是玩家 {player}。你的祕密身分是{role}。\
+                     為你的陣營爭取勝利。除非在策略上有利，否則不要洩露隱藏資訊。\
+                     請一律使用繁體中文回答。",
+                ),
+                discussion: PromptTemplate::new(
+                    "現在是第 {day} 天。存活玩家：{alive_players}。最近的討論：\n\
+                     {recent_discussion}\n你是{role}，請向全場發言。",
+                ),
+                voting: PromptTemplate::new(
+                    "現在是第 {day} 天，投票開始。存活玩家：{alive_players}。\
+                     最近的討論：\n{recent_discussion}\n請說出你要投票放逐的那一位玩家。",
+                ),
+                night_action: PromptTemplate::new(
+                    "現在是第 {day} 夜。存活玩家：{alive_players}。你是{role}，\
+                     請決定你今晚的行動。",
+                ),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn english_role_names_match_role_info() {
+        for role in [
+            Role::Villager,
+            Role::Werewolf,
+            Role::Seer,
+            Role::Witch,
+            Role::Hunter,
+            Role::Guard,
+            Role::AlphaWerewolf,
+            Role::Minion,
+        ] {
+            assert_eq!(Locale::En.role_name(role), role.info().display_name);
+        }
+    }
+
+    #[test]
+    fn zh_tw_names_every_role() {
+        for role in [
+            Role::Villager,
+            Role::Werewolf,
+            Role::Seer,
+            Role::Witch,
+            Role::Hunter,
+            Role::Guard,
+            Role::AlphaWerewolf,
+            Role::Minion,
+        ] {
+            assert!(!Locale::ZhTw.role_name(role).is_ascii());
+        }
+    }
+
+    #[test]
+    fn zh_tw_prompts_address_the_model_in_chinese() {
+        let prompts = Locale::ZhTw.prompt_set();
+        let vars = std::collections::HashMap::from([
+            ("player", "3".to_string()),
+            ("role", Locale::ZhTw.role_name(Role::Seer).to_string()),
+        ]);
+        let system = prompts.system.render(&vars).unwrap();
+        assert!(system.contains("玩家 3"));
+        assert!(system.contains("預言家"));
+    }
+}
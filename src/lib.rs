@@ -1,3 +1,19 @@
+pub mod clock;
+pub mod config;
+pub mod error;
+pub mod game;
+pub mod i18n;
+pub mod llm;
+pub mod metrics;
+pub mod narrate;
+pub mod player;
+pub mod roles;
+#[cfg(feature = "server")]
+pub mod server;
+pub mod tournament;
+
+pub use error::{Error, Result};
+
 /// Returns the build version information including git metadata
 pub fn version() -> &'static str {
     env!("BUILD_VERSION")
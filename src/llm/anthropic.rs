@@ -0,0 +1,238 @@
+//! Anthropic (Claude) backend speaking the Messages API.
+//!
+//! The wire format differs from OpenAI's in three ways this module has to
+//! bridge: authentication uses `x-api-key` plus an `anthropic-version`
+//! header, the system prompt is a top-level field rather than a message
+//! role, and `max_tokens` is mandatory. Everything maps back into the
+//! shared [`ChatResponse`]/[`LlmError`] types so game code stays
+//! provider-agnostic.
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use crate::llm::http::{HttpClient, HttpRequest, ReqwestClient};
+use crate::llm::{
+    ChatMessage, ChatRequest, ChatResponse, LlmError, LlmProvider, MessageRole, TokenUsage,
+};
+
+/// The Messages API revision this provider speaks.
+const ANTHROPIC_VERSION: &str = "2023-06-01";
+
+/// A provider speaking the Anthropic Messages API.
+#[derive(Debug, Clone)]
+pub struct AnthropicProvider {
+    client: std::sync::Arc<dyn HttpClient>,
+    api_key: String,
+    base_url: String,
+    model: String,
+    /// Sent when the request doesn't specify one; the API requires it.
+    max_tokens: u32,
+}
+
+#[derive(Serialize)]
+struct AnthropicRequest<'a> {
+    model: &'a str,
+    max_tokens: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    system: Option<String>,
+    messages: Vec<AnthropicMessage<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    temperature: Option<f32>,
+}
+
+#[derive(Serialize)]
+struct AnthropicMessage<'a> {
+    role: &'static str,
+    content: &'a str,
+}
+
+#[derive(Deserialize)]
+struct AnthropicResponse {
+    content: Vec<AnthropicContent>,
+    #[serde(default)]
+    usage: Option<AnthropicUsage>,
+}
+
+#[derive(Deserialize)]
+struct AnthropicContent {
+    #[serde(default)]
+    text: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct AnthropicUsage {
+    input_tokens: u32,
+    output_tokens: u32,
+}
+
+impl AnthropicProvider {
+    pub fn new(api_key: impl Into<String>, model: impl Into<String>) -> Self {
+        Self {
+            client: std::sync::Arc::new(ReqwestClient::new()),
+            api_key: api_key.into(),
+            base_url: "https://api.anthropic.com".to_string(),
+            model: model.into(),
+            max_tokens: 1024,
+        }
+    }
+
+    /// Points the provider at a different endpoint (proxies, test servers).
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Replaces the transport — a [`MockHttpClient`] in tests, or any
+    /// custom [`HttpClient`] (proxying, capture, ...).
+    ///
+    /// [`MockHttpClient`]: crate::llm::http::MockHttpClient
+    pub fn with_http_client(mut self, client: std::sync::Arc<dyn HttpClient>) -> Self {
+        self.client = client;
+        self
+    }
+
+    /// Sets the `max_tokens` used when a request doesn't carry its own.
+    pub fn with_max_tokens(mut self, max_tokens: u32) -> Self {
+        self.max_tokens = max_tokens;
+        self
+    }
+
+    /// The model id requests are sent with.
+    pub fn model(&self) -> &str {
+        &self.model
+    }
+
+    fn endpoint(&self) -> String {
+        format!("{}/v1/messages", self.base_url)
+    }
+
+    /// Splits the shared message list into Anthropic's shape: system
+    /// messages become the top-level `system` field (concatenated, in
+    /// order), the rest keep their roles.
+    fn split_system(messages: &[ChatMessage]) -> (Option<String>, Vec<AnthropicMessage<'_>>) {
+        let system: Vec<&str> = messages
+            .iter()
+            .filter(|m| m.role == MessageRole::System)
+            .map(|m| m.content.as_str())
+            .collect();
+        let system = if system.is_empty() { None } else { Some(system.join("\n\n")) };
+        let rest = messages
+            .iter()
+            .filter_map(|m| match m.role {
+                MessageRole::System => None,
+                MessageRole::User => Some(AnthropicMessage { role: "user", content: &m.content }),
+                MessageRole::Assistant => {
+                    Some(AnthropicMessage { role: "assistant", content: &m.content })
+                }
+            })
+            .collect();
+        (system, rest)
+    }
+
+    /// Parses a Messages API body into a [`ChatResponse`].
+    fn parse_response(body: &str) -> Result<ChatResponse, LlmError> {
+        let parsed: AnthropicResponse = serde_json::from_str(body)
+            .map_err(|e| LlmError::MalformedResponse(e.to_string()))?;
+        let content: String = parsed
+            .content
+            .iter()
+            .filter_map(|c| c.text.as_deref())
+            .collect::<Vec<_>>()
+            .join("");
+        if parsed.content.is_empty() {
+            return Err(LlmError::MalformedResponse("response contained no content".into()));
+        }
+        let usage = parsed
+            .usage
+            .map(|u| TokenUsage {
+                prompt_tokens: u.input_tokens,
+                completion_tokens: u.output_tokens,
+                total_tokens: u.input_tokens + u.output_tokens,
+            })
+            .unwrap_or_default();
+        Ok(ChatResponse { content, usage })
+    }
+}
+
+#[async_trait]
+impl LlmProvider for AnthropicProvider {
+    async fn complete(&self, req: ChatRequest) -> Result<ChatResponse, LlmError> {
+        let (system, messages) = Self::split_system(&req.messages);
+        let payload = AnthropicRequest {
+            model: &self.model,
+            max_tokens: req.max_tokens.unwrap_or(self.max_tokens),
+            system,
+            messages,
+            temperature: req.temperature,
+        };
+        let request = HttpRequest {
+            url: self.endpoint(),
+            headers: vec![
+                ("x-api-key".into(), self.api_key.clone()),
+                ("anthropic-version".into(), ANTHROPIC_VERSION.into()),
+            ],
+            body: serde_json::to_string(&payload).expect("request serialization cannot fail"),
+        };
+        let response = self.client.send(request).await?;
+        if !(200..300).contains(&response.status) {
+            return Err(LlmError::Status { status: response.status, body: response.body });
+        }
+        Self::parse_response(&response.body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_a_messages_api_body() {
+        let body = r#"{
+            "content": [{"type": "text", "text": "I vote for player 3."}],
+            "usage": {"input_tokens": 210, "output_tokens": 8}
+        }"#;
+        let resp = AnthropicProvider::parse_response(body).unwrap();
+        assert_eq!(resp.content, "I vote for player 3.");
+        assert_eq!(resp.usage.prompt_tokens, 210);
+        assert_eq!(resp.usage.completion_tokens, 8);
+        assert_eq!(resp.usage.total_tokens, 218);
+    }
+
+    #[test]
+    fn multiple_content_blocks_are_concatenated() {
+        let body = r#"{"content": [{"type": "text", "text": "a"}, {"type": "text", "text": "b"}]}"#;
+        assert_eq!(AnthropicProvider::parse_response(body).unwrap().content, "ab");
+    }
+
+    #[test]
+    fn empty_content_is_malformed() {
+        let err = AnthropicProvider::parse_response(r#"{"content": []}"#).unwrap_err();
+        assert!(matches!(err, LlmError::MalformedResponse(_)));
+    }
+
+    #[test]
+    fn system_messages_become_the_top_level_field() {
+        let messages = vec![
+            ChatMessage::system("You are the Seer."),
+            ChatMessage::user("Who do you investigate?"),
+            ChatMessage::assistant("Player 2."),
+        ];
+        let (system, rest) = AnthropicProvider::split_system(&messages);
+        assert_eq!(system.as_deref(), Some("You are the Seer."));
+        assert_eq!(rest.len(), 2);
+        assert_eq!(rest[0].role, "user");
+        assert_eq!(rest[1].role, "assistant");
+    }
+
+    #[test]
+    fn no_system_message_means_no_system_field() {
+        let (system, _) = AnthropicProvider::split_system(&[ChatMessage::user("hi")]);
+        assert_eq!(system, None);
+    }
+
+    #[test]
+    fn endpoint_uses_the_messages_route() {
+        let p = AnthropicProvider::new("k", "claude-sonnet-4-5").with_base_url("http://proxy/");
+        assert_eq!(p.endpoint(), "http://proxy/v1/messages");
+    }
+}
@@ -0,0 +1,111 @@
+//! Dollar-cost accounting for LLM-backed games.
+//!
+//! Providers report [`TokenUsage`] per call; [`CostTracker`] accumulates
+//! it per player and overall, and converts tokens to an estimated dollar
+//! figure through a per-model price table. The tracker lives on the
+//! [`GameState`](crate::game::state::GameState) so the engine can enforce
+//! a hard [`max_cost`](crate::config::GameConfig::max_cost) ceiling.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::game::state::PlayerId;
+use crate::llm::TokenUsage;
+
+/// Dollar prices per million tokens for one model.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ModelPrice {
+    pub prompt_per_million: f64,
+    pub completion_per_million: f64,
+}
+
+impl ModelPrice {
+    fn cost_of(&self, usage: TokenUsage) -> f64 {
+        f64::from(usage.prompt_tokens) * self.prompt_per_million / 1e6
+            + f64::from(usage.completion_tokens) * self.completion_per_million / 1e6
+    }
+}
+
+/// Accumulates reported token usage and estimates the dollar cost.
+///
+/// Models missing from the price table contribute their tokens to the
+/// totals but cost nothing — better to under-report than to invent a
+/// price.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CostTracker {
+    prices: BTreeMap<String, ModelPrice>,
+    per_player: BTreeMap<PlayerId, f64>,
+    total_cost: f64,
+    total_tokens: u64,
+}
+
+impl CostTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers (or replaces) the price for a model.
+    pub fn with_price(mut self, model: impl Into<String>, price: ModelPrice) -> Self {
+        self.prices.insert(model.into(), price);
+        self
+    }
+
+    /// Records one model call's reported usage against a player.
+    pub fn record(&mut self, player: PlayerId, model: &str, usage: TokenUsage) {
+        let cost = self.prices.get(model).map(|p| p.cost_of(usage)).unwrap_or(0.0);
+        *self.per_player.entry(player).or_default() += cost;
+        self.total_cost += cost;
+        self.total_tokens += u64::from(usage.total_tokens);
+    }
+
+    /// The estimated dollar cost of the whole game so far.
+    pub fn total_cost(&self) -> f64 {
+        self.total_cost
+    }
+
+    /// The estimated dollar cost one player has incurred so far.
+    pub fn cost_of(&self, player: PlayerId) -> f64 {
+        self.per_player.get(&player).copied().unwrap_or(0.0)
+    }
+
+    /// Total tokens reported across all calls, priced or not.
+    pub fn total_tokens(&self) -> u64 {
+        self.total_tokens
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn usage(prompt: u32, completion: u32) -> TokenUsage {
+        TokenUsage {
+            prompt_tokens: prompt,
+            completion_tokens: completion,
+            total_tokens: prompt + completion,
+        }
+    }
+
+    #[test]
+    fn accumulates_per_player_and_overall() {
+        let mut tracker = CostTracker::new().with_price("gpt-test", ModelPrice {
+            prompt_per_million: 1.0,
+            completion_per_million: 2.0,
+        });
+        tracker.record(PlayerId(0), "gpt-test", usage(1_000_000, 500_000));
+        tracker.record(PlayerId(1), "gpt-test", usage(2_000_000, 0));
+        assert_eq!(tracker.cost_of(PlayerId(0)), 2.0);
+        assert_eq!(tracker.cost_of(PlayerId(1)), 2.0);
+        assert_eq!(tracker.total_cost(), 4.0);
+        assert_eq!(tracker.total_tokens(), 3_500_000);
+    }
+
+    #[test]
+    fn unknown_models_count_tokens_but_cost_nothing() {
+        let mut tracker = CostTracker::new();
+        tracker.record(PlayerId(0), "mystery-model", usage(100, 100));
+        assert_eq!(tracker.total_cost(), 0.0);
+        assert_eq!(tracker.total_tokens(), 200);
+    }
+}
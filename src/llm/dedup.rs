@@ -0,0 +1,189 @@
+//! Embedding-based speech deduplication.
+//!
+//! Weak models repeat themselves; a table of them fills the transcript
+//! with the same accusation restated every round. A [`DedupFilter`]
+//! compares each new speech to the speaker's recent ones by cosine
+//! similarity over embeddings and, above a threshold, replaces the
+//! near-duplicate with a short placeholder — the reader sees "(Player 3
+//! reiterates their point.)" instead of the third retelling, and the
+//! placeholder adds nothing to the speaker's token tally.
+//!
+//! The [`Embedder`] behind it is abstract: production can plug in a real
+//! embedding endpoint, while [`HashingEmbedder`] gives tests a local
+//! bag-of-words stand-in that needs no network.
+
+use std::collections::{HashMap, VecDeque};
+use std::hash::{Hash, Hasher};
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+
+use crate::game::state::PlayerId;
+
+/// Turns text into a vector for similarity comparison. Async because real
+/// embedders are network calls; all vectors from one embedder must share a
+/// dimension.
+#[async_trait]
+pub trait Embedder: Send + Sync + std::fmt::Debug {
+    async fn embed(&self, text: &str) -> Vec<f32>;
+}
+
+/// A trivial local [`Embedder`]: a case- and punctuation-insensitive bag
+/// of words hashed into a fixed number of buckets. Crude, but
+/// near-identical speeches share almost all their words and land close in
+/// cosine space, which is all the dedup filter needs — and it never
+/// touches the network.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HashingEmbedder {
+    dims: usize,
+}
+
+impl Default for HashingEmbedder {
+    fn default() -> Self {
+        Self { dims: 64 }
+    }
+}
+
+#[async_trait]
+impl Embedder for HashingEmbedder {
+    async fn embed(&self, text: &str) -> Vec<f32> {
+        let mut vector = vec![0.0; self.dims];
+        for word in text.to_lowercase().split(|c: char| !c.is_alphanumeric()) {
+            if word.is_empty() {
+                continue;
+            }
+            let mut hasher = std::collections::hash_map::DefaultHasher::new();
+            word.hash(&mut hasher);
+            vector[(hasher.finish() % self.dims as u64) as usize] += 1.0;
+        }
+        vector
+    }
+}
+
+/// Cosine similarity between two vectors, in `[-1, 1]`; zero if either
+/// vector has zero norm.
+pub fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
+    let dot: f32 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+    let norm = |v: &[f32]| v.iter().map(|x| x * x).sum::<f32>().sqrt();
+    let denom = norm(a) * norm(b);
+    if denom == 0.0 { 0.0 } else { dot / denom }
+}
+
+/// Collapses a player's near-duplicate speeches into a placeholder.
+///
+/// Each speech is embedded and compared to the speaker's last `lookback`
+/// speeches; at or above `threshold` cosine similarity the transcript gets
+/// a placeholder instead of the repeat. The original reply is still what
+/// the model produced — token accounting happens on it, not on the
+/// placeholder.
+#[derive(Debug)]
+pub struct DedupFilter {
+    embedder: Arc<dyn Embedder>,
+    threshold: f32,
+    lookback: usize,
+    /// Per-speaker ring of recent speech embeddings.
+    recent: Mutex<HashMap<PlayerId, VecDeque<Vec<f32>>>>,
+}
+
+impl DedupFilter {
+    pub fn new(embedder: Arc<dyn Embedder>, threshold: f32, lookback: usize) -> Self {
+        Self { embedder, threshold, lookback, recent: Mutex::new(HashMap::new()) }
+    }
+
+    /// Returns the speech to record: the original text, or a short
+    /// placeholder if it is a near-duplicate of one of the speaker's
+    /// recent speeches. Either way the speech joins the speaker's window.
+    pub async fn rewrite(&self, player: PlayerId, text: String) -> String {
+        let embedding = self.embedder.embed(&text).await;
+        let mut recent = self.recent.lock().unwrap();
+        let window = recent.entry(player).or_default();
+        let duplicate = window
+            .iter()
+            .any(|prior| cosine_similarity(prior, &embedding) >= self.threshold);
+        window.push_back(embedding);
+        while window.len() > self.lookback {
+            window.pop_front();
+        }
+        if duplicate { format!("(Player {player} reiterates their point.)") } else { text }
+    }
+}
+
+impl Clone for DedupFilter {
+    fn clone(&self) -> Self {
+        Self {
+            embedder: self.embedder.clone(),
+            threshold: self.threshold,
+            lookback: self.lookback,
+            recent: Mutex::new(self.recent.lock().unwrap().clone()),
+        }
+    }
+}
+
+/// Equality is over configuration only; the remembered speeches are
+/// transient state, like the clock on a `TurnPolicy`.
+impl PartialEq for DedupFilter {
+    fn eq(&self, other: &Self) -> bool {
+        self.threshold == other.threshold && self.lookback == other.lookback
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn filter() -> DedupFilter {
+        DedupFilter::new(Arc::new(HashingEmbedder::default()), 0.9, 5)
+    }
+
+    #[tokio::test]
+    async fn near_identical_speeches_collapse_to_a_placeholder() {
+        let filter = filter();
+        let first = filter
+            .rewrite(PlayerId(3), "I am certain Player 5 is a wolf, their vote made no sense.".into())
+            .await;
+        assert_eq!(first, "I am certain Player 5 is a wolf, their vote made no sense.");
+
+        let second = filter
+            .rewrite(PlayerId(3), "I am certain Player 5 is a wolf; their vote made no sense!".into())
+            .await;
+        assert_eq!(second, "(Player 3 reiterates their point.)");
+    }
+
+    #[tokio::test]
+    async fn a_genuinely_new_speech_passes_through() {
+        let filter = filter();
+        filter.rewrite(PlayerId(0), "Player 5 has been quiet all game.".into()).await;
+        let next = filter
+            .rewrite(PlayerId(0), "The seer claim from seat two checks out with last night.".into())
+            .await;
+        assert_eq!(next, "The seer claim from seat two checks out with last night.");
+    }
+
+    #[tokio::test]
+    async fn windows_are_tracked_per_speaker() {
+        let filter = filter();
+        filter.rewrite(PlayerId(1), "Vote Player 4 today.".into()).await;
+        // The same words from a different seat are that seat's first
+        // speech, not a repeat.
+        let other = filter.rewrite(PlayerId(2), "Vote Player 4 today.".into()).await;
+        assert_eq!(other, "Vote Player 4 today.");
+    }
+
+    #[tokio::test]
+    async fn the_lookback_window_forgets_old_speeches() {
+        let filter = DedupFilter::new(Arc::new(HashingEmbedder::default()), 0.9, 1);
+        filter.rewrite(PlayerId(0), "Vote Player 4 today.".into()).await;
+        filter.rewrite(PlayerId(0), "Actually the guard claim changes everything.".into()).await;
+        // The first speech has scrolled out of the one-entry window.
+        let again = filter.rewrite(PlayerId(0), "Vote Player 4 today.".into()).await;
+        assert_eq!(again, "Vote Player 4 today.");
+    }
+
+    #[tokio::test]
+    async fn cosine_similarity_behaves_at_the_edges() {
+        let embedder = HashingEmbedder::default();
+        let a = embedder.embed("wolves among us").await;
+        assert!((cosine_similarity(&a, &a) - 1.0).abs() < 1e-6);
+        assert_eq!(cosine_similarity(&a, &vec![0.0; 64]), 0.0);
+    }
+}
@@ -0,0 +1,200 @@
+//! Pluggable HTTP transport for providers.
+//!
+//! Providers build an [`HttpRequest`] and hand it to an [`HttpClient`];
+//! the default [`ReqwestClient`] sends it over the network. Tests inject
+//! a [`MockHttpClient`] answering from a canned script instead, so
+//! parsing, error mapping, and retry logic can be exercised without a
+//! network.
+
+use std::collections::VecDeque;
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use futures::StreamExt;
+use futures::stream::BoxStream;
+
+use crate::llm::LlmError;
+
+/// A provider-built HTTP request. Providers only ever POST JSON, so the
+/// method and content type are implied.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpRequest {
+    pub url: String,
+    /// Extra headers as (name, value) pairs, in send order.
+    pub headers: Vec<(String, String)>,
+    /// The JSON payload, already serialized.
+    pub body: String,
+}
+
+/// A completed HTTP exchange: the status code and the full body.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpResponse {
+    pub status: u16,
+    pub body: String,
+}
+
+/// A response whose body arrives incrementally, for streaming
+/// completions.
+pub struct HttpStreamResponse {
+    pub status: u16,
+    pub bytes: BoxStream<'static, Result<Vec<u8>, LlmError>>,
+}
+
+/// The transport a provider sends its requests through.
+///
+/// `Err` is reserved for transport failures (DNS, connect, timeout, a
+/// broken body read); a non-2xx answer is an `Ok` response — mapping the
+/// status onto [`LlmError::Status`] is the provider's job.
+#[async_trait]
+pub trait HttpClient: Send + Sync + std::fmt::Debug {
+    /// Sends the request and collects the whole response.
+    async fn send(&self, req: HttpRequest) -> Result<HttpResponse, LlmError>;
+
+    /// Sends the request and hands the response body back as a byte
+    /// stream. The default buffers [`send`](HttpClient::send) into a
+    /// single chunk, so only transports that truly stream need to
+    /// override it.
+    async fn send_stream(&self, req: HttpRequest) -> Result<HttpStreamResponse, LlmError> {
+        let resp = self.send(req).await?;
+        Ok(HttpStreamResponse {
+            status: resp.status,
+            bytes: futures::stream::once(async move { Ok(resp.body.into_bytes()) }).boxed(),
+        })
+    }
+}
+
+/// The real transport, wrapping a shared [`reqwest::Client`].
+#[derive(Debug, Clone, Default)]
+pub struct ReqwestClient {
+    client: reqwest::Client,
+}
+
+impl ReqwestClient {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn builder(&self, req: HttpRequest) -> reqwest::RequestBuilder {
+        let mut builder = self
+            .client
+            .post(&req.url)
+            .header("content-type", "application/json");
+        for (name, value) in &req.headers {
+            builder = builder.header(name, value);
+        }
+        builder.body(req.body)
+    }
+}
+
+#[async_trait]
+impl HttpClient for ReqwestClient {
+    async fn send(&self, req: HttpRequest) -> Result<HttpResponse, LlmError> {
+        let response = self
+            .builder(req)
+            .send()
+            .await
+            .map_err(|e| LlmError::Network(e.to_string()))?;
+        let status = response.status().as_u16();
+        let body = response
+            .text()
+            .await
+            .map_err(|e| LlmError::Network(e.to_string()))?;
+        Ok(HttpResponse { status, body })
+    }
+
+    async fn send_stream(&self, req: HttpRequest) -> Result<HttpStreamResponse, LlmError> {
+        let response = self
+            .builder(req)
+            .send()
+            .await
+            .map_err(|e| LlmError::Network(e.to_string()))?;
+        let status = response.status().as_u16();
+        let bytes = response
+            .bytes_stream()
+            .map(|chunk| match chunk {
+                Ok(bytes) => Ok(bytes.to_vec()),
+                Err(e) => Err(LlmError::Network(e.to_string())),
+            })
+            .boxed();
+        Ok(HttpStreamResponse { status, bytes })
+    }
+}
+
+/// A canned transport for tests: answers each [`send`] from a scripted
+/// queue, in order, and keeps every request it saw for assertions.
+/// Exhausting the script panics — a test that sends more requests than it
+/// scripted is wrong.
+///
+/// [`send`]: HttpClient::send
+#[derive(Debug, Default)]
+pub struct MockHttpClient {
+    script: Mutex<VecDeque<Result<HttpResponse, LlmError>>>,
+    requests: Mutex<Vec<HttpRequest>>,
+}
+
+impl MockHttpClient {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a scripted response with the given status and body.
+    pub fn reply(self, status: u16, body: impl Into<String>) -> Self {
+        self.script
+            .lock()
+            .unwrap()
+            .push_back(Ok(HttpResponse { status, body: body.into() }));
+        self
+    }
+
+    /// Appends a scripted transport failure.
+    pub fn fail(self, err: LlmError) -> Self {
+        self.script.lock().unwrap().push_back(Err(err));
+        self
+    }
+
+    /// Every request sent so far, in order.
+    pub fn requests(&self) -> Vec<HttpRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl HttpClient for MockHttpClient {
+    async fn send(&self, req: HttpRequest) -> Result<HttpResponse, LlmError> {
+        self.requests.lock().unwrap().push(req);
+        self.script
+            .lock()
+            .unwrap()
+            .pop_front()
+            .expect("MockHttpClient ran out of scripted responses")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn the_mock_replays_its_script_and_records_requests() {
+        let client = MockHttpClient::new()
+            .reply(200, "first")
+            .reply(500, "second");
+        let req = HttpRequest { url: "http://x".into(), headers: vec![], body: "{}".into() };
+        assert_eq!(
+            client.send(req.clone()).await.unwrap(),
+            HttpResponse { status: 200, body: "first".into() }
+        );
+        assert_eq!(client.send(req.clone()).await.unwrap().status, 500);
+        assert_eq!(client.requests().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn the_default_stream_yields_the_whole_body_at_once() {
+        let client = MockHttpClient::new().reply(200, "all at once");
+        let req = HttpRequest { url: "http://x".into(), headers: vec![], body: "{}".into() };
+        let resp = client.send_stream(req).await.unwrap();
+        assert_eq!(resp.status, 200);
+        let chunks: Vec<Vec<u8>> = resp.bytes.map(Result::unwrap).collect().await;
+        assert_eq!(chunks, vec![b"all at once".to_vec()]);
+    }
+}
@@ -0,0 +1,591 @@
+//! LLM backends: the [`LlmProvider`] trait and concrete implementations.
+
+pub mod anthropic;
+pub mod cost;
+pub mod dedup;
+pub mod http;
+#[cfg(feature = "ollama")]
+pub mod ollama;
+pub mod parse;
+pub mod prompt;
+pub mod ratelimit;
+pub mod retry;
+pub mod summary;
+
+use async_trait::async_trait;
+use futures::StreamExt;
+use futures::stream::BoxStream;
+use serde::{Deserialize, Serialize};
+
+/// Who authored a chat message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MessageRole {
+    System,
+    User,
+    Assistant,
+}
+
+/// One role-tagged message in a conversation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChatMessage {
+    pub role: MessageRole,
+    pub content: String,
+}
+
+impl ChatMessage {
+    pub fn system(content: impl Into<String>) -> Self {
+        Self { role: MessageRole::System, content: content.into() }
+    }
+
+    pub fn user(content: impl Into<String>) -> Self {
+        Self { role: MessageRole::User, content: content.into() }
+    }
+
+    pub fn assistant(content: impl Into<String>) -> Self {
+        Self { role: MessageRole::Assistant, content: content.into() }
+    }
+}
+
+/// A completion request, provider-agnostic.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChatRequest {
+    pub messages: Vec<ChatMessage>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_tokens: Option<u32>,
+}
+
+impl ChatRequest {
+    pub fn new(messages: Vec<ChatMessage>) -> Self {
+        Self { messages, temperature: None, max_tokens: None }
+    }
+}
+
+/// Token accounting reported by the provider.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct TokenUsage {
+    pub prompt_tokens: u32,
+    pub completion_tokens: u32,
+    pub total_tokens: u32,
+}
+
+/// A completed model response.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChatResponse {
+    /// The assistant's reply content.
+    pub content: String,
+    /// Token usage for this call, if the provider reported it.
+    pub usage: TokenUsage,
+}
+
+/// Errors from talking to a model backend.
+#[derive(Debug, thiserror::Error)]
+pub enum LlmError {
+    /// The request never completed (DNS, connect, timeout, ...).
+    #[error("network error: {0}")]
+    Network(String),
+    /// The provider answered with a non-2xx status.
+    #[error("provider returned HTTP {status}: {body}")]
+    Status { status: u16, body: String },
+    /// The provider answered 2xx but the body wasn't the expected shape.
+    #[error("malformed response: {0}")]
+    MalformedResponse(String),
+}
+
+/// An async backend capable of producing chat completions.
+#[async_trait]
+pub trait LlmProvider: Send + Sync {
+    /// Sends the request and waits for the full completion.
+    async fn complete(&self, req: ChatRequest) -> Result<ChatResponse, LlmError>;
+
+    /// Sends the request and yields the completion incrementally, one
+    /// content fragment at a time.
+    ///
+    /// The default implementation is the non-streaming fallback: it calls
+    /// [`complete`](LlmProvider::complete) and yields the whole reply as a
+    /// single fragment, so callers can always use the streaming interface.
+    async fn complete_stream(
+        &self,
+        req: ChatRequest,
+    ) -> Result<BoxStream<'_, Result<String, LlmError>>, LlmError> {
+        let resp = self.complete(req).await?;
+        Ok(futures::stream::once(async move { Ok(resp.content) }).boxed())
+    }
+
+    /// Verifies credentials and connectivity with the cheapest possible
+    /// request: a 1-token completion. Run it before a long game or
+    /// tournament so a bad API key fails fast instead of mid-game.
+    ///
+    /// The default goes through [`complete`](LlmProvider::complete), so
+    /// the provider's rate limiting applies to the probe like any other
+    /// call.
+    async fn health_check(&self) -> Result<(), LlmError> {
+        let mut req = ChatRequest::new(vec![ChatMessage::user("ping")]);
+        req.max_tokens = Some(1);
+        self.complete(req).await.map(|_| ())
+    }
+}
+
+/// A provider that failed its pre-game health check, and why.
+#[derive(Debug, thiserror::Error)]
+#[error("provider '{provider}' failed its health check: {source}")]
+pub struct HealthCheckFailure {
+    /// The label the provider was registered under (model id, gateway
+    /// name, ...), so the message points at the right credentials.
+    pub provider: String,
+    #[source]
+    pub source: LlmError,
+}
+
+/// Health-checks every listed provider, in order, and fails on the first
+/// problem, naming the offender. `providers` pairs a label for the error
+/// message with the provider itself; deduplicate before calling so a
+/// shared backend is only probed once.
+pub async fn health_check_all(
+    providers: &[(String, std::sync::Arc<dyn LlmProvider>)],
+) -> Result<(), HealthCheckFailure> {
+    for (name, provider) in providers {
+        if let Err(source) = provider.health_check().await {
+            return Err(HealthCheckFailure { provider: name.clone(), source });
+        }
+    }
+    Ok(())
+}
+
+/// A provider speaking the OpenAI chat-completions wire format.
+///
+/// The base URL is configurable so OpenAI-compatible gateways (Azure,
+/// OpenRouter, vLLM, LiteLLM, ...) work by pointing `base_url` at them.
+#[derive(Debug, Clone)]
+pub struct OpenAiProvider {
+    client: std::sync::Arc<dyn crate::llm::http::HttpClient>,
+    api_key: String,
+    base_url: String,
+    model: String,
+    limiter: Option<std::sync::Arc<crate::llm::ratelimit::RateLimiter>>,
+}
+
+#[derive(Serialize)]
+struct OpenAiRequest<'a> {
+    model: &'a str,
+    messages: &'a [ChatMessage],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    temperature: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_tokens: Option<u32>,
+    #[serde(skip_serializing_if = "is_false")]
+    stream: bool,
+}
+
+fn is_false(b: &bool) -> bool {
+    !*b
+}
+
+#[derive(Deserialize)]
+struct OpenAiResponse {
+    choices: Vec<OpenAiChoice>,
+    #[serde(default)]
+    usage: Option<TokenUsage>,
+}
+
+#[derive(Deserialize)]
+struct OpenAiChoice {
+    message: OpenAiMessage,
+}
+
+#[derive(Deserialize)]
+struct OpenAiMessage {
+    content: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct OpenAiStreamChunk {
+    choices: Vec<OpenAiStreamChoice>,
+}
+
+#[derive(Deserialize)]
+struct OpenAiStreamChoice {
+    delta: OpenAiDelta,
+}
+
+#[derive(Deserialize)]
+struct OpenAiDelta {
+    #[serde(default)]
+    content: Option<String>,
+}
+
+/// Incremental parser for an OpenAI server-sent-events response body.
+///
+/// Bytes arrive in arbitrary chunks; the parser buffers until a complete
+/// `data:` line is available, so multi-byte UTF-8 sequences split across
+/// chunk boundaries are reassembled before decoding. Everything after the
+/// `[DONE]` sentinel is ignored.
+#[derive(Debug, Default)]
+struct SseParser {
+    buf: Vec<u8>,
+    done: bool,
+}
+
+impl SseParser {
+    /// Feeds raw bytes in; returns the content fragments (or errors) that
+    /// became available.
+    fn push(&mut self, bytes: &[u8]) -> Vec<Result<String, LlmError>> {
+        self.buf.extend_from_slice(bytes);
+        let mut out = Vec::new();
+        while let Some(pos) = self.buf.iter().position(|&b| b == b'\n') {
+            let line: Vec<u8> = self.buf.drain(..=pos).collect();
+            if self.done {
+                continue;
+            }
+            let Ok(line) = std::str::from_utf8(&line) else {
+                out.push(Err(LlmError::MalformedResponse(
+                    "stream contained invalid UTF-8".into(),
+                )));
+                continue;
+            };
+            let Some(data) = line.trim().strip_prefix("data:") else { continue };
+            let data = data.trim();
+            if data == "[DONE]" {
+                self.done = true;
+                continue;
+            }
+            match serde_json::from_str::<OpenAiStreamChunk>(data) {
+                Ok(chunk) => {
+                    if let Some(delta) =
+                        chunk.choices.into_iter().next().and_then(|c| c.delta.content)
+                        && !delta.is_empty()
+                    {
+                        out.push(Ok(delta));
+                    }
+                }
+                Err(e) => out.push(Err(LlmError::MalformedResponse(e.to_string()))),
+            }
+        }
+        out
+    }
+}
+
+impl OpenAiProvider {
+    pub fn new(
+        api_key: impl Into<String>,
+        base_url: impl Into<String>,
+        model: impl Into<String>,
+    ) -> Self {
+        Self {
+            client: std::sync::Arc::new(crate::llm::http::ReqwestClient::new()),
+            api_key: api_key.into(),
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            model: model.into(),
+            limiter: None,
+        }
+    }
+
+    /// Throttles this provider through a shared [`RateLimiter`]: every
+    /// completion call first awaits a token. Hand the same `Arc` to every
+    /// provider using the same API key.
+    ///
+    /// [`RateLimiter`]: crate::llm::ratelimit::RateLimiter
+    pub fn with_rate_limiter(
+        mut self,
+        limiter: std::sync::Arc<crate::llm::ratelimit::RateLimiter>,
+    ) -> Self {
+        self.limiter = Some(limiter);
+        self
+    }
+
+    /// Replaces the transport — a [`MockHttpClient`] in tests, or any
+    /// custom [`HttpClient`] (proxying, capture, ...).
+    ///
+    /// [`HttpClient`]: crate::llm::http::HttpClient
+    /// [`MockHttpClient`]: crate::llm::http::MockHttpClient
+    pub fn with_http_client(
+        mut self,
+        client: std::sync::Arc<dyn crate::llm::http::HttpClient>,
+    ) -> Self {
+        self.client = client;
+        self
+    }
+
+    /// The model id requests are sent with.
+    pub fn model(&self) -> &str {
+        &self.model
+    }
+
+    fn endpoint(&self) -> String {
+        format!("{}/v1/chat/completions", self.base_url)
+    }
+
+    fn request(&self, payload: &OpenAiRequest<'_>) -> crate::llm::http::HttpRequest {
+        crate::llm::http::HttpRequest {
+            url: self.endpoint(),
+            headers: vec![("authorization".into(), format!("Bearer {}", self.api_key))],
+            body: serde_json::to_string(payload).expect("request serialization cannot fail"),
+        }
+    }
+
+    /// Parses an OpenAI-format completion body into a [`ChatResponse`].
+    fn parse_response(body: &str) -> Result<ChatResponse, LlmError> {
+        let parsed: OpenAiResponse = serde_json::from_str(body)
+            .map_err(|e| LlmError::MalformedResponse(e.to_string()))?;
+        let content = parsed
+            .choices
+            .first()
+            .and_then(|c| c.message.content.clone())
+            .ok_or_else(|| {
+                LlmError::MalformedResponse("response contained no choices".into())
+            })?;
+        Ok(ChatResponse { content, usage: parsed.usage.unwrap_or_default() })
+    }
+}
+
+#[async_trait]
+impl LlmProvider for OpenAiProvider {
+    async fn complete(&self, req: ChatRequest) -> Result<ChatResponse, LlmError> {
+        if let Some(limiter) = &self.limiter {
+            limiter.acquire().await;
+        }
+        let payload = OpenAiRequest {
+            model: &self.model,
+            messages: &req.messages,
+            temperature: req.temperature,
+            max_tokens: req.max_tokens,
+            stream: false,
+        };
+        let response = self.client.send(self.request(&payload)).await?;
+        if !(200..300).contains(&response.status) {
+            return Err(LlmError::Status { status: response.status, body: response.body });
+        }
+        Self::parse_response(&response.body)
+    }
+
+    async fn complete_stream(
+        &self,
+        req: ChatRequest,
+    ) -> Result<BoxStream<'_, Result<String, LlmError>>, LlmError> {
+        if let Some(limiter) = &self.limiter {
+            limiter.acquire().await;
+        }
+        let payload = OpenAiRequest {
+            model: &self.model,
+            messages: &req.messages,
+            temperature: req.temperature,
+            max_tokens: req.max_tokens,
+            stream: true,
+        };
+        let response = self.client.send_stream(self.request(&payload)).await?;
+        if !(200..300).contains(&response.status) {
+            // Collect the (short) error body so the status error can
+            // carry it, same as the non-streaming path.
+            let mut body = Vec::new();
+            let mut bytes = response.bytes;
+            while let Some(chunk) = bytes.next().await {
+                body.extend(chunk?);
+            }
+            return Err(LlmError::Status {
+                status: response.status,
+                body: String::from_utf8_lossy(&body).into_owned(),
+            });
+        }
+
+        let mut parser = SseParser::default();
+        let stream = response.bytes.flat_map(move |chunk| {
+            let fragments = match chunk {
+                Ok(bytes) => parser.push(&bytes),
+                Err(e) => vec![Err(e)],
+            };
+            futures::stream::iter(fragments)
+        });
+        Ok(stream.boxed())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_a_standard_completion_body() {
+        let body = r#"{
+            "choices": [{"message": {"role": "assistant", "content": "I vote for Alice."}}],
+            "usage": {"prompt_tokens": 120, "completion_tokens": 6, "total_tokens": 126}
+        }"#;
+        let resp = OpenAiProvider::parse_response(body).unwrap();
+        assert_eq!(resp.content, "I vote for Alice.");
+        assert_eq!(resp.usage.total_tokens, 126);
+    }
+
+    #[test]
+    fn missing_choices_is_malformed() {
+        let err = OpenAiProvider::parse_response(r#"{"choices": []}"#).unwrap_err();
+        assert!(matches!(err, LlmError::MalformedResponse(_)));
+    }
+
+    #[test]
+    fn invalid_json_is_malformed() {
+        let err = OpenAiProvider::parse_response("not json").unwrap_err();
+        assert!(matches!(err, LlmError::MalformedResponse(_)));
+    }
+
+    #[test]
+    fn base_url_trailing_slash_is_normalized() {
+        let p = OpenAiProvider::new("k", "https://example.com/", "gpt-4o");
+        assert_eq!(p.endpoint(), "https://example.com/v1/chat/completions");
+    }
+
+    fn delta_line(content: &str) -> String {
+        format!(r#"data: {{"choices":[{{"delta":{{"content":"{content}"}}}}]}}"#) + "\n\n"
+    }
+
+    #[test]
+    fn sse_parser_yields_fragments_and_honors_done() {
+        let mut parser = SseParser::default();
+        let mut body = delta_line("Hel");
+        body.push_str(&delta_line("lo"));
+        body.push_str("data: [DONE]\n\n");
+        body.push_str(&delta_line("ignored after done"));
+        let fragments: Vec<String> =
+            parser.push(body.as_bytes()).into_iter().map(Result::unwrap).collect();
+        assert_eq!(fragments, vec!["Hel", "lo"]);
+    }
+
+    #[test]
+    fn sse_parser_reassembles_lines_split_across_chunks() {
+        let mut parser = SseParser::default();
+        // Split mid-way through the multi-byte character "é".
+        let line = delta_line("caf\u{e9}");
+        let bytes = line.as_bytes();
+        let split = line.find('\u{e9}').unwrap() + 1; // inside the 2-byte sequence
+        let mut fragments = parser.push(&bytes[..split]);
+        assert!(fragments.is_empty());
+        fragments.extend(parser.push(&bytes[split..]));
+        let fragments: Vec<String> = fragments.into_iter().map(Result::unwrap).collect();
+        assert_eq!(fragments, vec!["caf\u{e9}"]);
+    }
+
+    #[test]
+    fn sse_parser_flags_malformed_chunks() {
+        let mut parser = SseParser::default();
+        let out = parser.push(b"data: {not json}\n");
+        assert!(matches!(out.as_slice(), [Err(LlmError::MalformedResponse(_))]));
+    }
+
+    #[tokio::test]
+    async fn a_429_then_200_sequence_succeeds_through_the_retry_wrapper() {
+        let ok_body = r#"{"choices": [{"message": {"content": "recovered"}}]}"#;
+        let mock = std::sync::Arc::new(
+            crate::llm::http::MockHttpClient::new()
+                .reply(429, "slow down")
+                .reply(200, ok_body),
+        );
+        let provider = OpenAiProvider::new("key", "https://example.com", "gpt-4o")
+            .with_http_client(mock.clone());
+        let policy = crate::llm::retry::RetryPolicy {
+            max_retries: 3,
+            base_delay: std::time::Duration::ZERO,
+            jitter: 0.0,
+        };
+        let retrying = crate::llm::retry::RetryingProvider::new(provider, policy);
+
+        let resp = retrying
+            .complete(ChatRequest::new(vec![ChatMessage::user("hi")]))
+            .await
+            .unwrap();
+
+        assert_eq!(resp.content, "recovered");
+        // Both attempts went over the wire, with auth attached.
+        let requests = mock.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].headers, vec![("authorization".to_string(), "Bearer key".to_string())]);
+        assert!(requests[0].url.ends_with("/v1/chat/completions"));
+    }
+
+    #[tokio::test]
+    async fn a_malformed_200_body_maps_to_malformed_response() {
+        let mock = std::sync::Arc::new(
+            crate::llm::http::MockHttpClient::new().reply(200, r#"{"unexpected": true}"#),
+        );
+        let provider = OpenAiProvider::new("key", "https://example.com", "gpt-4o")
+            .with_http_client(mock);
+        let err = provider
+            .complete(ChatRequest::new(vec![ChatMessage::user("hi")]))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, LlmError::MalformedResponse(_)));
+    }
+
+    #[tokio::test]
+    async fn a_non_2xx_status_carries_the_body() {
+        let mock = std::sync::Arc::new(
+            crate::llm::http::MockHttpClient::new().reply(503, "overloaded"),
+        );
+        let provider = OpenAiProvider::new("key", "https://example.com", "gpt-4o")
+            .with_http_client(mock);
+        let err = provider
+            .complete(ChatRequest::new(vec![ChatMessage::user("hi")]))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, LlmError::Status { status: 503, body } if body == "overloaded"));
+    }
+
+    #[tokio::test]
+    async fn default_complete_stream_falls_back_to_one_fragment() {
+        struct Canned;
+
+        #[async_trait]
+        impl LlmProvider for Canned {
+            async fn complete(&self, _req: ChatRequest) -> Result<ChatResponse, LlmError> {
+                Ok(ChatResponse { content: "all at once".into(), usage: TokenUsage::default() })
+            }
+        }
+
+        let stream = Canned
+            .complete_stream(ChatRequest::new(vec![ChatMessage::user("hi")]))
+            .await
+            .unwrap();
+        let fragments: Vec<String> =
+            stream.map(Result::unwrap).collect::<Vec<_>>().await;
+        assert_eq!(fragments, vec!["all at once"]);
+    }
+
+    #[tokio::test]
+    async fn health_check_probes_with_a_one_token_request() {
+        struct Recording {
+            seen: std::sync::Mutex<Option<ChatRequest>>,
+        }
+
+        #[async_trait]
+        impl LlmProvider for Recording {
+            async fn complete(&self, req: ChatRequest) -> Result<ChatResponse, LlmError> {
+                *self.seen.lock().unwrap() = Some(req);
+                Ok(ChatResponse { content: String::new(), usage: TokenUsage::default() })
+            }
+        }
+
+        let provider = Recording { seen: std::sync::Mutex::new(None) };
+        provider.health_check().await.unwrap();
+        let req = provider.seen.lock().unwrap().take().unwrap();
+        assert_eq!(req.max_tokens, Some(1));
+        assert_eq!(req.messages.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn health_check_all_names_the_failing_provider() {
+        struct Broken;
+
+        #[async_trait]
+        impl LlmProvider for Broken {
+            async fn complete(&self, _req: ChatRequest) -> Result<ChatResponse, LlmError> {
+                Err(LlmError::Status { status: 401, body: "bad key".into() })
+            }
+        }
+
+        let providers: Vec<(String, std::sync::Arc<dyn LlmProvider>)> =
+            vec![("gpt-test".into(), std::sync::Arc::new(Broken))];
+        let err = health_check_all(&providers).await.unwrap_err();
+        assert_eq!(err.provider, "gpt-test");
+        assert!(err.to_string().contains("gpt-test"));
+        assert!(matches!(err.source, LlmError::Status { status: 401, .. }));
+    }
+}
@@ -0,0 +1,208 @@
+//! Local model backend speaking the Ollama chat API.
+//!
+//! Ollama streams newline-delimited JSON rather than SSE, and uses its own
+//! field names (`message.content`, `prompt_eval_count`/`eval_count`,
+//! `done`). This module reassembles those into the shared [`ChatResponse`]
+//! so an [`LlmPlayer`] works against a local model with no other changes.
+//!
+//! Local models are slow and frequently answer with nothing useful; an
+//! empty reply is surfaced as [`LlmError::MalformedResponse`] so the
+//! normal retry and fallback machinery kicks in instead of the game
+//! treating silence as an answer.
+//!
+//! [`LlmPlayer`]: crate::player::LlmPlayer
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use crate::llm::http::{HttpClient, HttpRequest, ReqwestClient};
+use crate::llm::{ChatMessage, ChatRequest, ChatResponse, LlmError, LlmProvider, TokenUsage};
+
+/// A provider talking to a local Ollama server.
+#[derive(Debug, Clone)]
+pub struct OllamaProvider {
+    client: std::sync::Arc<dyn HttpClient>,
+    base_url: String,
+    model: String,
+}
+
+#[derive(Serialize)]
+struct OllamaRequest<'a> {
+    model: &'a str,
+    messages: &'a [ChatMessage],
+    stream: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    options: Option<OllamaOptions>,
+}
+
+#[derive(Serialize)]
+struct OllamaOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    temperature: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    num_predict: Option<u32>,
+}
+
+#[derive(Deserialize)]
+struct OllamaChunk {
+    #[serde(default)]
+    message: Option<OllamaMessage>,
+    #[serde(default)]
+    done: bool,
+    #[serde(default)]
+    prompt_eval_count: Option<u32>,
+    #[serde(default)]
+    eval_count: Option<u32>,
+}
+
+#[derive(Deserialize)]
+struct OllamaMessage {
+    #[serde(default)]
+    content: String,
+}
+
+impl OllamaProvider {
+    pub fn new(model: impl Into<String>) -> Self {
+        Self {
+            client: std::sync::Arc::new(ReqwestClient::new()),
+            base_url: "http://localhost:11434".to_string(),
+            model: model.into(),
+        }
+    }
+
+    /// Points the provider at a non-default Ollama server.
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Replaces the transport — a [`MockHttpClient`] in tests, or any
+    /// custom [`HttpClient`] (proxying, capture, ...).
+    ///
+    /// [`MockHttpClient`]: crate::llm::http::MockHttpClient
+    pub fn with_http_client(mut self, client: std::sync::Arc<dyn HttpClient>) -> Self {
+        self.client = client;
+        self
+    }
+
+    /// The model id requests are sent with.
+    pub fn model(&self) -> &str {
+        &self.model
+    }
+
+    fn endpoint(&self) -> String {
+        format!("{}/api/chat", self.base_url)
+    }
+
+    /// Parses a chat response body — either a single JSON object or
+    /// Ollama's newline-delimited streaming format — into a
+    /// [`ChatResponse`].
+    fn parse_response(body: &str) -> Result<ChatResponse, LlmError> {
+        let mut content = String::new();
+        let mut usage = TokenUsage::default();
+        let mut parsed_any = false;
+        for line in body.lines().filter(|l| !l.trim().is_empty()) {
+            let chunk: OllamaChunk = serde_json::from_str(line)
+                .map_err(|e| LlmError::MalformedResponse(e.to_string()))?;
+            parsed_any = true;
+            if let Some(message) = chunk.message {
+                content.push_str(&message.content);
+            }
+            if chunk.done {
+                let prompt = chunk.prompt_eval_count.unwrap_or(0);
+                let completion = chunk.eval_count.unwrap_or(0);
+                usage = TokenUsage {
+                    prompt_tokens: prompt,
+                    completion_tokens: completion,
+                    total_tokens: prompt + completion,
+                };
+                break;
+            }
+        }
+        if !parsed_any {
+            return Err(LlmError::MalformedResponse("empty response body".into()));
+        }
+        if content.trim().is_empty() {
+            // Treat silence as a failure so retries and fallbacks apply.
+            return Err(LlmError::MalformedResponse("model returned an empty reply".into()));
+        }
+        Ok(ChatResponse { content, usage })
+    }
+}
+
+#[async_trait]
+impl LlmProvider for OllamaProvider {
+    async fn complete(&self, req: ChatRequest) -> Result<ChatResponse, LlmError> {
+        let options = if req.temperature.is_some() || req.max_tokens.is_some() {
+            Some(OllamaOptions { temperature: req.temperature, num_predict: req.max_tokens })
+        } else {
+            None
+        };
+        let payload = OllamaRequest {
+            model: &self.model,
+            messages: &req.messages,
+            // The body is collected whole, so ask for the streaming format
+            // and reassemble; `parse_response` handles both shapes anyway.
+            stream: true,
+            options,
+        };
+        let request = HttpRequest {
+            url: self.endpoint(),
+            headers: Vec::new(),
+            body: serde_json::to_string(&payload).expect("request serialization cannot fail"),
+        };
+        let response = self.client.send(request).await?;
+        if !(200..300).contains(&response.status) {
+            return Err(LlmError::Status { status: response.status, body: response.body });
+        }
+        Self::parse_response(&response.body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_newline_delimited_streaming_chunks() {
+        let body = concat!(
+            r#"{"message":{"role":"assistant","content":"I vote "},"done":false}"#,
+            "\n",
+            r#"{"message":{"role":"assistant","content":"for 2."},"done":false}"#,
+            "\n",
+            r#"{"done":true,"prompt_eval_count":90,"eval_count":5}"#,
+            "\n",
+        );
+        let resp = OllamaProvider::parse_response(body).unwrap();
+        assert_eq!(resp.content, "I vote for 2.");
+        assert_eq!(resp.usage.prompt_tokens, 90);
+        assert_eq!(resp.usage.total_tokens, 95);
+    }
+
+    #[test]
+    fn parses_a_single_non_streamed_object() {
+        let body = r#"{"message":{"role":"assistant","content":"hello"},"done":true,"eval_count":2}"#;
+        let resp = OllamaProvider::parse_response(body).unwrap();
+        assert_eq!(resp.content, "hello");
+        assert_eq!(resp.usage.completion_tokens, 2);
+    }
+
+    #[test]
+    fn an_empty_reply_is_an_error_so_fallbacks_apply() {
+        let body = r#"{"message":{"role":"assistant","content":"  "},"done":true}"#;
+        let err = OllamaProvider::parse_response(body).unwrap_err();
+        assert!(matches!(err, LlmError::MalformedResponse(_)));
+    }
+
+    #[test]
+    fn garbage_lines_are_malformed() {
+        let err = OllamaProvider::parse_response("not json\n").unwrap_err();
+        assert!(matches!(err, LlmError::MalformedResponse(_)));
+    }
+
+    #[test]
+    fn endpoint_targets_the_chat_route() {
+        let p = OllamaProvider::new("llama3").with_base_url("http://192.168.1.5:11434/");
+        assert_eq!(p.endpoint(), "http://192.168.1.5:11434/api/chat");
+    }
+}
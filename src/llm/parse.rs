@@ -0,0 +1,220 @@
+//! Extracting structured decisions from messy model text.
+//!
+//! Models are asked to answer with a JSON block like `{"vote": 3}` but
+//! routinely reply in prose ("I vote for Player 3"). The parsers here
+//! accept both: an explicit JSON block wins when present; otherwise the
+//! text is scanned for player mentions and accepted only when it names
+//! exactly one living player. Anything ambiguous, malformed, or naming a
+//! dead or unknown player yields `None` so the turn fallback kicks in.
+
+use crate::game::state::PlayerId;
+
+/// Recognizes model refusals: empty output, or output containing one of a
+/// set of trigger substrings ("I cannot...", "as an AI...").
+///
+/// A refusal is not a parse failure. A parse failure is valid text in the
+/// wrong format — the model played, the parser lost — and surfaces as a
+/// plain fallback. A refusal is the model declining to play at all, and
+/// the engine logs it separately (as [`PlayerRefused`]) so metrics can
+/// tell the two apart.
+///
+/// [`PlayerRefused`]: crate::game::event::GameEventKind::PlayerRefused
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RefusalDetector {
+    /// Lowercased trigger substrings; matching is case-insensitive.
+    triggers: Vec<String>,
+}
+
+impl Default for RefusalDetector {
+    /// The stock trigger list, covering the common refusal openers of
+    /// current chat models.
+    fn default() -> Self {
+        Self::with_triggers(
+            [
+                "i can't",
+                "i cannot",
+                "i won't",
+                "i'm sorry",
+                "i am sorry",
+                "i'm not able to",
+                "i am not able to",
+                "as an ai",
+                "as a language model",
+            ]
+            .map(String::from)
+            .into(),
+        )
+    }
+}
+
+impl RefusalDetector {
+    /// A detector with a custom trigger list; matching is
+    /// case-insensitive. An empty list still flags empty output.
+    pub fn with_triggers(triggers: Vec<String>) -> Self {
+        Self { triggers: triggers.into_iter().map(|t| t.to_lowercase()).collect() }
+    }
+
+    /// Whether `text` is a refusal: blank, or containing any trigger.
+    pub fn is_refusal(&self, text: &str) -> bool {
+        let trimmed = text.trim();
+        if trimmed.is_empty() {
+            return true;
+        }
+        let lowered = trimmed.to_lowercase();
+        self.triggers.iter().any(|t| lowered.contains(t))
+    }
+}
+
+/// Extracts a vote target from model output. Accepts `{"vote": 3}`,
+/// `{"vote": "Player 3"}`, or prose naming exactly one living player.
+pub fn parse_vote(text: &str, living: &[PlayerId]) -> Option<PlayerId> {
+    parse_choice(text, "vote", living)
+}
+
+/// Extracts a night-action target from model output. Accepts
+/// `{"target": 3}`, `{"target": "Player 3"}`, or prose naming exactly one
+/// living player.
+pub fn parse_night_target(text: &str, living: &[PlayerId]) -> Option<PlayerId> {
+    parse_choice(text, "target", living)
+}
+
+fn parse_choice(text: &str, key: &str, living: &[PlayerId]) -> Option<PlayerId> {
+    if let Some(answer) = json_choice(text, key) {
+        // An explicit JSON answer is authoritative: if it names a dead or
+        // unknown player we fail rather than second-guess it from prose.
+        return answer.filter(|id| living.contains(id));
+    }
+    loose_choice(text, living)
+}
+
+/// The JSON-block answer, if the text contains a valid JSON object.
+///
+/// Returns `None` when no valid JSON object is present (prose scanning may
+/// still apply); `Some(None)` when a JSON object is present but carries no
+/// intelligible value for `key` — structured output that doesn't answer
+/// the question is a refusal, not something to mine for digits.
+fn json_choice(text: &str, key: &str) -> Option<Option<PlayerId>> {
+    let start = text.find('{')?;
+    let end = text.rfind('}')?;
+    let value: serde_json::Value = serde_json::from_str(&text[start..=end]).ok()?;
+    Some(value.get(key).and_then(id_from_value))
+}
+
+fn id_from_value(value: &serde_json::Value) -> Option<PlayerId> {
+    match value {
+        serde_json::Value::Number(n) => n.as_u64().and_then(|n| u32::try_from(n).ok()).map(PlayerId),
+        serde_json::Value::String(s) => id_from_token(s),
+        _ => None,
+    }
+}
+
+/// Parses tokens like `"3"`, `"Player 3"`, or `"player3"`.
+fn id_from_token(token: &str) -> Option<PlayerId> {
+    let trimmed = token.trim();
+    let rest = trimmed
+        .to_ascii_lowercase()
+        .strip_prefix("player")
+        .map(|r| r.trim_start().to_string())
+        .unwrap_or_else(|| trimmed.to_string());
+    rest.parse().ok()
+}
+
+/// Scans prose for player mentions and accepts the answer only when
+/// exactly one distinct living player is named.
+fn loose_choice(text: &str, living: &[PlayerId]) -> Option<PlayerId> {
+    let mut mentioned: Vec<PlayerId> = Vec::new();
+    let mut digits = String::new();
+    for c in text.chars().chain(std::iter::once(' ')) {
+        if c.is_ascii_digit() {
+            digits.push(c);
+        } else if !digits.is_empty() {
+            if let Ok(id) = digits.parse::<PlayerId>()
+                && living.contains(&id)
+                && !mentioned.contains(&id)
+            {
+                mentioned.push(id);
+            }
+            digits.clear();
+        }
+    }
+    match mentioned.as_slice() {
+        [only] => Some(*only),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LIVING: &[PlayerId] = &[PlayerId(0), PlayerId(2), PlayerId(3), PlayerId(5)];
+
+    #[test]
+    fn vote_parsing_table() {
+        let cases: &[(&str, &str, Option<PlayerId>)] = &[
+            // Strict JSON forms.
+            ("json number", r#"{"vote": 3}"#, Some(PlayerId(3))),
+            ("json string id", r#"{"vote": "3"}"#, Some(PlayerId(3))),
+            ("json player name", r#"{"vote": "Player 3"}"#, Some(PlayerId(3))),
+            ("json lowercase, no space", r#"{"vote":"player3"}"#, Some(PlayerId(3))),
+            ("json wrapped in prose", r#"Sure! {"vote": 5} is my answer."#, Some(PlayerId(5))),
+            // JSON is authoritative, even when wrong.
+            ("json dead player", r#"{"vote": 4}"#, None),
+            ("json nonsense value", r#"{"vote": true}"#, None),
+            ("json overrides prose", r#"I like 3 but {"vote": 4}"#, None),
+            // Loose natural language.
+            ("plain prose", "I vote for Player 3.", Some(PlayerId(3))),
+            ("case insensitive", "I VOTE FOR PLAYER 3!", Some(PlayerId(3))),
+            ("bare number", "3", Some(PlayerId(3))),
+            ("dead players ignored", "Player 4 is gone, so Player 3.", Some(PlayerId(3))),
+            ("repeated mention is fine", "Player 3... yes, 3.", Some(PlayerId(3))),
+            // Failure modes that must trigger the fallback.
+            ("ambiguous mentions", "Either Player 2 or Player 3.", None),
+            ("names only the dead", "I vote for Player 4.", None),
+            ("no target at all", "I abstain.", None),
+            ("empty input", "", None),
+            ("broken json falls back to prose", r#"{"vote": } Player 2"#, Some(PlayerId(2))),
+        ];
+        for (name, input, expected) in cases {
+            assert_eq!(parse_vote(input, LIVING), *expected, "case: {name}");
+        }
+    }
+
+    #[test]
+    fn refusal_detection_table() {
+        let detector = RefusalDetector::default();
+        let cases: &[(&str, &str, bool)] = &[
+            ("empty input", "", true),
+            ("whitespace only", "  \n\t ", true),
+            ("refusal phrase", "I'm sorry, I can't participate in this game.", true),
+            ("case insensitive", "AS AN AI, I must decline.", true),
+            ("normal response", "I vote for Player 3, they dodged every question.", false),
+            ("sorry mid-discussion is still flagged", "Well, I am sorry to say it: wolf.", true),
+        ];
+        for (name, input, expected) in cases {
+            assert_eq!(detector.is_refusal(input), *expected, "case: {name}");
+        }
+    }
+
+    #[test]
+    fn custom_triggers_replace_the_stock_list() {
+        let detector = RefusalDetector::with_triggers(vec!["No Comment".into()]);
+        assert!(detector.is_refusal("no comment."));
+        assert!(detector.is_refusal(""));
+        assert!(!detector.is_refusal("I'm sorry, but Player 2 is lying."));
+    }
+
+    #[test]
+    fn night_target_parsing_table() {
+        let cases: &[(&str, &str, Option<PlayerId>)] = &[
+            ("json target", r#"{"target": 2}"#, Some(PlayerId(2))),
+            ("json string target", r#"{"target": "Player 5"}"#, Some(PlayerId(5))),
+            ("prose target", "Tonight I investigate Player 0.", Some(PlayerId(0))),
+            ("vote key is not a target", r#"{"vote": 2}"#, None),
+            ("ambiguous", "Maybe 2, maybe 5.", None),
+        ];
+        for (name, input, expected) in cases {
+            assert_eq!(parse_night_target(input, LIVING), *expected, "case: {name}");
+        }
+    }
+}
@@ -0,0 +1,197 @@
+//! Prompt templating for LLM players.
+//!
+//! Templates use simple `{placeholder}` substitution. Rendering is strict:
+//! a placeholder with no value, or a brace that never closes, is a
+//! [`RenderError`] rather than silently leaking braces into the prompt.
+
+use std::collections::HashMap;
+
+use crate::player::GameContext;
+
+/// A prompt with `{placeholder}` slots.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PromptTemplate {
+    template: String,
+}
+
+/// A failure to render a template.
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum RenderError {
+    #[error("unknown placeholder {{{0}}}")]
+    UnknownPlaceholder(String),
+    #[error("unclosed '{{' at byte offset {0}")]
+    UnclosedBrace(usize),
+}
+
+impl PromptTemplate {
+    pub fn new(template: impl Into<String>) -> Self {
+        Self { template: template.into() }
+    }
+
+    /// Substitutes every `{placeholder}` from `vars`.
+    pub fn render(&self, vars: &HashMap<&str, String>) -> Result<String, RenderError> {
+        let mut out = String::with_capacity(self.template.len());
+        let mut rest = self.template.as_str();
+        while let Some(open) = rest.find('{') {
+            out.push_str(&rest[..open]);
+            let after = &rest[open + 1..];
+            let close = after.find('}').ok_or_else(|| {
+                RenderError::UnclosedBrace(self.template.len() - rest.len() + open)
+            })?;
+            let name = &after[..close];
+            let value = vars
+                .get(name)
+                .ok_or_else(|| RenderError::UnknownPlaceholder(name.to_string()))?;
+            out.push_str(value);
+            rest = &after[close + 1..];
+        }
+        out.push_str(rest);
+        Ok(out)
+    }
+}
+
+/// The per-phase templates an [`LlmPlayer`] renders its prompts from.
+/// Every template can be overridden; [`PromptSet::default`] ships sensible
+/// English defaults.
+///
+/// [`LlmPlayer`]: crate::player::LlmPlayer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PromptSet {
+    /// Role-specific system prompt, rendered once per game.
+    pub system: PromptTemplate,
+    /// Per-turn prompt for the discussion phase.
+    pub discussion: PromptTemplate,
+    /// Per-turn prompt for the voting phase.
+    pub voting: PromptTemplate,
+    /// Per-turn prompt for the night-action phase.
+    pub night_action: PromptTemplate,
+}
+
+impl Default for PromptSet {
+    fn default() -> Self {
+        Self {
+            system: PromptTemplate::new(
+                "You are playing Werewolf as player {player}. Your secret role is \
+                 {role}. Win for your side. Never reveal hidden information unless \
+                 it is strategically sound.",
+            ),
+            discussion: PromptTemplate::new(
+                "It is day {day}. Living players: {alive_players}. Recent \
+                 discussion:\n{recent_discussion}\nAs the {role}, make your \
+                 statement to the table.",
+            ),
+            voting: PromptTemplate::new(
+                "It is day {day} and voting has begun. Living players: \
+                 {alive_players}. Recent discussion:\n{recent_discussion}\nName \
+                 the one player you vote to eliminate.",
+            ),
+            night_action: PromptTemplate::new(
+                "It is night {day}. Living players: {alive_players}. As the \
+                 {role}, choose your night action target.",
+            ),
+        }
+    }
+}
+
+/// The standard substitution variables derived from a [`GameContext`].
+pub fn context_vars(ctx: &GameContext) -> HashMap<&'static str, String> {
+    let alive = ctx
+        .alive_players
+        .iter()
+        .map(|id| id.to_string())
+        .collect::<Vec<_>>()
+        .join(", ");
+    let recent = if ctx.public_log.is_empty() {
+        "(nothing yet)".to_string()
+    } else {
+        ctx.public_log.join("\n")
+    };
+    let investigations = if ctx.knowledge.investigations.is_empty() {
+        "(none)".to_string()
+    } else {
+        ctx.knowledge
+            .investigations
+            .iter()
+            .map(|i| {
+                format!("night {}: player {} is {:?}", i.night, i.target, i.revealed_alignment)
+            })
+            .collect::<Vec<_>>()
+            .join("; ")
+    };
+    let claims = if ctx.claims.is_empty() {
+        "(none)".to_string()
+    } else {
+        ctx.claims
+            .iter()
+            .map(|c| format!("player {} claims {}", c.claimant, c.role.info().display_name))
+            .collect::<Vec<_>>()
+            .join("; ")
+    };
+    HashMap::from([
+        ("player", ctx.player.to_string()),
+        ("role", ctx.role.info().display_name.to_string()),
+        ("day", ctx.day.to_string()),
+        ("alive_players", alive),
+        ("recent_discussion", recent),
+        ("investigations", investigations),
+        ("claims", claims),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::state::{Phase, PlayerId};
+    use crate::roles::Role;
+
+    fn ctx() -> GameContext {
+        GameContext {
+            player: PlayerId(2),
+            role: Role::Seer,
+            day: 3,
+            phase: Phase::Day,
+            alive_players: vec![PlayerId(0), PlayerId(2), PlayerId(4)],
+            public_log: vec!["Player 0: I trust 4.".into()],
+            knowledge: Default::default(),
+            claims: Vec::new(),
+            tokens_used: 0,
+            potions: None,
+            last_protected: None,
+            wolf_chat: Vec::new(),
+            graveyard: Vec::new(),
+            cost_so_far: 0.0,
+        }
+    }
+
+    #[test]
+    fn renders_simple_substitution() {
+        let t = PromptTemplate::new("Hello {name}!");
+        let vars = HashMap::from([("name", "Alice".to_string())]);
+        assert_eq!(t.render(&vars).unwrap(), "Hello Alice!");
+    }
+
+    #[test]
+    fn rendered_discussion_prompt_names_role_and_roster() {
+        let prompts = PromptSet::default();
+        let rendered = prompts.discussion.render(&context_vars(&ctx())).unwrap();
+        assert!(rendered.contains("Seer"));
+        assert!(rendered.contains("0, 2, 4"));
+        assert!(rendered.contains("I trust 4."));
+    }
+
+    #[test]
+    fn unknown_placeholder_is_an_error() {
+        let t = PromptTemplate::new("{role} and {nonsense}");
+        let err = t.render(&context_vars(&ctx())).unwrap_err();
+        assert_eq!(err, RenderError::UnknownPlaceholder("nonsense".into()));
+    }
+
+    #[test]
+    fn unclosed_brace_is_an_error() {
+        let t = PromptTemplate::new("broken {role");
+        assert!(matches!(
+            t.render(&context_vars(&ctx())),
+            Err(RenderError::UnclosedBrace(_))
+        ));
+    }
+}
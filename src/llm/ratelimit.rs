@@ -0,0 +1,202 @@
+//! Token-bucket rate limiting for provider calls.
+//!
+//! A tournament fans dozens of concurrent games out over one API key, and
+//! providers answer the resulting burst with HTTP 429s. A [`RateLimiter`]
+//! is shared via `Arc` across every player and game using the same key;
+//! each `complete` call awaits [`RateLimiter::acquire`] before dispatching,
+//! so the process as a whole never exceeds the configured requests per
+//! minute. Waiters queue on a fair (FIFO) lock, so concurrent games make
+//! progress in arrival order rather than starving each other.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::clock::{Clock, SystemClock};
+
+/// The refillable bucket behind a [`RateLimiter`].
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    last_refill: Duration,
+}
+
+/// An async token-bucket limiter: [`acquire`] waits for a token instead of
+/// erroring, so callers simply run slower when the budget is tight.
+///
+/// Tokens refill continuously at the configured requests-per-minute rate.
+/// The bucket holds at most `burst` tokens (1 by default, spacing requests
+/// evenly), so no 60-second window ever dispatches more than
+/// `per_minute + burst - 1` requests, and with the default burst exactly
+/// `per_minute`.
+///
+/// [`acquire`]: RateLimiter::acquire
+pub struct RateLimiter {
+    per_minute: f64,
+    burst: f64,
+    bucket: tokio::sync::Mutex<Bucket>,
+    clock: Arc<dyn Clock>,
+}
+
+impl std::fmt::Debug for RateLimiter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RateLimiter")
+            .field("per_minute", &self.per_minute)
+            .field("burst", &self.burst)
+            .finish_non_exhaustive()
+    }
+}
+
+impl RateLimiter {
+    /// A limiter allowing `per_minute` requests per minute, evenly spaced.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `per_minute` is zero — a limiter that never grants a
+    /// token would deadlock every caller.
+    pub fn per_minute(per_minute: u32) -> Self {
+        assert!(per_minute > 0, "a rate limit of 0 requests per minute admits nothing");
+        Self {
+            per_minute: f64::from(per_minute),
+            burst: 1.0,
+            bucket: tokio::sync::Mutex::new(Bucket {
+                tokens: 1.0,
+                last_refill: Duration::ZERO,
+            }),
+            clock: Arc::new(SystemClock::default()),
+        }
+    }
+
+    /// Allows up to `burst` requests to dispatch back-to-back when the
+    /// limiter has been idle, instead of the default even spacing.
+    pub fn with_burst(mut self, burst: u32) -> Self {
+        self.burst = f64::from(burst.max(1));
+        self.bucket.get_mut().tokens = self.burst;
+        self
+    }
+
+    /// Replaces the clock; tests use this to drive time by hand.
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.bucket.get_mut().last_refill = clock.now();
+        self.clock = clock;
+        self
+    }
+
+    /// Waits until a token is available and consumes it.
+    ///
+    /// Waiters queue on a fair lock, so under contention tokens are granted
+    /// in arrival order.
+    pub async fn acquire(&self) {
+        loop {
+            let wait = {
+                let mut bucket = self.bucket.lock().await;
+                let now = self.clock.now();
+                let elapsed = now.saturating_sub(bucket.last_refill);
+                bucket.tokens = (bucket.tokens
+                    + elapsed.as_secs_f64() * self.per_minute / 60.0)
+                    .min(self.burst);
+                bucket.last_refill = now;
+                if bucket.tokens >= 1.0 {
+                    bucket.tokens -= 1.0;
+                    return;
+                }
+                Duration::from_secs_f64((1.0 - bucket.tokens) * 60.0 / self.per_minute)
+            };
+            self.clock.sleep(wait).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use async_trait::async_trait;
+
+    use super::*;
+
+    /// A clock driven entirely by its own `sleep` calls: time stands still
+    /// until some waiter sleeps, then jumps forward by the slept amount.
+    /// Unlike [`crate::clock::MockClock`], nobody has to advance it — which
+    /// is what the join-heavy tests below need.
+    #[derive(Debug, Default)]
+    struct SleepDrivenClock {
+        now: Mutex<Duration>,
+    }
+
+    #[async_trait]
+    impl Clock for SleepDrivenClock {
+        fn now(&self) -> Duration {
+            *self.now.lock().unwrap()
+        }
+
+        async fn sleep(&self, duration: Duration) {
+            *self.now.lock().unwrap() += duration;
+        }
+    }
+
+    #[tokio::test]
+    async fn no_window_exceeds_the_configured_rate() {
+        let clock = Arc::new(SleepDrivenClock::default());
+        let limiter = RateLimiter::per_minute(6).with_clock(clock.clone());
+        let dispatched: Mutex<Vec<Duration>> = Mutex::new(Vec::new());
+
+        futures::future::join_all((0..18).map(|_| async {
+            limiter.acquire().await;
+            dispatched.lock().unwrap().push(clock.now());
+        }))
+        .await;
+
+        let times = dispatched.lock().unwrap();
+        assert_eq!(times.len(), 18);
+        let minute = Duration::from_secs(60);
+        for &start in times.iter() {
+            let in_window =
+                times.iter().filter(|&&t| t >= start && t < start + minute).count();
+            assert!(in_window <= 6, "{in_window} dispatches within one minute");
+        }
+    }
+
+    #[tokio::test]
+    async fn tokens_are_spaced_at_the_refill_interval() {
+        let clock = Arc::new(SleepDrivenClock::default());
+        let limiter = RateLimiter::per_minute(6).with_clock(clock.clone());
+        // The bucket starts with one token; each further acquire waits out
+        // one 10-second refill.
+        for expected_secs in [0, 10, 20, 30] {
+            limiter.acquire().await;
+            assert_eq!(clock.now(), Duration::from_secs(expected_secs));
+        }
+    }
+
+    #[tokio::test]
+    async fn burst_lets_idle_capacity_dispatch_back_to_back() {
+        let clock = Arc::new(SleepDrivenClock::default());
+        let limiter =
+            RateLimiter::per_minute(6).with_burst(3).with_clock(clock.clone());
+        for _ in 0..3 {
+            limiter.acquire().await;
+        }
+        assert_eq!(clock.now(), Duration::ZERO);
+        // The fourth call pays the full refill interval.
+        limiter.acquire().await;
+        assert_eq!(clock.now(), Duration::from_secs(10));
+    }
+
+    #[tokio::test]
+    async fn an_idle_limiter_does_not_bank_more_than_the_burst() {
+        let clock = Arc::new(SleepDrivenClock::default());
+        let limiter = RateLimiter::per_minute(6).with_clock(clock.clone());
+        // A long quiet spell must not accumulate a backlog of tokens.
+        clock.sleep(Duration::from_secs(600)).await;
+        limiter.acquire().await;
+        let after_idle = clock.now();
+        limiter.acquire().await;
+        assert_eq!(clock.now() - after_idle, Duration::from_secs(10));
+    }
+
+    #[test]
+    #[should_panic(expected = "rate limit of 0")]
+    fn a_zero_rate_is_rejected() {
+        let _ = RateLimiter::per_minute(0);
+    }
+}
@@ -0,0 +1,195 @@
+//! Retry with exponential backoff for flaky provider calls.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+
+use crate::clock::{Clock, SystemClock};
+use crate::llm::{ChatRequest, ChatResponse, LlmError, LlmProvider};
+
+/// How a failed call is retried.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RetryPolicy {
+    /// Additional attempts after the first call.
+    pub max_retries: u32,
+    /// Delay before the first retry; doubles on each subsequent one.
+    pub base_delay: Duration,
+    /// Fraction of the delay added as uniform random jitter (0.0 = none).
+    pub jitter: f64,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self { max_retries: 3, base_delay: Duration::from_millis(500), jitter: 0.2 }
+    }
+}
+
+impl RetryPolicy {
+    /// The backoff delay before retry number `attempt` (0-based), before
+    /// jitter is applied.
+    pub fn delay_for(&self, attempt: u32) -> Duration {
+        self.base_delay.saturating_mul(1u32 << attempt.min(16))
+    }
+}
+
+/// Whether this failure is worth retrying: rate limits, server errors, and
+/// network failures are transient; other 4xx responses mean the request
+/// itself is wrong and will keep failing.
+pub fn is_retryable(err: &LlmError) -> bool {
+    match err {
+        LlmError::Network(_) => true,
+        LlmError::Status { status, .. } => *status == 429 || (500..600).contains(status),
+        LlmError::MalformedResponse(_) => false,
+    }
+}
+
+/// Wraps any [`LlmProvider`] with a [`RetryPolicy`]. Backoff sleeps run
+/// on an injectable [`Clock`], so tests can count retries without
+/// actually waiting.
+pub struct RetryingProvider<P> {
+    inner: P,
+    policy: RetryPolicy,
+    clock: Arc<dyn Clock>,
+}
+
+impl<P: LlmProvider> RetryingProvider<P> {
+    pub fn new(inner: P, policy: RetryPolicy) -> Self {
+        Self { inner, policy, clock: Arc::new(SystemClock::default()) }
+    }
+
+    /// Replaces the clock; tests use this to avoid real delays.
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+}
+
+#[async_trait]
+impl<P: LlmProvider> LlmProvider for RetryingProvider<P> {
+    async fn complete(&self, req: ChatRequest) -> Result<ChatResponse, LlmError> {
+        let mut attempt = 0u32;
+        loop {
+            match self.inner.complete(req.clone()).await {
+                Ok(resp) => return Ok(resp),
+                Err(err) if is_retryable(&err) && attempt < self.policy.max_retries => {
+                    let mut delay = self.policy.delay_for(attempt);
+                    if self.policy.jitter > 0.0 {
+                        let extra = delay.as_secs_f64() * self.policy.jitter * rand::random::<f64>();
+                        delay += Duration::from_secs_f64(extra);
+                    }
+                    self.clock.sleep(delay).await;
+                    attempt += 1;
+                }
+                // Client errors, or retries exhausted: surface the last
+                // underlying error untouched.
+                Err(err) => return Err(err),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+
+    /// Fails with the scripted errors, then succeeds.
+    struct FlakyProvider {
+        failures: Mutex<Vec<LlmError>>,
+        calls: AtomicUsize,
+    }
+
+    impl FlakyProvider {
+        fn new(failures: Vec<LlmError>) -> Self {
+            Self { failures: Mutex::new(failures), calls: AtomicUsize::new(0) }
+        }
+
+        fn calls(&self) -> usize {
+            self.calls.load(Ordering::SeqCst)
+        }
+    }
+
+    #[async_trait]
+    impl LlmProvider for &FlakyProvider {
+        async fn complete(&self, _req: ChatRequest) -> Result<ChatResponse, LlmError> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            let mut failures = self.failures.lock().unwrap();
+            if failures.is_empty() {
+                Ok(ChatResponse { content: "ok".into(), usage: Default::default() })
+            } else {
+                Err(failures.remove(0))
+            }
+        }
+    }
+
+    /// Records requested delays instead of sleeping.
+    #[derive(Debug, Default)]
+    struct RecordingClock(Mutex<Vec<Duration>>);
+
+    #[async_trait]
+    impl Clock for RecordingClock {
+        fn now(&self) -> Duration {
+            self.0.lock().unwrap().iter().sum()
+        }
+
+        async fn sleep(&self, duration: Duration) {
+            self.0.lock().unwrap().push(duration);
+        }
+    }
+
+    fn policy() -> RetryPolicy {
+        RetryPolicy { max_retries: 3, base_delay: Duration::from_millis(10), jitter: 0.0 }
+    }
+
+    fn status(code: u16) -> LlmError {
+        LlmError::Status { status: code, body: String::new() }
+    }
+
+    #[tokio::test]
+    async fn retries_429_then_succeeds() {
+        let flaky = FlakyProvider::new(vec![status(429), status(503)]);
+        let clock = Arc::new(RecordingClock::default());
+        let provider =
+            RetryingProvider::new(&flaky, policy()).with_clock(clock.clone());
+        let resp = provider.complete(ChatRequest::new(vec![])).await.unwrap();
+        assert_eq!(resp.content, "ok");
+        assert_eq!(flaky.calls(), 3);
+        assert_eq!(
+            *clock.0.lock().unwrap(),
+            vec![Duration::from_millis(10), Duration::from_millis(20)]
+        );
+    }
+
+    #[tokio::test]
+    async fn client_errors_are_not_retried() {
+        let flaky = FlakyProvider::new(vec![status(400)]);
+        let provider = RetryingProvider::new(&flaky, policy())
+            .with_clock(Arc::new(RecordingClock::default()));
+        let err = provider.complete(ChatRequest::new(vec![])).await.unwrap_err();
+        assert!(matches!(err, LlmError::Status { status: 400, .. }));
+        assert_eq!(flaky.calls(), 1);
+    }
+
+    #[tokio::test]
+    async fn exhausted_retries_preserve_the_last_error() {
+        let flaky =
+            FlakyProvider::new(vec![status(500), status(502), status(503), status(504)]);
+        let provider = RetryingProvider::new(&flaky, policy())
+            .with_clock(Arc::new(RecordingClock::default()));
+        let err = provider.complete(ChatRequest::new(vec![])).await.unwrap_err();
+        assert!(matches!(err, LlmError::Status { status: 504, .. }));
+        assert_eq!(flaky.calls(), 4);
+    }
+
+    #[tokio::test]
+    async fn network_errors_are_retried() {
+        let flaky = FlakyProvider::new(vec![LlmError::Network("reset".into())]);
+        let provider = RetryingProvider::new(&flaky, policy())
+            .with_clock(Arc::new(RecordingClock::default()));
+        assert!(provider.complete(ChatRequest::new(vec![])).await.is_ok());
+        assert_eq!(flaky.calls(), 2);
+    }
+}
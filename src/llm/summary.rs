@@ -0,0 +1,273 @@
+//! Transcript compression so prompts don't outgrow the model's context.
+//!
+//! A [`Summarizer`] turns a slice of finished-day events into a short
+//! paragraph. The driver runs it once per day rollover and caches the
+//! result on the [`GameState`] (see [`summarize_prior_day`]), so the
+//! compression cost is paid once, not once per player.
+//!
+//! [`GameState`]: crate::game::state::GameState
+
+use async_trait::async_trait;
+
+use crate::game::day::estimate_tokens;
+use crate::game::event::{GameEvent, GameEventKind};
+use crate::game::state::GameState;
+use crate::llm::{ChatMessage, ChatRequest, LlmProvider};
+
+/// Compresses a day's worth of events into a short summary string.
+#[async_trait]
+pub trait Summarizer: Send + Sync {
+    async fn summarize(&self, events: &[GameEvent]) -> String;
+}
+
+/// When a day's transcript is long enough to be worth summarizing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SummaryThreshold {
+    /// Summarize once the day produced at least this many public events.
+    Events(usize),
+    /// Summarize once the day's rendered transcript reaches this many
+    /// estimated tokens.
+    EstimatedTokens(u32),
+}
+
+impl Default for SummaryThreshold {
+    fn default() -> Self {
+        Self::EstimatedTokens(500)
+    }
+}
+
+impl SummaryThreshold {
+    /// Whether `events` is long enough to trigger summarization.
+    pub fn exceeded(&self, events: &[GameEvent]) -> bool {
+        let lines: Vec<String> = events.iter().filter_map(event_line).collect();
+        match self {
+            Self::Events(n) => lines.len() >= *n,
+            Self::EstimatedTokens(n) => {
+                lines.iter().map(|l| estimate_tokens(l)).sum::<u32>() >= *n
+            }
+        }
+    }
+}
+
+/// Renders one event as a public transcript line.
+///
+/// Secret events ([`GameEventKind::NightAction`]) and engine bookkeeping
+/// are omitted: the summary is injected into every player's context, so it
+/// must contain only common knowledge.
+fn event_line(event: &GameEvent) -> Option<String> {
+    match &event.kind {
+        GameEventKind::PlayerSpoke { player, text } => {
+            Some(format!("Player {player}: {text}"))
+        }
+        GameEventKind::VoteCast { voter, target: Some(target), .. } => {
+            Some(format!("Player {voter} voted for player {target}."))
+        }
+        GameEventKind::VoteCast { voter, target: None, .. } => {
+            Some(format!("Player {voter} abstained."))
+        }
+        GameEventKind::PlayerDied { player, cause, .. } => {
+            Some(format!("Player {player} died ({cause:?})."))
+        }
+        GameEventKind::HunterShot { hunter, target } => {
+            Some(format!("Player {hunter} shot player {target}."))
+        }
+        GameEventKind::Accusation { accuser, accused, text } => {
+            Some(format!("Player {accuser} accused player {accused}: {text}"))
+        }
+        GameEventKind::Defense { player, text } => {
+            Some(format!("Player {player} defended: {text}"))
+        }
+        GameEventKind::LastWords { player, text } => {
+            Some(format!("Player {player}'s last words: {text}"))
+        }
+        GameEventKind::GameEnded { winner: Some(winner) } => {
+            Some(format!("The game ended: {winner:?} won."))
+        }
+        GameEventKind::GameEnded { winner: None } => {
+            Some("The game ended in a draw.".to_string())
+        }
+        GameEventKind::PhaseChanged { .. }
+        | GameEventKind::NightAction { .. }
+        | GameEventKind::InvalidAction { .. }
+        | GameEventKind::SpeakingOrder { .. }
+        | GameEventKind::BudgetExceeded { .. }
+        | GameEventKind::DiscussionEnded { .. }
+        | GameEventKind::GraveyardChat { .. }
+        | GameEventKind::WolfDeadlock { .. }
+        | GameEventKind::PlayerModel { .. }
+        | GameEventKind::FallbackTriggered { .. }
+        | GameEventKind::PlayerRefused { .. } => None,
+    }
+}
+
+/// A [`Summarizer`] that asks a model — typically a cheaper one than the
+/// players use — to compress the transcript into a few sentences.
+pub struct LlmSummarizer<P> {
+    provider: P,
+    max_tokens: u32,
+}
+
+impl<P: LlmProvider> LlmSummarizer<P> {
+    pub fn new(provider: P) -> Self {
+        Self { provider, max_tokens: 200 }
+    }
+
+    /// Caps the summary length requested from the model.
+    pub fn with_max_tokens(mut self, max_tokens: u32) -> Self {
+        self.max_tokens = max_tokens;
+        self
+    }
+}
+
+#[async_trait]
+impl<P: LlmProvider> Summarizer for LlmSummarizer<P> {
+    async fn summarize(&self, events: &[GameEvent]) -> String {
+        let lines: Vec<String> = events.iter().filter_map(event_line).collect();
+        if lines.is_empty() {
+            return String::new();
+        }
+        let mut req = ChatRequest::new(vec![
+            ChatMessage::system(
+                "You compress Werewolf game transcripts. Reply with a few plain \
+                 sentences covering who was accused, who died and how, and any \
+                 public role claims. No preamble.",
+            ),
+            ChatMessage::user(lines.join("\n")),
+        ]);
+        req.max_tokens = Some(self.max_tokens);
+        match self.provider.complete(req).await {
+            Ok(resp) => resp.content,
+            // A failed summary must not stall the game; degrade to
+            // truncation instead.
+            Err(_) => TruncatingSummarizer::default().summarize(events).await,
+        }
+    }
+}
+
+/// A model-free [`Summarizer`] that keeps the last `keep_last` public
+/// events verbatim. Suitable for offline runs and tests.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TruncatingSummarizer {
+    pub keep_last: usize,
+}
+
+impl Default for TruncatingSummarizer {
+    fn default() -> Self {
+        Self { keep_last: 10 }
+    }
+}
+
+#[async_trait]
+impl Summarizer for TruncatingSummarizer {
+    async fn summarize(&self, events: &[GameEvent]) -> String {
+        let lines: Vec<String> = events.iter().filter_map(event_line).collect();
+        let skipped = lines.len().saturating_sub(self.keep_last);
+        let mut out = Vec::new();
+        if skipped > 0 {
+            out.push(format!("({skipped} earlier entries omitted)"));
+        }
+        out.extend(lines.into_iter().skip(skipped));
+        out.join("\n")
+    }
+}
+
+/// Summarizes the just-finished `day`'s events and caches the result on
+/// the state, where [`GameState::context_for`] will use it in place of the
+/// bare statement/death counts.
+///
+/// Days below the `threshold` are left to the default counted summary.
+/// Call this once after each day rollover, not per player.
+pub async fn summarize_prior_day(
+    state: &mut GameState,
+    summarizer: &dyn Summarizer,
+    day: u32,
+    threshold: SummaryThreshold,
+) {
+    if state.day_summary(day).is_some() {
+        return;
+    }
+    let events: Vec<GameEvent> =
+        state.log().iter().filter(|e| e.day == day).cloned().collect();
+    if !threshold.exceeded(&events) {
+        return;
+    }
+    let summary = summarizer.summarize(&events).await;
+    if !summary.is_empty() {
+        state.set_day_summary(day, summary);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::night::DeathCause;
+    use crate::game::state::{Phase, PlayerId};
+
+    fn spoke(day: u32, player: PlayerId, text: &str) -> GameEvent {
+        GameEvent::now(day, GameEventKind::PlayerSpoke { player, text: text.into() })
+    }
+
+    #[tokio::test]
+    async fn truncating_summarizer_keeps_the_tail() {
+        let events: Vec<GameEvent> =
+            (0..5).map(|i| spoke(1, PlayerId(i), &format!("line {i}"))).collect();
+        let summary = TruncatingSummarizer { keep_last: 2 }.summarize(&events).await;
+        assert_eq!(summary, "(3 earlier entries omitted)\nPlayer 3: line 3\nPlayer 4: line 4");
+    }
+
+    #[tokio::test]
+    async fn night_actions_never_leak_into_summaries() {
+        let events = vec![
+            spoke(1, PlayerId(0), "hello"),
+            GameEvent::now(1, GameEventKind::NightAction {
+                actor: PlayerId(2),
+                action: crate::game::Action::Kill(PlayerId(0)),
+            }),
+        ];
+        let summary = TruncatingSummarizer::default().summarize(&events).await;
+        assert!(!summary.contains("Kill"));
+        assert!(summary.contains("hello"));
+    }
+
+    #[test]
+    fn thresholds_gate_on_events_or_tokens() {
+        let events: Vec<GameEvent> =
+            (0..4).map(|i| spoke(1, PlayerId(i), "a statement of some length")).collect();
+        assert!(SummaryThreshold::Events(4).exceeded(&events));
+        assert!(!SummaryThreshold::Events(5).exceeded(&events));
+        assert!(SummaryThreshold::EstimatedTokens(10).exceeded(&events));
+        assert!(!SummaryThreshold::EstimatedTokens(10_000).exceeded(&events));
+    }
+
+    #[tokio::test]
+    async fn cached_summary_feeds_later_contexts() {
+        let mut state = GameState::new(0..4, Phase::Night, 0);
+        state.assign_role(PlayerId(0), crate::roles::Role::Werewolf);
+        for id in 1..4 {
+            state.assign_role(PlayerId(id), crate::roles::Role::Villager);
+        }
+        state.advance(); // Day 1
+        state.record(GameEventKind::PlayerSpoke { player: PlayerId(0), text: "I saw nothing.".into() });
+        state.record(GameEventKind::PlayerDied {
+            player: PlayerId(3),
+            cause: DeathCause::Vote,
+            role: None,
+            alignment: None,
+        });
+        state.advance(); // Voting
+        state.advance(); // Night
+        state.advance(); // Day 2
+
+        summarize_prior_day(&mut state, &TruncatingSummarizer::default(), 1,
+            SummaryThreshold::Events(1))
+        .await;
+
+        let ctx = state.context_for(PlayerId(0));
+        assert!(ctx.public_log.iter().any(|l| l.contains("I saw nothing.")));
+        // Second call is a no-op thanks to the cache.
+        summarize_prior_day(&mut state, &TruncatingSummarizer { keep_last: 0 }, 1,
+            SummaryThreshold::Events(1))
+        .await;
+        assert!(state.day_summary(1).unwrap().contains("I saw nothing."));
+    }
+}
@@ -1,45 +1,181 @@
-/// Main function that demonstrates the add function
-pub fn main() {
-    // Display version information
-    println!("llmwerewolf-rs v{}", llmwerewolf_rs::version());
-    println!(
-        "Built with Rust {} and Cargo {}",
-        llmwerewolf_rs::rust_version(),
-        llmwerewolf_rs::cargo_version()
-    );
-    println!();
-
-    let a = 2;
-    let b = 3;
-    let result = llmwerewolf_rs::calculate_and_display(a, b);
-    println!("{result}");
+//! The `llmwerewolf` command line: run a configured game with LLM players,
+//! or narrate a saved event log.
+
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::sync::Arc;
+
+use clap::{Parser, Subcommand};
+
+use llmwerewolf_rs::config::GameConfig;
+use llmwerewolf_rs::game::{
+    GameBuilder, GameEvent, GameEventKind, GameObserver, PlayerId, run_game_observed,
+};
+use llmwerewolf_rs::llm::{HealthCheckFailure, LlmProvider, OpenAiProvider};
+use llmwerewolf_rs::narrate::Narrator;
+use llmwerewolf_rs::player::LlmPlayer;
+use llmwerewolf_rs::roles::Alignment;
+
+/// Exit codes: 0 the town won, 1 the wolves won, 2 a neutral party won,
+/// 3 the game ended with no winner (a draw at the day cap, or the step
+/// ceiling fired).
+#[derive(Parser)]
+#[command(name = "llmwerewolf", version = llmwerewolf_rs::version(), about)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Runs a game from a TOML config with LLM players, streaming
+    /// narration to the terminal.
+    ///
+    /// Providers are configured from the environment: `OPENAI_API_KEY`
+    /// (required), `OPENAI_BASE_URL` (any OpenAI-compatible gateway;
+    /// defaults to api.openai.com), and `LLMWEREWOLF_MODEL`.
+    Run {
+        /// Path to the game config (TOML).
+        #[arg(long)]
+        config: PathBuf,
+        /// Seed for role assignment and tie-breaks; random if omitted.
+        #[arg(long)]
+        seed: Option<u64>,
+        /// Also dump the full event log as JSON to this path.
+        #[arg(long)]
+        log: Option<PathBuf>,
+    },
+    /// Narrates a saved event log (as dumped by `run --log`) without
+    /// making any LLM calls.
+    Replay {
+        /// Path to the event log (JSON).
+        input: PathBuf,
+    },
+}
+
+/// Prints each event's narration line as soon as it is recorded.
+struct NarratingObserver {
+    narrator: Narrator,
+}
+
+impl GameObserver for NarratingObserver {
+    fn on_event(&self, event: &GameEvent) {
+        if let Some(line) = self.narrator.narrate_event(event) {
+            println!("{line}");
+        }
+    }
+}
+
+/// Maps the winning alignment onto the documented exit codes.
+fn exit_for(winner: Option<Alignment>) -> ExitCode {
+    match winner {
+        Some(Alignment::Town) => ExitCode::from(0),
+        Some(Alignment::Wolf) => ExitCode::from(1),
+        Some(Alignment::Neutral) => ExitCode::from(2),
+        None => ExitCode::from(3),
+    }
+}
+
+async fn run(
+    config: PathBuf,
+    seed: Option<u64>,
+    log: Option<PathBuf>,
+) -> Result<ExitCode, Box<dyn std::error::Error>> {
+    let source = std::fs::read_to_string(&config)?;
+    let config = GameConfig::from_toml(&source)?;
+    config.validate()?;
+
+    let api_key = std::env::var("OPENAI_API_KEY")
+        .map_err(|_| "OPENAI_API_KEY is not set; LLM players need a provider")?;
+    let base_url = std::env::var("OPENAI_BASE_URL")
+        .unwrap_or_else(|_| "https://api.openai.com".into());
+    let model =
+        std::env::var("LLMWEREWOLF_MODEL").unwrap_or_else(|_| "gpt-4o-mini".into());
+    let provider = Arc::new(OpenAiProvider::new(api_key, base_url, model.clone()));
+    // Fail fast on a bad key or endpoint before any game state exists.
+    provider
+        .health_check()
+        .await
+        .map_err(|source| HealthCheckFailure { provider: model.clone(), source })?;
+
+    let mut builder = GameBuilder::new()
+        .config(config.clone())
+        .seed(seed.unwrap_or_else(rand::random));
+    for id in 0..config.player_count as u32 {
+        builder =
+            builder.player(PlayerId(id), Box::new(LlmPlayer::new(model.clone(), provider.clone())));
+    }
+    let (state, players) = builder.build_with_players()?;
+
+    let observer = NarratingObserver { narrator: Narrator::new().full().ansi() };
+    let result = run_game_observed(state, players, &config, &[&observer]).await?;
+
+    if let Some(path) = log {
+        serde_json::to_writer_pretty(std::fs::File::create(path)?, &result.log)?;
+    }
+    Ok(exit_for(result.winner))
+}
+
+fn replay(input: PathBuf) -> Result<ExitCode, Box<dyn std::error::Error>> {
+    let source = std::fs::read_to_string(&input)?;
+    let events: Vec<GameEvent> = serde_json::from_str(&source)?;
+    let text = Narrator::new().full().ansi().narrate(&events);
+    if !text.is_empty() {
+        println!("{text}");
+    }
+    let winner = events
+        .iter()
+        .rev()
+        .find_map(|e| match e.kind {
+            GameEventKind::GameEnded { winner } => Some(winner),
+            _ => None,
+        })
+        .flatten();
+    Ok(exit_for(winner))
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let outcome = match cli.command {
+        Command::Run { config, seed, log } => run(config, seed, log).await,
+        Command::Replay { input } => replay(input),
+    };
+    match outcome {
+        Ok(code) => code,
+        Err(e) => {
+            eprintln!("llmwerewolf: {e}");
+            ExitCode::FAILURE
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use super::*;
 
     #[test]
-    fn test_main_functionality() {
-        // Test that main function logic works correctly
-        let a = 2;
-        let b = 3;
-        let result = llmwerewolf_rs::calculate_and_display(a, b);
-        assert_eq!(result, "2 + 3 = 5");
+    fn cli_definition_is_consistent() {
+        use clap::CommandFactory;
+        Cli::command().debug_assert();
     }
 
     #[test]
-    fn test_main_with_different_values() {
-        let a = 10;
-        let b = 20;
-        let result = llmwerewolf_rs::calculate_and_display(a, b);
-        assert_eq!(result, "10 + 20 = 30");
+    fn exit_codes_reflect_the_winner() {
+        assert_eq!(exit_for(Some(Alignment::Town)), ExitCode::from(0));
+        assert_eq!(exit_for(Some(Alignment::Wolf)), ExitCode::from(1));
+        assert_eq!(exit_for(Some(Alignment::Neutral)), ExitCode::from(2));
+        assert_eq!(exit_for(None), ExitCode::from(3));
     }
 
     #[test]
-    fn test_main_with_negative_values() {
-        let a = -5;
-        let b = 3;
-        let result = llmwerewolf_rs::calculate_and_display(a, b);
-        assert_eq!(result, "-5 + 3 = -2");
+    fn replay_reads_a_dumped_log() {
+        let events = vec![GameEvent::now(3, GameEventKind::GameEnded {
+            winner: Some(Alignment::Wolf),
+        })];
+        let path = std::env::temp_dir().join("llmwerewolf-replay-test.json");
+        std::fs::write(&path, serde_json::to_string(&events).unwrap()).unwrap();
+        assert_eq!(replay(path.clone()).unwrap(), ExitCode::from(1));
+        std::fs::remove_file(path).ok();
     }
 }
@@ -0,0 +1,437 @@
+//! Post-game skill metrics computed from the event log.
+//!
+//! These measure how well the models actually played: did the wolves stay
+//! hidden, did town votes land on wolves, did the Seer act on what she
+//! learned. All of it needs the hidden role map, so metrics are computed
+//! God-side from the full log, never from a redacted view.
+
+use std::collections::{BTreeMap, HashMap};
+
+use serde::{Deserialize, Serialize};
+
+use crate::game::event::{GameEvent, GameEventKind};
+use crate::game::state::PlayerId;
+use crate::roles::{Alignment, Role};
+
+/// The hidden role assignment of a finished game.
+pub type RoleMap = HashMap<PlayerId, Role>;
+
+/// Per-game skill metrics, serializable for aggregation across a
+/// tournament.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GameMetrics {
+    /// The winning side, if the log records a game end.
+    pub winner: Option<Alignment>,
+    /// The last day the log reaches.
+    pub days: u32,
+    /// Per wolf: whether they were still alive when the game ended.
+    pub wolf_survival: BTreeMap<PlayerId, bool>,
+    /// Per town player who voted at least once: the fraction of their
+    /// votes cast at actual wolves. Abstentions don't count either way.
+    pub town_vote_accuracy: BTreeMap<PlayerId, f64>,
+    /// Per Seer: the fraction of investigations that found a wolf.
+    pub seer_wolf_find_rate: BTreeMap<PlayerId, f64>,
+    /// Per Seer: of the investigations that found a wolf, the fraction
+    /// followed by the Seer's next vote targeting that wolf.
+    pub seer_followup_rate: BTreeMap<PlayerId, f64>,
+    /// Every seat's hidden alignment, so cross-game aggregation can split
+    /// results by side without re-reading the logs.
+    #[serde(default)]
+    pub alignments: BTreeMap<PlayerId, Alignment>,
+    /// Per seat: whether they were still alive when the game ended.
+    #[serde(default)]
+    pub survival: BTreeMap<PlayerId, bool>,
+}
+
+/// Computes metrics from a finished game's log and its hidden role map.
+pub fn compute_metrics(log: &[GameEvent], roles: &RoleMap) -> GameMetrics {
+    let is_wolf = |id: PlayerId| {
+        roles.get(&id).map(|r| r.alignment() == Alignment::Wolf).unwrap_or(false)
+    };
+
+    let mut winner = None;
+    let mut days = 0;
+    let mut dead: Vec<PlayerId> = Vec::new();
+    // Per town player: (votes at wolves, votes total).
+    let mut votes: BTreeMap<PlayerId, (usize, usize)> = BTreeMap::new();
+    // Per seer: (wolf finds, investigations).
+    let mut investigations: BTreeMap<PlayerId, (usize, usize)> = BTreeMap::new();
+    // Per seer: (followed up, wolf finds).
+    let mut followups: BTreeMap<PlayerId, (usize, usize)> = BTreeMap::new();
+
+    for (index, event) in log.iter().enumerate() {
+        days = days.max(event.day);
+        match &event.kind {
+            GameEventKind::GameEnded { winner: w } => winner = *w,
+            GameEventKind::PlayerDied { player, .. } => dead.push(*player),
+            GameEventKind::VoteCast { voter, target: Some(target), .. }
+                if roles.get(voter).map(|r| r.alignment()) == Some(Alignment::Town) =>
+            {
+                let entry = votes.entry(*voter).or_default();
+                entry.1 += 1;
+                if is_wolf(*target) {
+                    entry.0 += 1;
+                }
+            }
+            GameEventKind::NightAction { actor, action } => {
+                if roles.get(actor) == Some(&Role::Seer)
+                    && let crate::game::Action::Investigate(target) = action
+                {
+                    let entry = investigations.entry(*actor).or_default();
+                    entry.1 += 1;
+                    if is_wolf(*target) {
+                        entry.0 += 1;
+                        let followup = followups.entry(*actor).or_default();
+                        followup.1 += 1;
+                        if next_vote_of(&log[index + 1..], *actor) == Some(*target) {
+                            followup.0 += 1;
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let wolf_survival = roles
+        .iter()
+        .filter(|&(_, role)| role.alignment() == Alignment::Wolf)
+        .map(|(id, _)| (*id, !dead.contains(id)))
+        .collect();
+
+    GameMetrics {
+        winner,
+        days,
+        wolf_survival,
+        town_vote_accuracy: ratios(&votes),
+        seer_wolf_find_rate: ratios(&investigations),
+        seer_followup_rate: ratios(&followups),
+        alignments: roles.iter().map(|(id, role)| (*id, role.alignment())).collect(),
+        survival: roles.keys().map(|id| (*id, !dead.contains(id))).collect(),
+    }
+}
+
+fn ratios(counts: &BTreeMap<PlayerId, (usize, usize)>) -> BTreeMap<PlayerId, f64> {
+    counts
+        .iter()
+        .filter(|&(_, &(_, total))| total > 0)
+        .map(|(id, &(hits, total))| (*id, hits as f64 / total as f64))
+        .collect()
+}
+
+/// The target of `voter`'s next non-abstaining vote in `rest`, if any.
+fn next_vote_of(rest: &[GameEvent], voter: PlayerId) -> Option<PlayerId> {
+    rest.iter().find_map(|e| match e.kind {
+        GameEventKind::VoteCast { voter: v, target, .. } if v == voter => target,
+        _ => None,
+    })
+}
+
+/// An opaque model identifier, as carried by the per-player model tags.
+pub type ModelId = String;
+
+/// Ratings start here; a model that only draws stays here.
+const ELO_START: f64 = 1000.0;
+/// Per-game rating swing at maximal surprise.
+const ELO_K: f64 = 32.0;
+
+/// One model's aggregated line in a [`Leaderboard`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ModelStanding {
+    pub model: ModelId,
+    /// Seat-games played; a model fielding three seats in one game counts
+    /// three times.
+    pub games: usize,
+    pub town_games: usize,
+    pub town_wins: usize,
+    pub wolf_games: usize,
+    pub wolf_wins: usize,
+    /// Fraction of seat-games where the model's seat was still alive at
+    /// the end.
+    pub survival_rate: f64,
+    /// Elo-style rating, updated game by game from [`ELO_START`].
+    pub rating: f64,
+}
+
+impl ModelStanding {
+    /// Win rate over town seat-games, or `None` if the model never
+    /// played town.
+    pub fn town_win_rate(&self) -> Option<f64> {
+        (self.town_games > 0).then(|| self.town_wins as f64 / self.town_games as f64)
+    }
+
+    /// Win rate over wolf seat-games, or `None` if the model never
+    /// played wolf.
+    pub fn wolf_win_rate(&self) -> Option<f64> {
+        (self.wolf_games > 0).then(|| self.wolf_wins as f64 / self.wolf_games as f64)
+    }
+}
+
+/// A cross-game model ranking, sorted by rating, best first.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct Leaderboard {
+    pub standings: Vec<ModelStanding>,
+}
+
+#[derive(Default)]
+struct StandingAccumulator {
+    games: usize,
+    town_games: usize,
+    town_wins: usize,
+    wolf_games: usize,
+    wolf_wins: usize,
+    survived: usize,
+    rating: f64,
+}
+
+/// Aggregates per-game metrics into a per-model ranking.
+///
+/// `model_of` maps each seat to the model that played it — the same
+/// per-player tags the [`PlayerModel`] events carry. Win rates are split
+/// by side, since wolf games are structurally easier to win than town
+/// games. The Elo update treats each game as town-team versus wolf-team:
+/// both sides enter at their seats' mean rating, and every seat on a side
+/// moves by the same delta. A game without a recorded winner counts as a
+/// draw.
+///
+/// [`PlayerModel`]: crate::game::event::GameEventKind::PlayerModel
+pub fn leaderboard(stats: &[GameMetrics], model_of: impl Fn(PlayerId) -> ModelId) -> Leaderboard {
+    let mut accumulators: BTreeMap<ModelId, StandingAccumulator> = BTreeMap::new();
+    let rating_of = |accumulators: &BTreeMap<ModelId, StandingAccumulator>, model: &str| {
+        accumulators.get(model).map(|a| a.rating).unwrap_or(ELO_START)
+    };
+
+    for game in stats {
+        let seats: Vec<(PlayerId, Alignment, ModelId)> = game
+            .alignments
+            .iter()
+            .map(|(&id, &alignment)| (id, alignment, model_of(id)))
+            .collect();
+
+        // Pre-game side strengths, before any of this game's updates.
+        let side_rating = |side: Alignment| {
+            let ratings: Vec<f64> = seats
+                .iter()
+                .filter(|(_, alignment, _)| *alignment == side)
+                .map(|(_, _, model)| rating_of(&accumulators, model))
+                .collect();
+            (!ratings.is_empty())
+                .then(|| ratings.iter().sum::<f64>() / ratings.len() as f64)
+        };
+        let expected_town = side_rating(Alignment::Town).zip(side_rating(Alignment::Wolf)).map(
+            |(town, wolf)| 1.0 / (1.0 + 10f64.powf((wolf - town) / 400.0)),
+        );
+        let town_score = match game.winner {
+            Some(Alignment::Town) => 1.0,
+            Some(Alignment::Wolf) => 0.0,
+            _ => 0.5,
+        };
+
+        for (id, alignment, model) in seats {
+            let entry = accumulators.entry(model).or_insert_with(|| StandingAccumulator {
+                rating: ELO_START,
+                ..Default::default()
+            });
+            entry.games += 1;
+            if game.survival.get(&id).copied().unwrap_or(false) {
+                entry.survived += 1;
+            }
+            let won = game.winner == Some(alignment);
+            match alignment {
+                Alignment::Town => {
+                    entry.town_games += 1;
+                    entry.town_wins += usize::from(won);
+                }
+                Alignment::Wolf => {
+                    entry.wolf_games += 1;
+                    entry.wolf_wins += usize::from(won);
+                }
+                Alignment::Neutral => {}
+            }
+            if let Some(expected) = expected_town {
+                entry.rating += match alignment {
+                    Alignment::Town => ELO_K * (town_score - expected),
+                    Alignment::Wolf => -(ELO_K * (town_score - expected)),
+                    Alignment::Neutral => 0.0,
+                };
+            }
+        }
+    }
+
+    let mut standings: Vec<ModelStanding> = accumulators
+        .into_iter()
+        .map(|(model, a)| ModelStanding {
+            model,
+            games: a.games,
+            town_games: a.town_games,
+            town_wins: a.town_wins,
+            wolf_games: a.wolf_games,
+            wolf_wins: a.wolf_wins,
+            survival_rate: if a.games > 0 { a.survived as f64 / a.games as f64 } else { 0.0 },
+            rating: a.rating,
+        })
+        .collect();
+    standings.sort_by(|a, b| {
+        b.rating.total_cmp(&a.rating).then_with(|| a.model.cmp(&b.model))
+    });
+    Leaderboard { standings }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::Action;
+    use crate::game::night::DeathCause;
+
+    /// 0/1: wolves, 2: Seer, 3/4: villagers.
+    fn roles() -> RoleMap {
+        HashMap::from([
+            (PlayerId(0), Role::Werewolf),
+            (PlayerId(1), Role::Werewolf),
+            (PlayerId(2), Role::Seer),
+            (PlayerId(3), Role::Villager),
+            (PlayerId(4), Role::Villager),
+        ])
+    }
+
+    fn vote(day: u32, voter: PlayerId, target: Option<PlayerId>) -> GameEvent {
+        GameEvent::now(day, GameEventKind::VoteCast { voter, target, reason: None })
+    }
+
+    #[test]
+    fn wolf_survival_tracks_deaths() {
+        let log = vec![
+            GameEvent::now(1, GameEventKind::PlayerDied {
+                player: PlayerId(0),
+                cause: DeathCause::Vote,
+                role: None,
+                alignment: None,
+            }),
+            GameEvent::now(2, GameEventKind::GameEnded { winner: Some(Alignment::Town) }),
+        ];
+        let metrics = compute_metrics(&log, &roles());
+        assert_eq!(metrics.wolf_survival, BTreeMap::from([(PlayerId(0), false), (PlayerId(1), true)]));
+        assert_eq!(metrics.winner, Some(Alignment::Town));
+        assert_eq!(metrics.days, 2);
+    }
+
+    #[test]
+    fn town_vote_accuracy_counts_only_town_votes_at_wolves() {
+        let log = vec![
+            vote(1, PlayerId(3), Some(PlayerId(0))), // villager hits a wolf
+            vote(1, PlayerId(3), Some(PlayerId(4))), // villager hits town
+            vote(1, PlayerId(0), Some(PlayerId(3))), // wolf votes don't enter the metric
+            vote(2, PlayerId(4), None),    // abstention doesn't count either way
+        ];
+        let metrics = compute_metrics(&log, &roles());
+        assert_eq!(metrics.town_vote_accuracy.get(&PlayerId(3)), Some(&0.5));
+        assert_eq!(metrics.town_vote_accuracy.get(&PlayerId(4)), None);
+        assert_eq!(metrics.town_vote_accuracy.get(&PlayerId(0)), None);
+    }
+
+    #[test]
+    fn seer_find_and_followup_rates() {
+        let log = vec![
+            GameEvent::now(1, GameEventKind::NightAction {
+                actor: PlayerId(2),
+                action: Action::Investigate(PlayerId(0)),
+            }),
+            vote(1, PlayerId(2), Some(PlayerId(0))), // follows up on the wolf she found
+            GameEvent::now(2, GameEventKind::NightAction {
+                actor: PlayerId(2),
+                action: Action::Investigate(PlayerId(3)),
+            }),
+            GameEvent::now(3, GameEventKind::NightAction {
+                actor: PlayerId(2),
+                action: Action::Investigate(PlayerId(1)),
+            }),
+            vote(3, PlayerId(2), Some(PlayerId(4))), // found a wolf but votes elsewhere
+        ];
+        let metrics = compute_metrics(&log, &roles());
+        assert_eq!(metrics.seer_wolf_find_rate.get(&PlayerId(2)), Some(&(2.0 / 3.0)));
+        assert_eq!(metrics.seer_followup_rate.get(&PlayerId(2)), Some(&0.5));
+    }
+
+    #[test]
+    fn metrics_serialize_for_aggregation() {
+        let metrics = compute_metrics(&[], &roles());
+        let json = serde_json::to_string(&metrics).unwrap();
+        assert!(json.contains("town_vote_accuracy"));
+    }
+
+    #[test]
+    fn metrics_record_every_seat_alignment_and_survival() {
+        let log = vec![GameEvent::now(1, GameEventKind::PlayerDied {
+            player: PlayerId(3),
+            cause: DeathCause::WolfKill,
+            role: None,
+            alignment: None,
+        })];
+        let metrics = compute_metrics(&log, &roles());
+        assert_eq!(metrics.alignments.len(), 5);
+        assert_eq!(metrics.alignments.get(&PlayerId(2)), Some(&Alignment::Town));
+        assert_eq!(metrics.survival.get(&PlayerId(3)), Some(&false));
+        assert_eq!(metrics.survival.get(&PlayerId(4)), Some(&true));
+    }
+
+    /// A finished game as the leaderboard sees it: seats 0/1 are wolves,
+    /// 2–4 are town, everyone on the winning side survives.
+    fn synthetic_game(winner: Option<Alignment>) -> GameMetrics {
+        let alignments: BTreeMap<PlayerId, Alignment> =
+            roles().iter().map(|(id, role)| (*id, role.alignment())).collect();
+        let survival = alignments
+            .iter()
+            .map(|(id, alignment)| (*id, winner == Some(*alignment)))
+            .collect();
+        GameMetrics {
+            winner,
+            days: 3,
+            wolf_survival: BTreeMap::new(),
+            town_vote_accuracy: BTreeMap::new(),
+            seer_wolf_find_rate: BTreeMap::new(),
+            seer_followup_rate: BTreeMap::new(),
+            alignments,
+            survival,
+        }
+    }
+
+    #[test]
+    fn the_winning_model_ranks_first() {
+        // "alpha" plays the wolf seats, "beta" the town seats; town wins
+        // three games out of four.
+        let stats = vec![
+            synthetic_game(Some(Alignment::Town)),
+            synthetic_game(Some(Alignment::Town)),
+            synthetic_game(Some(Alignment::Wolf)),
+            synthetic_game(Some(Alignment::Town)),
+        ];
+        let board = leaderboard(&stats, |id| {
+            if id.0 < 2 { "alpha".to_string() } else { "beta".to_string() }
+        });
+        assert_eq!(board.standings[0].model, "beta");
+        assert!(board.standings[0].rating > board.standings[1].rating);
+        assert_eq!(board.standings[0].town_win_rate(), Some(0.75));
+        assert_eq!(board.standings[0].wolf_win_rate(), None);
+        assert_eq!(board.standings[1].wolf_win_rate(), Some(0.25));
+        assert_eq!(board.standings[0].survival_rate, 0.75);
+    }
+
+    #[test]
+    fn a_drawn_game_moves_no_ratings() {
+        let board = leaderboard(&[synthetic_game(None)], |id| {
+            if id.0 < 2 { "alpha".to_string() } else { "beta".to_string() }
+        });
+        for standing in &board.standings {
+            assert_eq!(standing.rating, 1000.0);
+            assert_eq!(standing.games, if standing.model == "beta" { 3 } else { 2 });
+        }
+    }
+
+    #[test]
+    fn leaderboards_serialize() {
+        let board = leaderboard(&[synthetic_game(Some(Alignment::Wolf))], |_| "solo".into());
+        let json = serde_json::to_string(&board).unwrap();
+        assert!(json.contains("\"rating\""));
+        assert_eq!(serde_json::from_str::<Leaderboard>(&json).unwrap(), board);
+    }
+}
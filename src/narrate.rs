@@ -0,0 +1,841 @@
+//! Rendering the event log as human-readable narration.
+//!
+//! The [`Narrator`] walks a slice of [`GameEvent`]s and emits one line per
+//! event from a set of [`PromptTemplate`]s, so the wording can be restyled
+//! or localized without touching the engine. Spoiler-free mode omits
+//! hidden information (secret night actions) for narrating to players;
+//! full mode shows everything for a God's-eye replay.
+
+use std::collections::HashMap;
+
+use crate::game::event::{GameEvent, GameEventKind};
+use crate::game::state::{Phase, PlayerId, PlayerRoster};
+use crate::i18n::Locale;
+use crate::game::timeout::ActionKind;
+use crate::llm::prompt::PromptTemplate;
+
+/// How much the narration is allowed to reveal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NarrationMode {
+    /// Omit hidden information; safe to show to living players.
+    #[default]
+    SpoilerFree,
+    /// Narrate everything, including secret night actions.
+    Full,
+}
+
+/// How lines are decorated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NarrationStyle {
+    /// Plain text.
+    #[default]
+    Plain,
+    /// ANSI-colored text for terminals.
+    Ansi,
+}
+
+/// The line templates narration is rendered from.
+///
+/// Placeholders available to each template are listed per field; all
+/// templates may also use `{day}`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NarrationTemplates {
+    /// Night begins. Placeholders: `{day}`.
+    pub night_falls: PromptTemplate,
+    /// Day begins. Placeholders: `{day}`.
+    pub day_breaks: PromptTemplate,
+    /// Voting begins. Placeholders: `{day}`.
+    pub voting_begins: PromptTemplate,
+    /// The game-over phase is reached. Placeholders: `{day}`.
+    pub game_over: PromptTemplate,
+    /// A discussion statement. Placeholders: `{player}`, `{text}`.
+    pub spoke: PromptTemplate,
+    /// A vote for a target. Placeholders: `{voter}`, `{target}`.
+    pub vote_cast: PromptTemplate,
+    /// A vote with a stated public reason. Placeholders: `{voter}`,
+    /// `{target}`, `{text}`.
+    pub vote_cast_reasoned: PromptTemplate,
+    /// An abstention. Placeholders: `{voter}`.
+    pub abstained: PromptTemplate,
+    /// A death. Placeholders: `{player}`, `{cause}`.
+    pub player_died: PromptTemplate,
+    /// A death with the role publicly revealed (the table plays with
+    /// `DeathReveal::Full`). Placeholders: `{player}`, `{cause}`,
+    /// `{role}`.
+    pub player_died_revealed: PromptTemplate,
+    /// A death with only the alignment revealed (the table plays with
+    /// `DeathReveal::AlignmentOnly`). Placeholders: `{player}`, `{cause}`,
+    /// `{alignment}`.
+    pub player_died_alignment: PromptTemplate,
+    /// A secret night action; full mode only. Placeholders: `{actor}`,
+    /// `{action}`.
+    pub night_action: PromptTemplate,
+    /// The game ends with a winner. Placeholders: `{winner}`.
+    pub game_ended: PromptTemplate,
+    /// The game ends in a draw at the day cap. No placeholders.
+    pub game_drawn: PromptTemplate,
+    /// A fallback answered for a player. Placeholders: `{player}`,
+    /// `{action}`.
+    pub fallback: PromptTemplate,
+    /// A refused turn (the model declined to play). Placeholders:
+    /// `{player}`.
+    pub player_refused: PromptTemplate,
+    /// A dying Hunter's shot. Placeholders: `{hunter}`, `{target}`.
+    pub hunter_shot: PromptTemplate,
+    /// A dying player's final statement. Placeholders: `{player}`,
+    /// `{text}`.
+    pub last_words: PromptTemplate,
+    /// A rules-rejected action; full mode only. Placeholders: `{player}`,
+    /// `{action}`.
+    pub invalid_action: PromptTemplate,
+    /// A formal accusation. Placeholders: `{accuser}`, `{accused}`,
+    /// `{text}`.
+    pub accusation: PromptTemplate,
+    /// The accused's defense speech. Placeholders: `{player}`, `{text}`.
+    pub defense: PromptTemplate,
+    /// The day's speaking order. Placeholders: `{order}`.
+    pub speaking_order: PromptTemplate,
+    /// The cost ceiling was hit. Placeholders: `{cost}`, `{max_cost}`.
+    pub budget_exceeded: PromptTemplate,
+    /// An adaptive discussion stopped. Placeholders: `{reason}`.
+    pub discussion_ended: PromptTemplate,
+    /// A dead player's graveyard remark; full mode only. Placeholders:
+    /// `{player}`, `{text}`.
+    pub graveyard_chat: PromptTemplate,
+    /// A deadlocked wolf council that settles on no kill; full mode only.
+    /// Placeholders: `{day}`.
+    pub wolf_no_kill: PromptTemplate,
+    /// A deadlocked wolf council resolved by a forced random kill; full
+    /// mode only. Placeholders: `{target}`.
+    pub wolf_forced_kill: PromptTemplate,
+    /// The model behind a seat, announced at game start. Placeholders:
+    /// `{player}`, `{model}`.
+    pub player_model: PromptTemplate,
+}
+
+impl Default for NarrationTemplates {
+    fn default() -> Self {
+        Self {
+            night_falls: PromptTemplate::new("\u{1f319} Night {day} falls."),
+            day_breaks: PromptTemplate::new("\u{2600}\u{fe0f} Day {day} breaks."),
+            voting_begins: PromptTemplate::new("\u{1f5f3}\u{fe0f} The village votes."),
+            game_over: PromptTemplate::new("The game is over."),
+            spoke: PromptTemplate::new("{player} says: {text}"),
+            vote_cast: PromptTemplate::new("{voter} votes for {target}."),
+            vote_cast_reasoned: PromptTemplate::new(
+                "{voter} votes for {target}: {text}",
+            ),
+            abstained: PromptTemplate::new("{voter} abstains."),
+            player_died: PromptTemplate::new("{player} is dead — {cause}."),
+            player_died_revealed: PromptTemplate::new(
+                "{player} is dead — {cause}. They were a {role}.",
+            ),
+            player_died_alignment: PromptTemplate::new(
+                "{player} is dead — {cause}. They were {alignment}-aligned.",
+            ),
+            night_action: PromptTemplate::new("(night) {actor}: {action}"),
+            game_ended: PromptTemplate::new("\u{1f3c1} The {winner} side wins."),
+            game_drawn: PromptTemplate::new("\u{1f3c1} The game ends in a draw."),
+            fallback: PromptTemplate::new("{player} fails to act ({action})."),
+            player_refused: PromptTemplate::new("{player} refuses to answer."),
+            hunter_shot: PromptTemplate::new(
+                "\u{1f3f9} With a dying breath, {hunter} shoots {target}.",
+            ),
+            last_words: PromptTemplate::new("{player}'s last words: {text}"),
+            invalid_action: PromptTemplate::new(
+                "(night) {player}'s {action} is ruled invalid.",
+            ),
+            accusation: PromptTemplate::new(
+                "\u{2696}\u{fe0f} {accuser} accuses {accused}: {text}",
+            ),
+            defense: PromptTemplate::new("{player}, in defense: {text}"),
+            speaking_order: PromptTemplate::new("Today's speaking order: {order}."),
+            budget_exceeded: PromptTemplate::new(
+                "\u{1f4b8} The budget is spent (${cost} of ${max_cost}) — straight to the vote.",
+            ),
+            discussion_ended: PromptTemplate::new("The discussion winds down ({reason})."),
+            graveyard_chat: PromptTemplate::new(
+                "\u{1f47b} (graveyard) {player}: {text}",
+            ),
+            wolf_no_kill: PromptTemplate::new(
+                "(night) The pack cannot agree; nobody is attacked.",
+            ),
+            wolf_forced_kill: PromptTemplate::new(
+                "(night) The pack cannot agree; fate settles on {target}.",
+            ),
+            player_model: PromptTemplate::new("{player} is played by {model}."),
+        }
+    }
+}
+
+/// Renders an event log to readable text.
+#[derive(Debug, Clone, Default)]
+pub struct Narrator {
+    mode: NarrationMode,
+    style: NarrationStyle,
+    locale: Locale,
+    templates: NarrationTemplates,
+    roster: PlayerRoster,
+}
+
+impl Narrator {
+    /// A spoiler-free, plain-text narrator.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Narrates everything, including secret night actions.
+    pub fn full(mut self) -> Self {
+        self.mode = NarrationMode::Full;
+        self
+    }
+
+    /// Emits ANSI-colored lines for terminals.
+    pub fn ansi(mut self) -> Self {
+        self.style = NarrationStyle::Ansi;
+        self
+    }
+
+    /// Switches the output language: installs the locale's line templates
+    /// and localizes role names and death causes.
+    pub fn locale(mut self, locale: Locale) -> Self {
+        self.templates = locale.narration_templates();
+        self.locale = locale;
+        self
+    }
+
+    /// Replaces the default templates, e.g. for a custom restyle. For a
+    /// stock translation prefer [`Narrator::locale`].
+    pub fn with_templates(mut self, templates: NarrationTemplates) -> Self {
+        self.templates = templates;
+        self
+    }
+
+    /// Installs display names: seats the roster names render as "Alice"
+    /// instead of the locale's numeric "Player 3" label.
+    pub fn with_roster(mut self, roster: PlayerRoster) -> Self {
+        self.roster = roster;
+        self
+    }
+
+    /// How a seat appears in output: its roster name, or the locale's
+    /// numeric label.
+    fn seat(&self, id: PlayerId) -> String {
+        match self.roster.name(id) {
+            Some(name) => name.to_string(),
+            None => self.locale.player_label(id),
+        }
+    }
+
+    /// Narrates the whole log, one line per visible event.
+    pub fn narrate(&self, events: &[GameEvent]) -> String {
+        events
+            .iter()
+            .filter_map(|e| self.narrate_event(e))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Narrates one event, or `None` when the current mode hides it.
+    pub fn narrate_event(&self, event: &GameEvent) -> Option<String> {
+        let spoiler_free = self.mode == NarrationMode::SpoilerFree;
+        let mut vars: HashMap<&str, String> = HashMap::from([("day", event.day.to_string())]);
+        let (template, color) = match &event.kind {
+            GameEventKind::PhaseChanged { to, .. } => {
+                let template = match to {
+                    Phase::Night => &self.templates.night_falls,
+                    Phase::Day => &self.templates.day_breaks,
+                    Phase::Voting => &self.templates.voting_begins,
+                    Phase::GameOver => &self.templates.game_over,
+                };
+                (template, CYAN)
+            }
+            GameEventKind::PlayerSpoke { player, text } => {
+                vars.insert("player", self.seat(*player));
+                vars.insert("text", text.clone());
+                (&self.templates.spoke, RESET)
+            }
+            GameEventKind::VoteCast { voter, target, reason } => {
+                vars.insert("voter", self.seat(*voter));
+                match (target, reason) {
+                    (Some(target), Some(reason)) => {
+                        vars.insert("target", self.seat(*target));
+                        vars.insert("text", reason.clone());
+                        (&self.templates.vote_cast_reasoned, YELLOW)
+                    }
+                    (Some(target), None) => {
+                        vars.insert("target", self.seat(*target));
+                        (&self.templates.vote_cast, YELLOW)
+                    }
+                    (None, _) => (&self.templates.abstained, YELLOW),
+                }
+            }
+            GameEventKind::PlayerDied { player, cause, role, alignment } => {
+                vars.insert("player", self.seat(*player));
+                vars.insert("cause", self.locale.cause_phrase(*cause).to_string());
+                match (role, alignment) {
+                    (Some(role), _) => {
+                        vars.insert("role", self.locale.role_name(*role).to_string());
+                        (&self.templates.player_died_revealed, RED)
+                    }
+                    (None, Some(alignment)) => {
+                        vars.insert(
+                            "alignment",
+                            self.locale.alignment_name(*alignment).to_string(),
+                        );
+                        (&self.templates.player_died_alignment, RED)
+                    }
+                    (None, None) => (&self.templates.player_died, RED),
+                }
+            }
+            GameEventKind::NightAction { actor, action } => {
+                if spoiler_free {
+                    return None;
+                }
+                vars.insert("actor", self.seat(*actor));
+                vars.insert("action", format!("{action:?}"));
+                (&self.templates.night_action, MAGENTA)
+            }
+            GameEventKind::GameEnded { winner: Some(winner) } => {
+                vars.insert("winner", format!("{winner:?}"));
+                (&self.templates.game_ended, GREEN)
+            }
+            GameEventKind::GameEnded { winner: None } => {
+                (&self.templates.game_drawn, GREEN)
+            }
+            GameEventKind::FallbackTriggered { player, action, .. } => {
+                // Even knowing that a player *has* a night action is
+                // hidden information.
+                if spoiler_free && *action == ActionKind::NightAction {
+                    return None;
+                }
+                vars.insert("player", self.seat(*player));
+                vars.insert("action", format!("{action:?}"));
+                (&self.templates.fallback, YELLOW)
+            }
+            GameEventKind::PlayerRefused { player, .. } => {
+                // The raw refusal text stays in the log for diagnostics;
+                // narrating it to the table would just be noise.
+                vars.insert("player", self.seat(*player));
+                (&self.templates.player_refused, YELLOW)
+            }
+            GameEventKind::HunterShot { hunter, target } => {
+                vars.insert("hunter", self.seat(*hunter));
+                vars.insert("target", self.seat(*target));
+                (&self.templates.hunter_shot, RED)
+            }
+            GameEventKind::LastWords { player, text } => {
+                vars.insert("player", self.seat(*player));
+                vars.insert("text", text.clone());
+                (&self.templates.last_words, RESET)
+            }
+            GameEventKind::SpeakingOrder { order } => {
+                let order = order
+                    .iter()
+                    .map(|&id| self.seat(id))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                vars.insert("order", order);
+                (&self.templates.speaking_order, CYAN)
+            }
+            GameEventKind::Accusation { accuser, accused, text } => {
+                vars.insert("accuser", self.seat(*accuser));
+                vars.insert("accused", self.seat(*accused));
+                vars.insert("text", text.clone());
+                (&self.templates.accusation, YELLOW)
+            }
+            GameEventKind::Defense { player, text } => {
+                vars.insert("player", self.seat(*player));
+                vars.insert("text", text.clone());
+                (&self.templates.defense, RESET)
+            }
+            GameEventKind::BudgetExceeded { cost, max_cost } => {
+                vars.insert("cost", format!("{cost:.2}"));
+                vars.insert("max_cost", format!("{max_cost:.2}"));
+                (&self.templates.budget_exceeded, YELLOW)
+            }
+            GameEventKind::DiscussionEnded { reason } => {
+                vars.insert("reason", format!("{reason:?}"));
+                (&self.templates.discussion_ended, CYAN)
+            }
+            GameEventKind::InvalidAction { player, action } => {
+                // Invalid actions are night secrets too: revealing one
+                // would expose what the player tried to do.
+                if spoiler_free {
+                    return None;
+                }
+                vars.insert("player", self.seat(*player));
+                vars.insert("action", format!("{action:?}"));
+                (&self.templates.invalid_action, MAGENTA)
+            }
+            GameEventKind::GraveyardChat { player, text } => {
+                // The graveyard channel is hidden from the living.
+                if spoiler_free {
+                    return None;
+                }
+                vars.insert("player", self.seat(*player));
+                vars.insert("text", text.clone());
+                (&self.templates.graveyard_chat, MAGENTA)
+            }
+            GameEventKind::WolfDeadlock { forced_target } => {
+                // How (and whether) the pack settled is a night secret.
+                if spoiler_free {
+                    return None;
+                }
+                match forced_target {
+                    Some(target) => {
+                        vars.insert("target", self.seat(*target));
+                        (&self.templates.wolf_forced_kill, MAGENTA)
+                    }
+                    None => (&self.templates.wolf_no_kill, MAGENTA),
+                }
+            }
+            GameEventKind::PlayerModel { player, model } => {
+                vars.insert("player", self.seat(*player));
+                vars.insert("model", model.clone());
+                (&self.templates.player_model, CYAN)
+            }
+        };
+        // A broken custom template should degrade, not panic mid-game.
+        let line = template
+            .render(&vars)
+            .unwrap_or_else(|_| format!("{:?}", event.kind));
+        Some(match self.style {
+            NarrationStyle::Plain => line,
+            NarrationStyle::Ansi => format!("{color}{line}{RESET}"),
+        })
+    }
+}
+
+/// How much a Markdown transcript reveals.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RevealMode {
+    /// Hide hidden information; roles surface only as deaths reveal them.
+    #[default]
+    Blind,
+    /// Show roles inline on every speech and narrate night secrets.
+    Spoiler,
+}
+
+/// Renders a full game as a shareable Markdown writeup: day/night
+/// headers, speeches in blockquotes, one vote table per vote, and a final
+/// result banner. Everything is derived from the event log alone.
+pub fn to_markdown(log: &[GameEvent], mode: RevealMode) -> String {
+    let narrator = match mode {
+        RevealMode::Spoiler => Narrator::new().full(),
+        RevealMode::Blind => Narrator::new(),
+    };
+    // Inline role attribution is spoiler territory; the roles come from
+    // the death reveals, which name every player by the end of the log.
+    let roles: HashMap<crate::game::state::PlayerId, crate::roles::Role> = match mode {
+        RevealMode::Spoiler => log
+            .iter()
+            .filter_map(|e| match e.kind {
+                GameEventKind::PlayerDied { player, role: Some(role), .. } => {
+                    Some((player, role))
+                }
+                _ => None,
+            })
+            .collect(),
+        RevealMode::Blind => HashMap::new(),
+    };
+    let name = |id: crate::game::state::PlayerId| match roles.get(&id) {
+        Some(role) => format!("Player {id} ({})", role.info().display_name),
+        None => format!("Player {id}"),
+    };
+
+    let mut out = vec!["# Werewolf transcript".to_string()];
+    let mut votes: Vec<(String, String, String)> = Vec::new();
+    fn flush_votes(out: &mut Vec<String>, votes: &mut Vec<(String, String, String)>) {
+        if votes.is_empty() {
+            return;
+        }
+        let mut table = String::from("| Voter | Vote | Reason |\n| --- | --- | --- |");
+        for (voter, target, reason) in votes.drain(..) {
+            table.push_str(&format!("\n| {voter} | {target} | {reason} |"));
+        }
+        out.push(table);
+    }
+
+    for event in log {
+        match &event.kind {
+            GameEventKind::PhaseChanged { to, .. } => {
+                flush_votes(&mut out, &mut votes);
+                match to {
+                    Phase::Night => out.push(format!("## \u{1f319} Night {}", event.day)),
+                    Phase::Day => out.push(format!("## \u{2600}\u{fe0f} Day {}", event.day)),
+                    Phase::Voting => out.push("### \u{1f5f3}\u{fe0f} The vote".to_string()),
+                    Phase::GameOver => {}
+                }
+            }
+            GameEventKind::PlayerSpoke { player, text } => {
+                out.push(format!("> **{}:** {text}", name(*player)));
+            }
+            GameEventKind::Defense { player, text } => {
+                out.push(format!("> **{} (in defense):** {text}", name(*player)));
+            }
+            GameEventKind::Accusation { accuser, accused, text } => {
+                out.push(format!(
+                    "> \u{2696}\u{fe0f} **{}** accuses **{}**: {text}",
+                    name(*accuser),
+                    name(*accused)
+                ));
+            }
+            GameEventKind::VoteCast { voter, target, reason } => {
+                votes.push((
+                    name(*voter),
+                    match target {
+                        Some(target) => name(*target),
+                        None => "abstain".to_string(),
+                    },
+                    reason.clone().unwrap_or_default(),
+                ));
+            }
+            GameEventKind::PlayerDied { .. } => {
+                // A death during voting means the tally is in; the table
+                // reads better before the verdict.
+                flush_votes(&mut out, &mut votes);
+                if let Some(line) = narrator.narrate_event(event) {
+                    out.push(format!("**\u{1f480} {line}**"));
+                }
+            }
+            GameEventKind::GameEnded { .. } => {
+                flush_votes(&mut out, &mut votes);
+                out.push("---".to_string());
+                if let Some(line) = narrator.narrate_event(event) {
+                    out.push(format!("## {line}"));
+                }
+            }
+            // Everything else (night actions, fallbacks, hunter shots, ...)
+            // keeps its narration line, italicized, when the mode shows it.
+            _ => {
+                if let Some(line) = narrator.narrate_event(event) {
+                    out.push(format!("_{line}_"));
+                }
+            }
+        }
+    }
+    flush_votes(&mut out, &mut votes);
+    let mut text = out.join("\n\n");
+    text.push('\n');
+    text
+}
+
+const RESET: &str = "\u{1b}[0m";
+const RED: &str = "\u{1b}[31m";
+const GREEN: &str = "\u{1b}[32m";
+const YELLOW: &str = "\u{1b}[33m";
+const MAGENTA: &str = "\u{1b}[35m";
+const CYAN: &str = "\u{1b}[36m";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::action::Action;
+    use crate::game::state::PlayerId;
+    use crate::game::night::DeathCause;
+    use crate::game::timeout::FallbackReason;
+    use crate::roles::Alignment;
+
+    fn one_of_each() -> Vec<GameEvent> {
+        vec![
+            GameEvent::now(1, GameEventKind::PhaseChanged {
+                from: Phase::Night,
+                to: Phase::Day,
+            }),
+            GameEvent::now(1, GameEventKind::PlayerSpoke {
+                player: PlayerId(0),
+                text: "I trust nobody.".into(),
+            }),
+            GameEvent::now(1, GameEventKind::VoteCast {
+                voter: PlayerId(0),
+                target: Some(PlayerId(2)),
+                reason: None,
+            }),
+            GameEvent::now(1, GameEventKind::VoteCast {
+                voter: PlayerId(0),
+                target: Some(PlayerId(2)),
+                reason: Some("He dodged every question.".into()),
+            }),
+            GameEvent::now(1, GameEventKind::VoteCast {
+                voter: PlayerId(1),
+                target: None,
+                reason: None,
+            }),
+            GameEvent::now(1, GameEventKind::PlayerDied {
+                player: PlayerId(2),
+                cause: DeathCause::Vote,
+                role: None,
+                alignment: None,
+            }),
+            GameEvent::now(1, GameEventKind::NightAction {
+                actor: PlayerId(3),
+                action: Action::Kill(PlayerId(0)),
+            }),
+            GameEvent::now(1, GameEventKind::FallbackTriggered {
+                player: PlayerId(4),
+                action: ActionKind::Vote,
+                reason: FallbackReason::Timeout,
+            }),
+            GameEvent::now(1, GameEventKind::PlayerRefused {
+                player: PlayerId(4),
+                text: "I cannot take part in this.".into(),
+            }),
+            GameEvent::now(1, GameEventKind::HunterShot { hunter: PlayerId(2), target: PlayerId(3) }),
+            GameEvent::now(1, GameEventKind::InvalidAction {
+                player: PlayerId(3),
+                action: Action::Heal(PlayerId(3)),
+            }),
+            GameEvent::now(1, GameEventKind::Accusation {
+                accuser: PlayerId(1),
+                accused: PlayerId(0),
+                text: "He lied about his claim.".into(),
+            }),
+            GameEvent::now(1, GameEventKind::Defense {
+                player: PlayerId(0),
+                text: "I never claimed anything.".into(),
+            }),
+            GameEvent::now(1, GameEventKind::SpeakingOrder { order: vec![PlayerId(1), PlayerId(2), PlayerId(0)] }),
+            GameEvent::now(1, GameEventKind::BudgetExceeded { cost: 5.01, max_cost: 5.0 }),
+            GameEvent::now(1, GameEventKind::DiscussionEnded {
+                reason: crate::game::day::DiscussionEndReason::BudgetTight,
+            }),
+            GameEvent::now(1, GameEventKind::GraveyardChat {
+                player: PlayerId(2),
+                text: "It was Player 3 all along.".into(),
+            }),
+            GameEvent::now(1, GameEventKind::WolfDeadlock { forced_target: None }),
+            GameEvent::now(1, GameEventKind::WolfDeadlock { forced_target: Some(PlayerId(4)) }),
+            GameEvent::now(0, GameEventKind::PlayerModel {
+                player: PlayerId(0),
+                model: "gpt-4o".into(),
+            }),
+            GameEvent::now(1, GameEventKind::LastWords {
+                player: PlayerId(2),
+                text: "Check my claim.".into(),
+            }),
+            GameEvent::now(2, GameEventKind::GameEnded { winner: None }),
+            GameEvent::now(2, GameEventKind::GameEnded { winner: Some(Alignment::Town) }),
+        ]
+    }
+
+    #[test]
+    fn full_mode_narrates_every_event_variant() {
+        let narrator = Narrator::new().full();
+        for event in one_of_each() {
+            let line = narrator.narrate_event(&event);
+            assert!(line.is_some(), "no narration for {:?}", event.kind);
+            assert!(!line.unwrap().is_empty());
+        }
+    }
+
+    #[test]
+    fn spoiler_free_mode_hides_night_actions() {
+        let narrator = Narrator::new();
+        let text = narrator.narrate(&one_of_each());
+        assert!(!text.contains("Kill"));
+        assert!(!text.contains("invalid"));
+        assert!(text.contains("Player 2 is dead"));
+    }
+
+    #[test]
+    fn spoiler_free_mode_hides_night_action_fallbacks_too() {
+        let narrator = Narrator::new();
+        let event = GameEvent::now(1, GameEventKind::FallbackTriggered {
+            player: PlayerId(4),
+            action: ActionKind::NightAction,
+            reason: FallbackReason::Timeout,
+        });
+        assert_eq!(narrator.narrate_event(&event), None);
+        assert!(Narrator::new().full().narrate_event(&event).is_some());
+    }
+
+    #[test]
+    fn a_roster_name_replaces_the_numeric_label() {
+        let mut roster = PlayerRoster::new();
+        roster.set_name(PlayerId(3), "Alice");
+        let narrator = Narrator::new().with_roster(roster);
+        let spoke = GameEvent::now(1, GameEventKind::PlayerSpoke {
+            player: PlayerId(3),
+            text: "I trust nobody.".into(),
+        });
+        assert_eq!(narrator.narrate_event(&spoke).unwrap(), "Alice says: I trust nobody.");
+        // Unnamed seats keep the locale's numeric label.
+        let other = GameEvent::now(1, GameEventKind::PlayerSpoke {
+            player: PlayerId(4),
+            text: "Suspicious.".into(),
+        });
+        assert_eq!(narrator.narrate_event(&other).unwrap(), "Player 4 says: Suspicious.");
+    }
+
+    #[test]
+    fn a_revealed_death_narrates_the_role() {
+        let narrator = Narrator::new();
+        let event = GameEvent::now(1, GameEventKind::PlayerDied {
+            player: PlayerId(2),
+            cause: DeathCause::Vote,
+            role: Some(crate::roles::Role::Seer),
+            alignment: Some(crate::roles::Alignment::Town),
+        });
+        assert!(narrator.narrate_event(&event).unwrap().contains("They were a Seer"));
+    }
+
+    #[test]
+    fn an_alignment_only_death_names_the_side_not_the_role() {
+        let narrator = Narrator::new();
+        let event = GameEvent::now(1, GameEventKind::PlayerDied {
+            player: PlayerId(2),
+            cause: DeathCause::Vote,
+            role: None,
+            alignment: Some(crate::roles::Alignment::Wolf),
+        });
+        let line = narrator.narrate_event(&event).unwrap();
+        assert!(line.contains("Wolf-aligned"));
+        assert!(!line.contains("Werewolf"));
+    }
+
+    #[test]
+    fn ansi_renderer_colors_lines_and_plain_does_not() {
+        let events = one_of_each();
+        let plain = Narrator::new().narrate(&events);
+        let colored = Narrator::new().ansi().narrate(&events);
+        assert!(!plain.contains("\u{1b}["));
+        assert!(colored.contains("\u{1b}[31m"));
+    }
+
+    #[test]
+    fn custom_templates_restyle_the_output() {
+        let templates = NarrationTemplates {
+            day_breaks: PromptTemplate::new("Der Tag {day} bricht an."),
+            ..NarrationTemplates::default()
+        };
+        let narrator = Narrator::new().with_templates(templates);
+        let event = GameEvent::now(2, GameEventKind::PhaseChanged {
+            from: Phase::Night,
+            to: Phase::Day,
+        });
+        assert_eq!(narrator.narrate_event(&event).unwrap(), "Der Tag 2 bricht an.");
+    }
+
+    #[test]
+    fn switching_locale_changes_a_narrated_death_line() {
+        let event = GameEvent::now(1, GameEventKind::PlayerDied {
+            player: PlayerId(2),
+            cause: DeathCause::Vote,
+            role: Some(crate::roles::Role::Seer),
+            alignment: Some(crate::roles::Alignment::Town),
+        });
+        let english = Narrator::new().narrate_event(&event).unwrap();
+        assert_eq!(english, "Player 2 is dead — voted out by the village. They were a Seer.");
+        let chinese = Narrator::new()
+            .locale(Locale::ZhTw)
+            .narrate_event(&event)
+            .unwrap();
+        assert_eq!(chinese, "玩家 2 死了 —— 被村民投票放逐。他的身分是預言家。");
+    }
+
+    /// A two-phase game: day-1 speech and lynch, then a night kill that
+    /// ends it.
+    fn short_game() -> Vec<GameEvent> {
+        vec![
+            GameEvent::now(1, GameEventKind::PhaseChanged {
+                from: Phase::Night,
+                to: Phase::Day,
+            }),
+            GameEvent::now(1, GameEventKind::PlayerSpoke {
+                player: PlayerId(0),
+                text: "I trust nobody.".into(),
+            }),
+            GameEvent::now(1, GameEventKind::PhaseChanged {
+                from: Phase::Day,
+                to: Phase::Voting,
+            }),
+            GameEvent::now(1, GameEventKind::VoteCast {
+                voter: PlayerId(0),
+                target: Some(PlayerId(2)),
+                reason: Some("He dodged.".into()),
+            }),
+            GameEvent::now(1, GameEventKind::VoteCast {
+                voter: PlayerId(1),
+                target: None,
+                reason: None,
+            }),
+            GameEvent::now(1, GameEventKind::PlayerDied {
+                player: PlayerId(2),
+                cause: DeathCause::Vote,
+                role: Some(crate::roles::Role::Seer),
+                alignment: Some(crate::roles::Alignment::Town),
+            }),
+            GameEvent::now(1, GameEventKind::PhaseChanged {
+                from: Phase::Voting,
+                to: Phase::Night,
+            }),
+            GameEvent::now(1, GameEventKind::NightAction {
+                actor: PlayerId(3),
+                action: Action::Kill(PlayerId(0)),
+            }),
+            GameEvent::now(1, GameEventKind::PlayerDied {
+                player: PlayerId(0),
+                cause: DeathCause::WolfKill,
+                role: Some(crate::roles::Role::Villager),
+                alignment: Some(crate::roles::Alignment::Town),
+            }),
+            GameEvent::now(2, GameEventKind::GameEnded { winner: Some(Alignment::Wolf) }),
+        ]
+    }
+
+    #[test]
+    fn markdown_snapshot_of_a_short_blind_game() {
+        let expected = "\
+# Werewolf transcript
+
+## \u{2600}\u{fe0f} Day 1
+
+> **Player 0:** I trust nobody.
+
+### \u{1f5f3}\u{fe0f} The vote
+
+| Voter | Vote | Reason |
+| --- | --- | --- |
+| Player 0 | Player 2 | He dodged. |
+| Player 1 | abstain |  |
+
+**\u{1f480} Player 2 is dead — voted out by the village. They were a Seer.**
+
+## \u{1f319} Night 1
+
+**\u{1f480} Player 0 is dead — torn apart by the wolves. They were a Villager.**
+
+---
+
+## \u{1f3c1} The Wolf side wins.
+";
+        assert_eq!(to_markdown(&short_game(), RevealMode::Blind), expected);
+    }
+
+    #[test]
+    fn spoiler_markdown_names_roles_inline_and_shows_the_night() {
+        let text = to_markdown(&short_game(), RevealMode::Spoiler);
+        assert!(text.contains("> **Player 0 (Villager):** I trust nobody."));
+        assert!(text.contains("| Player 0 (Villager) | Player 2 (Seer) | He dodged. |"));
+        assert!(text.contains("_(night) Player 3: Kill(0)_"));
+        // Blind mode keeps the night secret.
+        let blind = to_markdown(&short_game(), RevealMode::Blind);
+        assert!(!blind.contains("Kill(0)"));
+    }
+
+    #[test]
+    fn a_broken_template_degrades_instead_of_panicking() {
+        let templates = NarrationTemplates {
+            abstained: PromptTemplate::new("{nonsense}"),
+            ..NarrationTemplates::default()
+        };
+        let narrator = Narrator::new().with_templates(templates);
+        let event = GameEvent::now(1, GameEventKind::VoteCast {
+            voter: PlayerId(1),
+            target: None,
+            reason: None,
+        });
+        assert!(narrator.narrate_event(&event).unwrap().contains("VoteCast"));
+    }
+}
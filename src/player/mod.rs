@@ -0,0 +1,454 @@
+//! The [`Player`] abstraction: anything that can act in a game, whether a
+//! human, a scripted test actor, or an LLM.
+
+use std::collections::VecDeque;
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+
+use crate::game::action::Action;
+use crate::game::knowledge::{Claim, KnowledgeBase};
+use crate::game::state::{Phase, PlayerId};
+use crate::llm::prompt::{PromptSet, PromptTemplate};
+use crate::roles::Role;
+
+/// Everything a player is allowed to see when asked to act.
+///
+/// The context exposes only public information plus the player's own role;
+/// hidden state (other players' roles, secret night targets) must never be
+/// reachable from here.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GameContext {
+    /// The acting player's own id.
+    pub player: PlayerId,
+    /// The acting player's own role.
+    pub role: Role,
+    /// Current day number.
+    pub day: u32,
+    /// Current phase.
+    pub phase: Phase,
+    /// Living players, in seat order.
+    pub alive_players: Vec<PlayerId>,
+    /// The public transcript so far: speeches, announcements, vote results.
+    pub public_log: Vec<String>,
+    /// This player's own private knowledge (e.g. Seer investigations).
+    pub knowledge: KnowledgeBase,
+    /// Public role claims made so far — common knowledge.
+    pub claims: Vec<Claim>,
+    /// Estimated tokens this player has consumed speaking so far, against
+    /// any configured budget.
+    pub tokens_used: u32,
+    /// Remaining single-use potions, for potion-carrying roles (the
+    /// Witch); `None` for everyone else.
+    pub potions: Option<crate::game::night::WitchPotions>,
+    /// Whom this player protected last night, for protecting roles (the
+    /// Guard); `None` for everyone else or after a night without one.
+    pub last_protected: Option<PlayerId>,
+    /// The wolves' private coordination transcript. Populated only for
+    /// wolf-aligned players; always empty for town.
+    pub wolf_chat: Vec<(PlayerId, String)>,
+    /// The graveyard transcript. Populated only once this player is dead;
+    /// always empty for the living.
+    pub graveyard: Vec<(PlayerId, String)>,
+    /// Estimated dollar cost the whole game has incurred so far, so a
+    /// prompt can ask the model to be concise as the budget tightens.
+    pub cost_so_far: f64,
+}
+
+/// An actor in the game. Implementations decide how each question is
+/// answered: from a script, a human input channel, or a model call.
+#[async_trait]
+pub trait Player: Send + Sync {
+    /// Asks the player whom to vote for during the voting phase.
+    async fn vote(&self, ctx: &GameContext) -> PlayerId;
+
+    /// Like [`vote`](Player::vote), but the player may also state a short
+    /// public justification. The reason lands in the `VoteCast` event and
+    /// in the context later voters see, so they can react to it.
+    ///
+    /// The default implementation votes without a reason, so scripted and
+    /// human players aren't forced to supply one.
+    async fn vote_with_reason(&self, ctx: &GameContext) -> (PlayerId, Option<String>) {
+        (self.vote(ctx).await, None)
+    }
+
+    /// Asks the player for their night action, if their role has one.
+    /// Returning `None` means the player skips acting tonight.
+    async fn night_action(&self, ctx: &GameContext) -> Option<Action>;
+
+    /// Asks the player to speak during the discussion phase.
+    async fn speak(&self, ctx: &GameContext) -> String;
+
+    /// Like [`speak`](Player::speak), but reports content fragments to
+    /// `on_chunk` as they are produced, for live spectator UIs. The full
+    /// assembled speech is still returned for the log.
+    ///
+    /// The default implementation is the non-streaming fallback: it waits
+    /// for the complete speech and reports it as one fragment.
+    async fn speak_streaming(
+        &self,
+        ctx: &GameContext,
+        on_chunk: &(dyn for<'a> Fn(&'a str) + Send + Sync),
+    ) -> String {
+        let text = self.speak(ctx).await;
+        if !text.is_empty() {
+            on_chunk(&text);
+        }
+        text
+    }
+
+    /// Asks a dying Hunter whom to shoot. Only called on players whose
+    /// role grants a dying shot; `None` holds fire.
+    async fn hunter_shot(&self, ctx: &GameContext) -> Option<PlayerId> {
+        let _ = ctx;
+        None
+    }
+
+    /// Asks the player for a formal pre-vote accusation: a target and the
+    /// stated case against them. `None` declines to accuse. Only called
+    /// when the accusation sub-phase is enabled.
+    async fn accuse(&self, ctx: &GameContext) -> Option<(PlayerId, String)> {
+        let _ = ctx;
+        None
+    }
+
+    /// The model identifier behind this player, if it is model-backed.
+    /// Used to tag the seat's events in the log so metrics can be broken
+    /// down per model; `None` for scripted and human players.
+    fn model_id(&self) -> Option<String> {
+        None
+    }
+}
+
+/// A deterministic [`Player`] that replays pre-programmed answers.
+///
+/// Each method pops the front of its own queue; an exhausted queue yields a
+/// harmless default (self-vote, no action, empty speech) so tests that run
+/// longer than their script don't panic.
+#[derive(Debug, Default)]
+pub struct ScriptedPlayer {
+    votes: Mutex<VecDeque<PlayerId>>,
+    vote_reasons: Mutex<VecDeque<Option<String>>>,
+    night_actions: Mutex<VecDeque<Option<Action>>>,
+    speeches: Mutex<VecDeque<String>>,
+    shots: Mutex<VecDeque<Option<PlayerId>>>,
+    accusations: Mutex<VecDeque<Option<(PlayerId, String)>>>,
+}
+
+impl ScriptedPlayer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queues a vote target.
+    pub fn will_vote(self, target: PlayerId) -> Self {
+        self.votes.lock().unwrap().push_back(target);
+        self.vote_reasons.lock().unwrap().push_back(None);
+        self
+    }
+
+    /// Queues a vote target with a stated public reason.
+    pub fn will_vote_because(self, target: PlayerId, reason: impl Into<String>) -> Self {
+        self.votes.lock().unwrap().push_back(target);
+        self.vote_reasons.lock().unwrap().push_back(Some(reason.into()));
+        self
+    }
+
+    /// Queues a night action (or an explicit skip).
+    pub fn will_act(self, action: Option<Action>) -> Self {
+        self.night_actions.lock().unwrap().push_back(action);
+        self
+    }
+
+    /// Queues a speech.
+    pub fn will_say(self, speech: impl Into<String>) -> Self {
+        self.speeches.lock().unwrap().push_back(speech.into());
+        self
+    }
+
+    /// Queues a Hunter shot (or an explicit hold-fire).
+    pub fn will_shoot(self, target: Option<PlayerId>) -> Self {
+        self.shots.lock().unwrap().push_back(target);
+        self
+    }
+
+    /// Queues a formal accusation.
+    pub fn will_accuse(self, target: PlayerId, case: impl Into<String>) -> Self {
+        self.accusations.lock().unwrap().push_back(Some((target, case.into())));
+        self
+    }
+}
+
+#[async_trait]
+impl Player for ScriptedPlayer {
+    async fn vote(&self, ctx: &GameContext) -> PlayerId {
+        self.vote_reasons.lock().unwrap().pop_front();
+        self.votes
+            .lock()
+            .unwrap()
+            .pop_front()
+            .unwrap_or(ctx.player)
+    }
+
+    async fn vote_with_reason(&self, ctx: &GameContext) -> (PlayerId, Option<String>) {
+        let reason = self.vote_reasons.lock().unwrap().pop_front().flatten();
+        let target = self
+            .votes
+            .lock()
+            .unwrap()
+            .pop_front()
+            .unwrap_or(ctx.player);
+        (target, reason)
+    }
+
+    async fn night_action(&self, _ctx: &GameContext) -> Option<Action> {
+        self.night_actions.lock().unwrap().pop_front().flatten()
+    }
+
+    async fn speak(&self, _ctx: &GameContext) -> String {
+        self.speeches.lock().unwrap().pop_front().unwrap_or_default()
+    }
+
+    async fn hunter_shot(&self, _ctx: &GameContext) -> Option<PlayerId> {
+        self.shots.lock().unwrap().pop_front().flatten()
+    }
+
+    async fn accuse(&self, _ctx: &GameContext) -> Option<(PlayerId, String)> {
+        self.accusations.lock().unwrap().pop_front().flatten()
+    }
+}
+
+/// A [`Player`] backed by a language model.
+///
+/// Each player carries its own provider, so one game can seat different
+/// backends against each other — GPT wolves against Claude villagers —
+/// and [`model_id`](Player::model_id) tags the seat's events for
+/// per-model metrics.
+pub struct LlmPlayer {
+    /// Model identifier passed to the backing provider.
+    pub model: String,
+    /// The backend this player's prompts are sent to.
+    pub provider: std::sync::Arc<dyn crate::llm::LlmProvider>,
+    /// Sampling temperature for this player's requests; `None` uses the
+    /// provider default.
+    pub temperature: Option<f32>,
+    /// Templates the player renders its prompts from.
+    pub prompts: PromptSet,
+}
+
+impl std::fmt::Debug for LlmPlayer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LlmPlayer")
+            .field("model", &self.model)
+            .field("temperature", &self.temperature)
+            .finish_non_exhaustive()
+    }
+}
+
+impl LlmPlayer {
+    pub fn new(
+        model: impl Into<String>,
+        provider: std::sync::Arc<dyn crate::llm::LlmProvider>,
+    ) -> Self {
+        Self {
+            model: model.into(),
+            provider,
+            temperature: None,
+            prompts: PromptSet::default(),
+        }
+    }
+
+    /// Overrides the provider's default sampling temperature.
+    pub fn with_temperature(mut self, temperature: f32) -> Self {
+        self.temperature = Some(temperature);
+        self
+    }
+
+    /// Overrides the default prompt templates.
+    pub fn with_prompts(mut self, prompts: PromptSet) -> Self {
+        self.prompts = prompts;
+        self
+    }
+
+    /// Renders `template` against the context and asks the model. Any
+    /// provider error degrades to an empty reply so the turn fallback
+    /// handles it.
+    async fn ask(&self, ctx: &GameContext, template: &PromptTemplate) -> String {
+        let vars = crate::llm::prompt::context_vars(ctx);
+        let Ok(system) = self.prompts.system.render(&vars) else {
+            return String::new();
+        };
+        let Ok(prompt) = template.render(&vars) else {
+            return String::new();
+        };
+        let mut req = crate::llm::ChatRequest::new(vec![
+            crate::llm::ChatMessage::system(system),
+            crate::llm::ChatMessage::user(prompt),
+        ]);
+        req.temperature = self.temperature;
+        match self.provider.complete(req).await {
+            Ok(resp) => resp.content,
+            Err(_) => String::new(),
+        }
+    }
+}
+
+#[async_trait]
+impl Player for LlmPlayer {
+    async fn vote(&self, ctx: &GameContext) -> PlayerId {
+        let reply = self.ask(ctx, &self.prompts.voting).await;
+        crate::llm::parse::parse_vote(&reply, &ctx.alive_players).unwrap_or(ctx.player)
+    }
+
+    async fn night_action(&self, ctx: &GameContext) -> Option<Action> {
+        let reply = self.ask(ctx, &self.prompts.night_action).await;
+        let target = crate::llm::parse::parse_night_target(&reply, &ctx.alive_players)?;
+        match ctx.role {
+            Role::Werewolf | Role::AlphaWerewolf => Some(Action::Kill(target)),
+            Role::Seer => Some(Action::Investigate(target)),
+            Role::Guard => Some(Action::Protect(target)),
+            _ => None,
+        }
+    }
+
+    async fn speak(&self, ctx: &GameContext) -> String {
+        self.ask(ctx, &self.prompts.discussion).await
+    }
+
+    fn model_id(&self) -> Option<String> {
+        Some(self.model.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ctx_for(player: PlayerId) -> GameContext {
+        GameContext {
+            player,
+            role: Role::Villager,
+            day: 1,
+            phase: Phase::Day,
+            alive_players: vec![PlayerId(0), PlayerId(1), PlayerId(2)],
+            public_log: Vec::new(),
+            knowledge: KnowledgeBase::default(),
+            claims: Vec::new(),
+            tokens_used: 0,
+            potions: None,
+            last_protected: None,
+            wolf_chat: Vec::new(),
+            graveyard: Vec::new(),
+            cost_so_far: 0.0,
+        }
+    }
+
+    #[tokio::test]
+    async fn scripted_player_replays_answers_in_order() {
+        let p = ScriptedPlayer::new()
+            .will_vote(PlayerId(2))
+            .will_vote(PlayerId(1))
+            .will_act(Some(Action::Investigate(PlayerId(2))))
+            .will_say("I am just a villager.");
+
+        let ctx = ctx_for(PlayerId(0));
+        assert_eq!(p.vote(&ctx).await, PlayerId(2));
+        assert_eq!(p.vote(&ctx).await, PlayerId(1));
+        assert_eq!(p.night_action(&ctx).await, Some(Action::Investigate(PlayerId(2))));
+        assert_eq!(p.speak(&ctx).await, "I am just a villager.");
+    }
+
+    #[tokio::test]
+    async fn exhausted_script_yields_defaults() {
+        let p = ScriptedPlayer::new();
+        let ctx = ctx_for(PlayerId(7));
+        assert_eq!(p.vote(&ctx).await, PlayerId(7));
+        assert_eq!(p.night_action(&ctx).await, None);
+        assert_eq!(p.speak(&ctx).await, "");
+    }
+
+    /// Always answers `reply` and keeps every system prompt it was sent,
+    /// so a test can check which seats talked to it.
+    struct Recording {
+        reply: String,
+        prompts: std::sync::Arc<Mutex<Vec<String>>>,
+    }
+
+    #[async_trait]
+    impl crate::llm::LlmProvider for Recording {
+        async fn complete(
+            &self,
+            req: crate::llm::ChatRequest,
+        ) -> Result<crate::llm::ChatResponse, crate::llm::LlmError> {
+            self.prompts.lock().unwrap().push(req.messages[0].content.clone());
+            Ok(crate::llm::ChatResponse {
+                content: self.reply.clone(),
+                usage: crate::llm::TokenUsage::default(),
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn each_llm_player_talks_only_to_its_own_provider() {
+        use std::collections::BTreeMap;
+        use std::sync::Arc;
+
+        let config = crate::config::GameConfig {
+            player_count: 4,
+            roles: BTreeMap::from([(Role::Werewolf, 1), (Role::Villager, 3)]),
+            ..crate::config::GameConfig::default()
+        };
+        let a_prompts = Arc::new(Mutex::new(Vec::new()));
+        let b_prompts = Arc::new(Mutex::new(Vec::new()));
+        let provider_a = Arc::new(Recording {
+            reply: r#"{"vote": 2, "target": 2}"#.into(),
+            prompts: a_prompts.clone(),
+        });
+        let provider_b = Arc::new(Recording {
+            reply: r#"{"vote": 0, "target": 0}"#.into(),
+            prompts: b_prompts.clone(),
+        });
+
+        let mut builder = crate::game::builder::GameBuilder::new()
+            .config(config.clone())
+            .seed(3)
+            .assign(PlayerId(0), Role::Werewolf);
+        for id in 0..4 {
+            let (model, provider) = if id < 2 {
+                ("model-a", provider_a.clone() as Arc<dyn crate::llm::LlmProvider>)
+            } else {
+                ("model-b", provider_b.clone() as Arc<dyn crate::llm::LlmProvider>)
+            };
+            builder = builder.player(PlayerId(id), Box::new(LlmPlayer::new(model, provider)));
+        }
+        let (state, players) = builder.build_with_players().unwrap();
+
+        let result = crate::game::run_game_with(state, players, &config).await.unwrap();
+
+        // Every seat's model is tagged in the log, once, at game start.
+        for (id, model) in [(0, "model-a"), (1, "model-a"), (2, "model-b"), (3, "model-b")] {
+            use crate::game::event::GameEventKind;
+            assert_eq!(
+                result
+                    .log
+                    .iter()
+                    .filter(|e| e.kind
+                        == GameEventKind::PlayerModel { player: PlayerId(id), model: model.into() })
+                    .count(),
+                1
+            );
+        }
+
+        // Each provider only ever heard from its own seats.
+        let a_prompts = a_prompts.lock().unwrap();
+        let b_prompts = b_prompts.lock().unwrap();
+        assert!(!a_prompts.is_empty());
+        assert!(!b_prompts.is_empty());
+        assert!(a_prompts
+            .iter()
+            .all(|p| p.contains("player 0") || p.contains("player 1")));
+        assert!(b_prompts
+            .iter()
+            .all(|p| p.contains("player 2") || p.contains("player 3")));
+    }
+}
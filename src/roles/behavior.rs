@@ -0,0 +1,413 @@
+//! Pluggable role logic.
+//!
+//! Everything the engine needs to know about a role is expressed through
+//! [`RoleBehavior`], so homebrew roles can be defined in a downstream crate
+//! and registered in a [`RoleRegistry`] without forking this one. The
+//! built-in [`Role`](crate::roles::Role) set is implemented in terms of the
+//! same trait.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::game::action::Action;
+use crate::game::state::{GameState, PlayerId};
+use crate::roles::Alignment;
+
+/// What one night action contributes to resolution.
+///
+/// Behaviors translate an [`Action`] into an effect; the engine applies the
+/// effects in priority order with the usual interactions (protection and
+/// heals cancel attacks, one death per player per night).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NightEffect {
+    /// No effect; also the answer to an action the role may not take.
+    None,
+    /// Shield `target` from attacks tonight.
+    Protect(PlayerId),
+    /// Try to kill `target`; cancelled by protection or a heal.
+    Attack(PlayerId),
+    /// Undo tonight's attack on `target`.
+    Heal(PlayerId),
+    /// Shield `target` by standing in for them: an attack on `target`
+    /// kills the actor instead.
+    Sacrifice(PlayerId),
+    /// Kill `target` outright; not stopped by protection.
+    Poison(PlayerId),
+    /// Privately learn `target`'s alignment.
+    Reveal(PlayerId),
+}
+
+/// The full definition of a role, built-in or homebrew.
+pub trait RoleBehavior: Send + Sync {
+    /// Human-readable name shown in narration and prompts.
+    fn display_name(&self) -> &'static str;
+
+    /// The side this role wins with.
+    fn alignment(&self) -> Alignment;
+
+    /// Order in which the role's night action resolves; lower acts first.
+    /// `None` for roles with no night action.
+    fn night_priority(&self) -> Option<u8> {
+        None
+    }
+
+    /// Whether the role wakes up to act at night.
+    fn acts_at_night(&self) -> bool {
+        self.night_priority().is_some()
+    }
+
+    /// How this role reads under [`SeerMode::Binary`]. Defaults to the
+    /// truthful answer; a role that deceives the check overrides it.
+    ///
+    /// [`SeerMode::Binary`]: crate::game::night::SeerMode::Binary
+    fn appears_as_werewolf(&self) -> bool {
+        self.alignment() == Alignment::Wolf
+    }
+
+    /// Translates this role's night action into its effect. Actions the
+    /// role may not take must map to [`NightEffect::None`].
+    fn resolve(&self, action: &Action, state: &GameState) -> NightEffect {
+        let _ = (action, state);
+        NightEffect::None
+    }
+}
+
+/// Plain townsfolk: no night action, wins with the town.
+#[derive(Debug, Clone, Copy)]
+pub struct VillagerBehavior;
+
+impl RoleBehavior for VillagerBehavior {
+    fn display_name(&self) -> &'static str {
+        "Villager"
+    }
+
+    fn alignment(&self) -> Alignment {
+        Alignment::Town
+    }
+}
+
+/// The wolf pack's nightly kill.
+#[derive(Debug, Clone, Copy)]
+pub struct WerewolfBehavior;
+
+impl RoleBehavior for WerewolfBehavior {
+    fn display_name(&self) -> &'static str {
+        "Werewolf"
+    }
+
+    fn alignment(&self) -> Alignment {
+        Alignment::Wolf
+    }
+
+    fn night_priority(&self) -> Option<u8> {
+        Some(30)
+    }
+
+    fn resolve(&self, action: &Action, _state: &GameState) -> NightEffect {
+        match action {
+            Action::Kill(target) => NightEffect::Attack(*target),
+            _ => NightEffect::None,
+        }
+    }
+}
+
+/// Investigates one player's alignment per night.
+#[derive(Debug, Clone, Copy)]
+pub struct SeerBehavior;
+
+impl RoleBehavior for SeerBehavior {
+    fn display_name(&self) -> &'static str {
+        "Seer"
+    }
+
+    fn alignment(&self) -> Alignment {
+        Alignment::Town
+    }
+
+    fn night_priority(&self) -> Option<u8> {
+        Some(20)
+    }
+
+    fn resolve(&self, action: &Action, _state: &GameState) -> NightEffect {
+        match action {
+            Action::Investigate(target) => NightEffect::Reveal(*target),
+            _ => NightEffect::None,
+        }
+    }
+}
+
+/// Holds a healing potion and a poison; acts last so the attack result is
+/// known when she decides.
+#[derive(Debug, Clone, Copy)]
+pub struct WitchBehavior;
+
+impl RoleBehavior for WitchBehavior {
+    fn display_name(&self) -> &'static str {
+        "Witch"
+    }
+
+    fn alignment(&self) -> Alignment {
+        Alignment::Town
+    }
+
+    fn night_priority(&self) -> Option<u8> {
+        Some(40)
+    }
+
+    fn resolve(&self, action: &Action, _state: &GameState) -> NightEffect {
+        match action {
+            Action::Heal(target) => NightEffect::Heal(*target),
+            Action::Poison(target) => NightEffect::Poison(*target),
+            _ => NightEffect::None,
+        }
+    }
+}
+
+/// No night action; the dying shot is handled by the death machinery.
+#[derive(Debug, Clone, Copy)]
+pub struct HunterBehavior;
+
+impl RoleBehavior for HunterBehavior {
+    fn display_name(&self) -> &'static str {
+        "Hunter"
+    }
+
+    fn alignment(&self) -> Alignment {
+        Alignment::Town
+    }
+}
+
+/// Protects one player per night; acts first.
+#[derive(Debug, Clone, Copy)]
+pub struct GuardBehavior;
+
+impl RoleBehavior for GuardBehavior {
+    fn display_name(&self) -> &'static str {
+        "Guard"
+    }
+
+    fn alignment(&self) -> Alignment {
+        Alignment::Town
+    }
+
+    fn night_priority(&self) -> Option<u8> {
+        Some(10)
+    }
+
+    fn resolve(&self, action: &Action, _state: &GameState) -> NightEffect {
+        match action {
+            Action::Protect(target) => NightEffect::Protect(*target),
+            _ => NightEffect::None,
+        }
+    }
+}
+
+/// Town protector who dies in their charge's place: an attack on the
+/// protected target lands on the Bodyguard instead. Protecting yourself
+/// degenerates to plain protection, under the same [`GuardRules`] as the
+/// Guard.
+///
+/// [`GuardRules`]: crate::game::night::GuardRules
+#[derive(Debug, Clone, Copy)]
+pub struct BodyguardBehavior;
+
+impl RoleBehavior for BodyguardBehavior {
+    fn display_name(&self) -> &'static str {
+        "Bodyguard"
+    }
+
+    fn alignment(&self) -> Alignment {
+        Alignment::Town
+    }
+
+    fn night_priority(&self) -> Option<u8> {
+        Some(11)
+    }
+
+    fn resolve(&self, action: &Action, _state: &GameState) -> NightEffect {
+        match action {
+            Action::Protect(target) => NightEffect::Sacrifice(*target),
+            _ => NightEffect::None,
+        }
+    }
+}
+
+/// Pack leader: kills like any wolf, but its voice counts double when the
+/// council picks tonight's victim.
+#[derive(Debug, Clone, Copy)]
+pub struct AlphaWerewolfBehavior;
+
+impl RoleBehavior for AlphaWerewolfBehavior {
+    fn display_name(&self) -> &'static str {
+        "Alpha Werewolf"
+    }
+
+    fn alignment(&self) -> Alignment {
+        Alignment::Wolf
+    }
+
+    fn night_priority(&self) -> Option<u8> {
+        Some(30)
+    }
+
+    fn resolve(&self, action: &Action, _state: &GameState) -> NightEffect {
+        match action {
+            Action::Kill(target) => NightEffect::Attack(*target),
+            _ => NightEffect::None,
+        }
+    }
+}
+
+/// Wolf-aligned informant: reads the wolf channel and wins with the pack,
+/// but never wakes up and holds no vote in the kill decision.
+#[derive(Debug, Clone, Copy)]
+pub struct MinionBehavior;
+
+impl RoleBehavior for MinionBehavior {
+    fn display_name(&self) -> &'static str {
+        "Minion"
+    }
+
+    fn alignment(&self) -> Alignment {
+        Alignment::Wolf
+    }
+
+    /// A Minion is an ordinary human: a binary-mode check reads them as
+    /// town despite the wolf alignment.
+    fn appears_as_werewolf(&self) -> bool {
+        false
+    }
+}
+
+/// The role behaviors available to a game, keyed by string id.
+///
+/// [`RoleRegistry::default`] contains every built-in role under its display
+/// name; downstream crates add their own with [`register`]. Two registries
+/// compare equal when they offer the same ids — behaviors themselves carry
+/// no comparable state.
+///
+/// [`register`]: RoleRegistry::register
+#[derive(Clone)]
+pub struct RoleRegistry {
+    behaviors: HashMap<String, Arc<dyn RoleBehavior>>,
+}
+
+impl Default for RoleRegistry {
+    fn default() -> Self {
+        let mut registry = Self { behaviors: HashMap::new() };
+        registry.register("Villager", Arc::new(VillagerBehavior));
+        registry.register("Werewolf", Arc::new(WerewolfBehavior));
+        registry.register("Seer", Arc::new(SeerBehavior));
+        registry.register("Witch", Arc::new(WitchBehavior));
+        registry.register("Hunter", Arc::new(HunterBehavior));
+        registry.register("Guard", Arc::new(GuardBehavior));
+        registry.register("Bodyguard", Arc::new(BodyguardBehavior));
+        registry.register("Alpha Werewolf", Arc::new(AlphaWerewolfBehavior));
+        registry.register("Minion", Arc::new(MinionBehavior));
+        registry
+    }
+}
+
+impl RoleRegistry {
+    /// Registers a behavior under `id`. Re-registering an id overwrites,
+    /// so built-ins can be replaced wholesale.
+    pub fn register(&mut self, id: impl Into<String>, behavior: Arc<dyn RoleBehavior>) {
+        self.behaviors.insert(id.into(), behavior);
+    }
+
+    /// The behavior registered under `id`, if any.
+    pub fn get(&self, id: &str) -> Option<&Arc<dyn RoleBehavior>> {
+        self.behaviors.get(id)
+    }
+
+    /// All registered ids, sorted.
+    pub fn ids(&self) -> Vec<&str> {
+        let mut ids: Vec<&str> = self.behaviors.keys().map(String::as_str).collect();
+        ids.sort_unstable();
+        ids
+    }
+}
+
+impl std::fmt::Debug for RoleRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RoleRegistry").field("ids", &self.ids()).finish()
+    }
+}
+
+impl PartialEq for RoleRegistry {
+    fn eq(&self, other: &Self) -> bool {
+        self.ids() == other.ids()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::night::{DeathCause, resolve_night_with};
+    use crate::game::state::Phase;
+    use crate::roles::Role;
+
+    #[test]
+    fn built_in_roles_are_registered_by_display_name() {
+        let registry = RoleRegistry::default();
+        for role in Role::all() {
+            let behavior = registry.get(role.info().display_name).unwrap();
+            assert_eq!(behavior.alignment(), role.alignment());
+            assert_eq!(behavior.night_priority(), role.info().night_action_priority);
+        }
+    }
+
+    /// A homebrew town killer, defined entirely outside the `Role` enum.
+    struct Vigilante;
+
+    impl RoleBehavior for Vigilante {
+        fn display_name(&self) -> &'static str {
+            "Vigilante"
+        }
+
+        fn alignment(&self) -> Alignment {
+            Alignment::Town
+        }
+
+        fn night_priority(&self) -> Option<u8> {
+            // After the wolves, before the Witch.
+            Some(35)
+        }
+
+        fn resolve(&self, action: &Action, _state: &GameState) -> NightEffect {
+            match action {
+                Action::Kill(target) => NightEffect::Attack(*target),
+                _ => NightEffect::None,
+            }
+        }
+    }
+
+    #[test]
+    fn a_registered_custom_role_plays_a_night() {
+        let mut registry = RoleRegistry::default();
+        registry.register("Vigilante", Arc::new(Vigilante));
+
+        let mut state = GameState::new(0..4, Phase::Night, 0);
+        state.assign_role(PlayerId(1), Role::Werewolf);
+        state.assign_role(PlayerId(2), Role::Villager);
+        state.assign_role(PlayerId(3), Role::Villager);
+        state.assign_custom_role(PlayerId(0), "Vigilante");
+
+        let outcome =
+            resolve_night_with(&mut state, vec![(PlayerId(0), Action::Kill(PlayerId(3)))], &registry);
+        assert_eq!(outcome.deaths, vec![(PlayerId(3), DeathCause::WolfKill)]);
+        assert!(!state.is_alive(PlayerId(3)));
+    }
+
+    #[test]
+    fn unknown_role_ids_resolve_to_nothing() {
+        let registry = RoleRegistry::default();
+        let mut state = GameState::new(0..3, Phase::Night, 0);
+        state.assign_role(PlayerId(1), Role::Werewolf);
+        state.assign_role(PlayerId(2), Role::Villager);
+        state.assign_custom_role(PlayerId(0), "Nonexistent");
+        let outcome =
+            resolve_night_with(&mut state, vec![(PlayerId(0), Action::Kill(PlayerId(2)))], &registry);
+        assert!(outcome.deaths.is_empty());
+    }
+}
@@ -0,0 +1,151 @@
+//! Role definitions for the classic Werewolf role set.
+
+pub mod behavior;
+
+use serde::{Deserialize, Serialize};
+
+pub use behavior::{NightEffect, RoleBehavior, RoleRegistry};
+
+/// The side a role wins with.
+///
+/// `Neutral` is reserved for future third-party roles (e.g. a Jester) that
+/// win alone; nothing in the built-in set uses it yet, but alignment-based
+/// logic should not assume every role is Town or Wolf.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum Alignment {
+    Town,
+    Wolf,
+    Neutral,
+}
+
+/// The classic Werewolf roles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum Role {
+    Villager,
+    Werewolf,
+    Seer,
+    Witch,
+    Hunter,
+    Guard,
+    /// Pack leader whose say counts double in the wolves' kill vote; see
+    /// [`run_wolf_council`](crate::game::night::run_wolf_council).
+    AlphaWerewolf,
+    /// Wolf-aligned but toothless: knows the wolves from the start and
+    /// reads their chat, yet has no night kill and no council vote. Town
+    /// wins without eliminating the Minion unless the
+    /// `minion_blocks_town_win` variant is on; see
+    /// [`WinRules`](crate::game::win::WinRules).
+    Minion,
+}
+
+/// Static metadata describing a role.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RoleInfo {
+    /// Human-readable name shown in narration and prompts.
+    pub display_name: &'static str,
+    /// Order in which the role's night action resolves; lower acts first.
+    /// `None` for roles with no night action.
+    pub night_action_priority: Option<u8>,
+    /// Whether the role wakes up to act at night.
+    pub acts_at_night: bool,
+}
+
+impl Role {
+    /// All built-in roles, useful for building default configurations.
+    pub fn all() -> &'static [Role] {
+        &[
+            Role::Villager,
+            Role::Werewolf,
+            Role::Seer,
+            Role::Witch,
+            Role::Hunter,
+            Role::Guard,
+            Role::AlphaWerewolf,
+            Role::Minion,
+        ]
+    }
+
+    /// This role's [`RoleBehavior`], the single source of truth for its
+    /// alignment, night priority, and action resolution.
+    pub fn behavior(&self) -> &'static dyn RoleBehavior {
+        match self {
+            Role::Villager => &behavior::VillagerBehavior,
+            Role::Werewolf => &behavior::WerewolfBehavior,
+            Role::Seer => &behavior::SeerBehavior,
+            Role::Witch => &behavior::WitchBehavior,
+            Role::Hunter => &behavior::HunterBehavior,
+            Role::Guard => &behavior::GuardBehavior,
+            Role::AlphaWerewolf => &behavior::AlphaWerewolfBehavior,
+            Role::Minion => &behavior::MinionBehavior,
+        }
+    }
+
+    /// The side this role wins with.
+    pub fn alignment(&self) -> Alignment {
+        self.behavior().alignment()
+    }
+
+    /// Static metadata for this role.
+    ///
+    /// Night resolution order follows the common convention: the Guard
+    /// protects first, then the Seer peeks, then the wolves kill, then the
+    /// Witch decides with the attack result known.
+    pub fn info(&self) -> RoleInfo {
+        let behavior = self.behavior();
+        RoleInfo {
+            display_name: behavior.display_name(),
+            night_action_priority: behavior.night_priority(),
+            acts_at_night: behavior.acts_at_night(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wolf_team_roles_are_wolf_aligned() {
+        for role in [Role::Werewolf, Role::AlphaWerewolf, Role::Minion] {
+            assert_eq!(role.alignment(), Alignment::Wolf);
+        }
+    }
+
+    #[test]
+    fn town_roles_are_town_aligned() {
+        for role in [Role::Villager, Role::Seer, Role::Witch, Role::Hunter, Role::Guard] {
+            assert_eq!(role.alignment(), Alignment::Town);
+        }
+    }
+
+    #[test]
+    fn all_lists_every_role() {
+        assert_eq!(Role::all().len(), 8);
+    }
+
+    #[test]
+    fn the_alpha_kills_like_a_wolf_and_the_minion_sleeps() {
+        assert_eq!(
+            Role::AlphaWerewolf.info().night_action_priority,
+            Role::Werewolf.info().night_action_priority
+        );
+        assert_eq!(Role::Minion.info().night_action_priority, None);
+        assert!(!Role::Minion.info().acts_at_night);
+    }
+
+    #[test]
+    fn night_priorities_follow_resolution_order() {
+        let guard = Role::Guard.info().night_action_priority.unwrap();
+        let seer = Role::Seer.info().night_action_priority.unwrap();
+        let wolf = Role::Werewolf.info().night_action_priority.unwrap();
+        let witch = Role::Witch.info().night_action_priority.unwrap();
+        assert!(guard < seer && seer < wolf && wolf < witch);
+    }
+
+    #[test]
+    fn roles_without_night_actions_have_no_priority() {
+        assert_eq!(Role::Villager.info().night_action_priority, None);
+        assert!(!Role::Villager.info().acts_at_night);
+        assert_eq!(Role::Hunter.info().night_action_priority, None);
+    }
+}
@@ -0,0 +1,356 @@
+//! WebSocket server mode: drive games from a frontend.
+//!
+//! The wire protocol is exactly the serde shapes of [`ClientMessage`] and
+//! [`ServerMessage`] (externally tagged with `type`), so a TypeScript
+//! frontend can mirror them mechanically. Clients subscribe to a
+//! [`GameHub`] and receive every [`GameEvent`] as JSON; a client that has
+//! joined a seat answers the hub's action requests for it.
+//!
+//! Human seats are ordinary [`Player`]s: a [`WebSocketPlayer`] forwards
+//! each question to the hub and awaits the client's answer, so mixed
+//! tables of humans and [`LlmPlayer`]s run through the same game loop. A
+//! disconnected client simply never answers, which lets the configured
+//! [`TurnPolicy`](crate::game::timeout::TurnPolicy) timeout and fallback
+//! take over the turn.
+//!
+//! [`LlmPlayer`]: crate::player::LlmPlayer
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use futures::{SinkExt, StreamExt};
+use serde::{Deserialize, Serialize};
+use tokio::net::TcpListener;
+use tokio::sync::{Mutex, broadcast, mpsc};
+use tokio_tungstenite::tungstenite::Message;
+
+use crate::game::action::Action;
+use crate::game::event::GameEvent;
+use crate::game::state::PlayerId;
+use crate::game::timeout::ActionKind;
+use crate::player::{GameContext, Player};
+
+/// Messages a client may send. Externally tagged as `{"type": "...", ...}`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ClientMessage {
+    /// Claim a seat; subsequent answers for it come from this client.
+    Join { player: PlayerId },
+    /// Answer to a `vote` request.
+    Vote { player: PlayerId, target: PlayerId },
+    /// Answer to a `night_action` request; `None` skips acting.
+    NightAction { player: PlayerId, action: Option<Action> },
+    /// Answer to a `speech` request.
+    Speech { player: PlayerId, text: String },
+    /// Answer to a `hunter_shot` request; `None` holds fire.
+    HunterShot { player: PlayerId, target: Option<PlayerId> },
+}
+
+/// Messages the server pushes. Externally tagged as `{"type": "...", ...}`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ServerMessage {
+    /// A game event, in log order.
+    Event { event: GameEvent },
+    /// The game is waiting on `player` for the given kind of answer.
+    ActionRequest { player: PlayerId, kind: ActionKind },
+}
+
+/// The rendezvous point between one game and its WebSocket clients.
+///
+/// The game loop publishes events through [`publish`]; connections
+/// subscribe and forward them. Answers submitted by clients are routed to
+/// the [`WebSocketPlayer`] for the seat they name.
+///
+/// [`publish`]: GameHub::publish
+pub struct GameHub {
+    events: broadcast::Sender<ServerMessage>,
+    inboxes: Mutex<HashMap<PlayerId, mpsc::UnboundedSender<ClientMessage>>>,
+}
+
+impl GameHub {
+    pub fn new() -> Arc<Self> {
+        let (events, _) = broadcast::channel(256);
+        Arc::new(Self { events, inboxes: Mutex::new(HashMap::new()) })
+    }
+
+    /// Broadcasts an event to every subscribed client.
+    pub fn publish(&self, event: &GameEvent) {
+        let _ = self.events.send(ServerMessage::Event { event: event.clone() });
+    }
+
+    /// A fresh subscription to the server's outbound messages.
+    pub fn subscribe(&self) -> broadcast::Receiver<ServerMessage> {
+        self.events.subscribe()
+    }
+
+    /// Creates the [`Player`] for a human seat. Answers a client submits
+    /// for `id` are delivered to it.
+    pub async fn player(self: &Arc<Self>, id: PlayerId) -> WebSocketPlayer {
+        let (tx, rx) = mpsc::unbounded_channel();
+        self.inboxes.lock().await.insert(id, tx);
+        WebSocketPlayer { id, hub: Arc::clone(self), answers: Mutex::new(rx) }
+    }
+
+    /// Routes a client's answer to the seat it names. Unknown seats and
+    /// `Join` messages are ignored here; `Join` is handled per-connection.
+    pub async fn submit(&self, msg: ClientMessage) {
+        let player = match &msg {
+            ClientMessage::Join { .. } => return,
+            ClientMessage::Vote { player, .. }
+            | ClientMessage::NightAction { player, .. }
+            | ClientMessage::Speech { player, .. }
+            | ClientMessage::HunterShot { player, .. } => *player,
+        };
+        if let Some(inbox) = self.inboxes.lock().await.get(&player) {
+            let _ = inbox.send(msg);
+        }
+    }
+
+    fn request(&self, player: PlayerId, kind: ActionKind) {
+        let _ = self.events.send(ServerMessage::ActionRequest { player, kind });
+    }
+}
+
+/// A [`Player`] whose answers come from a WebSocket client.
+///
+/// Each question publishes an [`ServerMessage::ActionRequest`] and then
+/// awaits a matching answer. If the client has disconnected the future
+/// never resolves, so the game's turn timeout and fallback strategy decide
+/// the turn instead.
+pub struct WebSocketPlayer {
+    id: PlayerId,
+    hub: Arc<GameHub>,
+    answers: Mutex<mpsc::UnboundedReceiver<ClientMessage>>,
+}
+
+impl WebSocketPlayer {
+    /// The next answer for this seat, skipping messages of the wrong kind.
+    /// Pends forever once the inbox is closed (client gone).
+    async fn next_answer<T>(&self, mut extract: impl FnMut(ClientMessage) -> Option<T>) -> T {
+        let mut answers = self.answers.lock().await;
+        loop {
+            match answers.recv().await {
+                Some(msg) => {
+                    if let Some(answer) = extract(msg) {
+                        return answer;
+                    }
+                }
+                None => std::future::pending().await,
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl Player for WebSocketPlayer {
+    async fn vote(&self, _ctx: &GameContext) -> PlayerId {
+        self.hub.request(self.id, ActionKind::Vote);
+        self.next_answer(|msg| match msg {
+            ClientMessage::Vote { target, .. } => Some(target),
+            _ => None,
+        })
+        .await
+    }
+
+    async fn night_action(&self, _ctx: &GameContext) -> Option<Action> {
+        self.hub.request(self.id, ActionKind::NightAction);
+        self.next_answer(|msg| match msg {
+            ClientMessage::NightAction { action, .. } => Some(action),
+            _ => None,
+        })
+        .await
+    }
+
+    async fn speak(&self, _ctx: &GameContext) -> String {
+        self.hub.request(self.id, ActionKind::Speech);
+        self.next_answer(|msg| match msg {
+            ClientMessage::Speech { text, .. } => Some(text),
+            _ => None,
+        })
+        .await
+    }
+
+    async fn hunter_shot(&self, _ctx: &GameContext) -> Option<PlayerId> {
+        self.hub.request(self.id, ActionKind::HunterShot);
+        self.next_answer(|msg| match msg {
+            ClientMessage::HunterShot { target, .. } => Some(target),
+            _ => None,
+        })
+        .await
+    }
+}
+
+/// Serves the hub on a TCP address, accepting WebSocket connections until
+/// the task is dropped. Each client receives every [`ServerMessage`] as a
+/// JSON text frame and may submit [`ClientMessage`] answers; a client must
+/// `Join` a seat before its answers for that seat are accepted.
+pub async fn serve(addr: &str, hub: Arc<GameHub>) -> std::io::Result<()> {
+    let listener = TcpListener::bind(addr).await?;
+    loop {
+        let (stream, _) = listener.accept().await?;
+        let hub = Arc::clone(&hub);
+        tokio::spawn(async move {
+            let Ok(ws) = tokio_tungstenite::accept_async(stream).await else { return };
+            let (mut sink, mut source) = ws.split();
+
+            let mut events = hub.subscribe();
+            let forward = tokio::spawn(async move {
+                while let Ok(msg) = events.recv().await {
+                    let Ok(json) = serde_json::to_string(&msg) else { continue };
+                    if sink.send(Message::text(json)).await.is_err() {
+                        break;
+                    }
+                }
+            });
+
+            let mut joined: Option<PlayerId> = None;
+            while let Some(Ok(frame)) = source.next().await {
+                let Ok(text) = frame.into_text() else { continue };
+                let Ok(msg) = serde_json::from_str::<ClientMessage>(text.as_str()) else {
+                    continue;
+                };
+                match msg {
+                    ClientMessage::Join { player } => joined = Some(player),
+                    // Only the joined seat may be answered for.
+                    ref answer if Some(answer_seat(answer)) == joined => {
+                        hub.submit(msg).await;
+                    }
+                    _ => {}
+                }
+            }
+            forward.abort();
+        });
+    }
+}
+
+fn answer_seat(msg: &ClientMessage) -> PlayerId {
+    match msg {
+        ClientMessage::Join { player }
+        | ClientMessage::Vote { player, .. }
+        | ClientMessage::NightAction { player, .. }
+        | ClientMessage::Speech { player, .. }
+        | ClientMessage::HunterShot { player, .. } => *player,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::game::event::GameEventKind;
+    use crate::game::state::{GameState, Phase};
+    use crate::game::timeout::{FallbackStrategy, TurnPolicy, timed_vote};
+    use crate::roles::Role;
+
+    fn ctx_for(player: PlayerId) -> GameContext {
+        GameContext {
+            player,
+            role: Role::Villager,
+            day: 1,
+            phase: Phase::Voting,
+            alive_players: vec![PlayerId(0), PlayerId(1), PlayerId(2)],
+            public_log: Vec::new(),
+            knowledge: Default::default(),
+            claims: Vec::new(),
+            tokens_used: 0,
+            potions: None,
+            last_protected: None,
+            wolf_chat: Vec::new(),
+            graveyard: Vec::new(),
+            cost_so_far: 0.0,
+        }
+    }
+
+    #[tokio::test]
+    async fn client_answer_resolves_the_players_turn() {
+        let hub = GameHub::new();
+        let player = hub.player(PlayerId(1)).await;
+        let mut requests = hub.subscribe();
+
+        let hub2 = Arc::clone(&hub);
+        let answerer = tokio::spawn(async move {
+            // Wait for the request, then answer like a client would.
+            while let Ok(msg) = requests.recv().await {
+                if matches!(
+                    msg,
+                    ServerMessage::ActionRequest { player: PlayerId(1), kind: ActionKind::Vote }
+                ) {
+                    hub2.submit(ClientMessage::Vote { player: PlayerId(1), target: PlayerId(2) }).await;
+                    break;
+                }
+            }
+        });
+
+        assert_eq!(player.vote(&ctx_for(PlayerId(1))).await, PlayerId(2));
+        answerer.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn answers_of_the_wrong_kind_are_skipped() {
+        let hub = GameHub::new();
+        let player = hub.player(PlayerId(1)).await;
+        hub.submit(ClientMessage::Speech { player: PlayerId(1), text: "hi".into() }).await;
+        hub.submit(ClientMessage::Vote { player: PlayerId(1), target: PlayerId(0) }).await;
+        assert_eq!(player.vote(&ctx_for(PlayerId(1))).await, PlayerId(0));
+    }
+
+    #[tokio::test]
+    async fn disconnected_client_falls_back_via_the_turn_timeout() {
+        let hub = GameHub::new();
+        let player = hub.player(PlayerId(1)).await;
+        // Simulate the disconnect: the hub drops the seat's inbox.
+        hub.inboxes.lock().await.clear();
+
+        let mut state = GameState::new(0..3, Phase::Voting, 0);
+        state.assign_role(PlayerId(0), Role::Werewolf);
+        state.assign_role(PlayerId(1), Role::Villager);
+        state.assign_role(PlayerId(2), Role::Villager);
+        let policy = TurnPolicy {
+            timeout: Duration::from_millis(10),
+            fallback: FallbackStrategy::Skip,
+            ..TurnPolicy::default()
+        };
+        let vote = timed_vote(&player, &ctx_for(PlayerId(1)), &mut state, &policy).await;
+        assert_eq!(vote, None);
+        assert!(state
+            .log()
+            .iter()
+            .any(|e| matches!(e.kind, GameEventKind::FallbackTriggered { player: PlayerId(1), .. })));
+    }
+
+    #[tokio::test]
+    async fn subscribers_receive_published_events_as_json_shapes() {
+        let hub = GameHub::new();
+        let mut rx = hub.subscribe();
+        let event = GameEvent::now(1, GameEventKind::PlayerDied {
+            player: PlayerId(2),
+            cause: crate::game::night::DeathCause::Vote,
+            role: None,
+            alignment: None,
+        });
+        hub.publish(&event);
+        let msg = rx.recv().await.unwrap();
+        let json = serde_json::to_string(&msg).unwrap();
+        assert!(json.contains(r#""type":"event""#));
+        assert!(json.contains("PlayerDied"));
+    }
+
+    #[test]
+    fn client_protocol_round_trips_through_json() {
+        let msgs = vec![
+            ClientMessage::Join { player: PlayerId(3) },
+            ClientMessage::Vote { player: PlayerId(3), target: PlayerId(1) },
+            ClientMessage::NightAction { player: PlayerId(3), action: Some(Action::Kill(PlayerId(0))) },
+            ClientMessage::Speech { player: PlayerId(3), text: "hello".into() },
+            ClientMessage::HunterShot { player: PlayerId(3), target: None },
+        ];
+        for msg in msgs {
+            let json = serde_json::to_string(&msg).unwrap();
+            assert!(json.contains(r#""type":"#), "missing tag: {json}");
+            assert_eq!(serde_json::from_str::<ClientMessage>(&json).unwrap(), msg);
+        }
+    }
+}
@@ -0,0 +1,329 @@
+//! Batch tournament runner for comparing strategies across many games.
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use tokio::task::JoinSet;
+
+use crate::config::{FirstPhase, GameConfig};
+use crate::game::runner::run_game_with;
+use crate::game::state::{GameState, Phase, PlayerId};
+use crate::llm::{LlmProvider, health_check_all};
+use crate::player::Player;
+use crate::roles::{Alignment, Role};
+
+/// Creates a fresh set of players for each game of a tournament, so
+/// per-game state (scripts, provider sessions) isn't shared.
+pub trait PlayerFactory: Send + Sync {
+    /// Players for game number `game_index`, keyed by seat.
+    fn create(&self, game_index: usize) -> HashMap<PlayerId, Box<dyn Player>>;
+
+    /// Like [`create`](PlayerFactory::create), but the game's role
+    /// assignment is known up front, so a factory can pick a backend per
+    /// role (e.g. one model for the wolves, another for town). The default
+    /// ignores the roles and delegates to `create`.
+    fn create_for_roles(
+        &self,
+        game_index: usize,
+        roles: &[(PlayerId, Role)],
+    ) -> HashMap<PlayerId, Box<dyn Player>> {
+        let _ = roles;
+        self.create(game_index)
+    }
+
+    /// The distinct providers the factory's players will call, each under
+    /// a label for error messages (a model id, a gateway name, ...). The
+    /// tournament health-checks every entry before the first game starts,
+    /// so a bad API key fails in seconds rather than mid-run. The default
+    /// is empty: scripted tables have nothing to check.
+    fn providers(&self) -> Vec<(String, Arc<dyn LlmProvider>)> {
+        Vec::new()
+    }
+}
+
+/// Aggregated results of a tournament.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TournamentStats {
+    /// Games that ran to completion.
+    pub games: usize,
+    /// Wins per alignment.
+    pub wins: BTreeMap<Alignment, usize>,
+    /// Games that ended in a draw at the day cap; see
+    /// [`GameConfig::max_days`](crate::config::GameConfig::max_days).
+    #[serde(default)]
+    pub draws: usize,
+    /// Mean number of days per game.
+    pub avg_game_length: f64,
+    /// Per role: fraction of games in which the player holding it survived.
+    pub survival_rate: BTreeMap<Role, f64>,
+}
+
+struct GameSummary {
+    winner: Option<Alignment>,
+    drawn: bool,
+    days: u32,
+    /// (role, survived) per seat.
+    survival: Vec<(Role, bool)>,
+}
+
+/// Runs `games` games of `config`, at most `concurrency` at a time, and
+/// aggregates the results. Game `i` is seeded with `base_seed + i` so the
+/// whole tournament is reproducible.
+///
+/// Every provider reported by [`PlayerFactory::providers`] is
+/// health-checked first; a failure aborts before any game runs, with an
+/// error naming the offending provider.
+pub async fn run_tournament(
+    config: &GameConfig,
+    players: Arc<dyn PlayerFactory>,
+    games: usize,
+    concurrency: usize,
+) -> crate::error::Result<TournamentStats> {
+    health_check_all(&players.providers()).await?;
+
+    let mut set: JoinSet<GameSummary> = JoinSet::new();
+    let mut next = 0usize;
+    let mut summaries = Vec::with_capacity(games);
+    let base_seed = 0u64;
+
+    while next < games || !set.is_empty() {
+        while next < games && set.len() < concurrency.max(1) {
+            let config = config.clone();
+            let factory = players.clone();
+            let game_index = next;
+            let seed = base_seed + next as u64;
+            set.spawn(async move { play_one(&config, &*factory, game_index, seed).await });
+            next += 1;
+        }
+        if let Some(Ok(summary)) = set.join_next().await {
+            summaries.push(summary);
+        }
+    }
+
+    Ok(aggregate(&summaries))
+}
+
+fn aggregate(summaries: &[GameSummary]) -> TournamentStats {
+    let games = summaries.len();
+    let mut wins: BTreeMap<Alignment, usize> = BTreeMap::new();
+    let mut draws = 0usize;
+    let mut total_days = 0u64;
+    let mut role_games: BTreeMap<Role, usize> = BTreeMap::new();
+    let mut role_survived: BTreeMap<Role, usize> = BTreeMap::new();
+
+    for summary in summaries {
+        if let Some(winner) = summary.winner {
+            *wins.entry(winner).or_default() += 1;
+        }
+        if summary.drawn {
+            draws += 1;
+        }
+        total_days += u64::from(summary.days);
+        for (role, survived) in &summary.survival {
+            *role_games.entry(*role).or_default() += 1;
+            if *survived {
+                *role_survived.entry(*role).or_default() += 1;
+            }
+        }
+    }
+
+    let survival_rate = role_games
+        .iter()
+        .map(|(role, &n)| {
+            let survived = role_survived.get(role).copied().unwrap_or(0);
+            (*role, survived as f64 / n as f64)
+        })
+        .collect();
+
+    TournamentStats {
+        games,
+        wins,
+        draws,
+        avg_game_length: if games == 0 { 0.0 } else { total_days as f64 / games as f64 },
+        survival_rate,
+    }
+}
+
+/// Runs a single game to completion: assigns roles via the seeded shuffle,
+/// creates the players for the resulting assignment, then hands off to the
+/// shared [`run_game_with`] driver.
+async fn play_one(
+    config: &GameConfig,
+    factory: &dyn PlayerFactory,
+    game_index: usize,
+    seed: u64,
+) -> GameSummary {
+    let first_phase = match config.first_phase {
+        FirstPhase::Night => Phase::Night,
+        FirstPhase::Day => Phase::Day,
+    };
+    let ids: Vec<PlayerId> = (0..config.player_count as u32).map(PlayerId).collect();
+    let mut state = GameState::new(ids.iter().copied(), first_phase, seed);
+
+    // Deterministic assignment: sorted seats, seeded shuffle of the
+    // sorted role multiset.
+    let mut roles: Vec<Role> = config
+        .roles
+        .iter()
+        .flat_map(|(role, count)| std::iter::repeat_n(*role, *count))
+        .collect();
+    state.rng_mut().shuffle(&mut roles);
+    for (id, role) in ids.iter().zip(roles) {
+        state.assign_role(*id, role);
+    }
+
+    let seat_roles: Vec<(PlayerId, Role)> = state
+        .players()
+        .iter()
+        .filter_map(|p| state.role_of(p.id).map(|role| (p.id, role)))
+        .collect();
+    let players = factory.create_for_roles(game_index, &seat_roles);
+    let result = run_game_with(state, players, config)
+        .await
+        .expect("a built state always runs to completion");
+    let survival = seat_roles
+        .into_iter()
+        .map(|(id, role)| (role, result.survivors.contains(&id)))
+        .collect();
+    GameSummary {
+        winner: result.winner,
+        drawn: result.outcome == crate::game::runner::GameOutcome::Draw,
+        days: result.days,
+        survival,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::player::ScriptedPlayer;
+
+    /// Every player votes the lowest living seat each day, which always
+    /// terminates: the table shrinks by one player per day.
+    struct LynchInOrder {
+        seats: usize,
+    }
+
+    impl PlayerFactory for LynchInOrder {
+        fn create(&self, _game_index: usize) -> HashMap<PlayerId, Box<dyn Player>> {
+            (0..self.seats as u32).map(PlayerId)
+                .map(|id| {
+                    let mut p = ScriptedPlayer::new();
+                    for day in 0..self.seats as u32 {
+                        // Vote the lowest seat that can still be alive.
+                        p = p.will_vote(PlayerId(day));
+                    }
+                    (id, Box::new(p) as Box<dyn Player>)
+                })
+                .collect()
+        }
+    }
+
+    fn small_config() -> GameConfig {
+        GameConfig {
+            player_count: 4,
+            roles: BTreeMap::from([(Role::Werewolf, 1), (Role::Villager, 3)]),
+            ..GameConfig::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn tournament_aggregates_all_games() {
+        let config = small_config();
+        let factory = Arc::new(LynchInOrder { seats: 4 });
+        let stats = run_tournament(&config, factory, 6, 2).await.unwrap();
+        assert_eq!(stats.games, 6);
+        assert_eq!(stats.wins.values().sum::<usize>(), 6);
+        assert!(stats.avg_game_length >= 1.0);
+        assert!(stats.survival_rate.contains_key(&Role::Werewolf));
+    }
+
+    #[tokio::test]
+    async fn stats_serialize_for_downstream_analysis() {
+        let config = small_config();
+        let factory = Arc::new(LynchInOrder { seats: 4 });
+        let stats = run_tournament(&config, factory, 2, 1).await.unwrap();
+        let json = serde_json::to_string(&stats).unwrap();
+        assert!(json.contains("avg_game_length"));
+    }
+
+    #[tokio::test]
+    async fn same_seeds_reproduce_the_same_outcome() {
+        let config = small_config();
+        let a = run_tournament(&config, Arc::new(LynchInOrder { seats: 4 }), 3, 1).await.unwrap();
+        let b = run_tournament(&config, Arc::new(LynchInOrder { seats: 4 }), 3, 3).await.unwrap();
+        assert_eq!(a.wins, b.wins);
+    }
+
+    #[tokio::test]
+    async fn drawn_games_are_counted_separately_from_wins() {
+        /// Every seat abstains forever (the vote target is off the table),
+        /// so only the day cap can end a game.
+        struct Stallers {
+            seats: usize,
+        }
+
+        impl PlayerFactory for Stallers {
+            fn create(&self, _game_index: usize) -> HashMap<PlayerId, Box<dyn Player>> {
+                (0..self.seats as u32).map(PlayerId)
+                    .map(|id| {
+                        let p = ScriptedPlayer::new().will_vote(PlayerId(99)).will_vote(PlayerId(99));
+                        (id, Box::new(p) as Box<dyn Player>)
+                    })
+                    .collect()
+            }
+        }
+
+        let config = GameConfig { max_days: Some(2), ..small_config() };
+        let stats = run_tournament(&config, Arc::new(Stallers { seats: 4 }), 3, 2)
+            .await
+            .unwrap();
+        assert_eq!(stats.games, 3);
+        assert_eq!(stats.draws, 3);
+        assert!(stats.wins.is_empty());
+    }
+
+    #[tokio::test]
+    async fn a_failed_health_check_aborts_before_any_game() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        use async_trait::async_trait;
+
+        use crate::error::Error;
+        use crate::llm::{ChatRequest, ChatResponse, LlmError};
+
+        struct DeadProvider;
+
+        #[async_trait]
+        impl LlmProvider for DeadProvider {
+            async fn complete(&self, _req: ChatRequest) -> Result<ChatResponse, LlmError> {
+                Err(LlmError::Status { status: 401, body: "invalid api key".into() })
+            }
+        }
+
+        struct CountingFactory {
+            created: AtomicUsize,
+        }
+
+        impl PlayerFactory for CountingFactory {
+            fn create(&self, _game_index: usize) -> HashMap<PlayerId, Box<dyn Player>> {
+                self.created.fetch_add(1, Ordering::SeqCst);
+                HashMap::new()
+            }
+
+            fn providers(&self) -> Vec<(String, Arc<dyn LlmProvider>)> {
+                vec![("gpt-broken".into(), Arc::new(DeadProvider))]
+            }
+        }
+
+        let config = small_config();
+        let factory = Arc::new(CountingFactory { created: AtomicUsize::new(0) });
+        let err = run_tournament(&config, factory.clone(), 5, 2).await.unwrap_err();
+        match err {
+            Error::HealthCheck(failure) => assert_eq!(failure.provider, "gpt-broken"),
+            other => panic!("expected a health-check failure, got {other}"),
+        }
+        assert_eq!(factory.created.load(Ordering::SeqCst), 0, "no game should have started");
+    }
+}
@@ -0,0 +1,50 @@
+//! End-to-end test of the public [`run_game`] driver with a scripted
+//! 7-player table.
+
+use llmwerewolf_rs::game::builder::GameBuilder;
+use llmwerewolf_rs::game::event::GameEventKind;
+use llmwerewolf_rs::game::runner::run_game;
+use llmwerewolf_rs::game::{Action, PlayerId};
+use llmwerewolf_rs::player::ScriptedPlayer;
+use llmwerewolf_rs::roles::{Alignment, Role};
+
+#[tokio::test]
+async fn scripted_seven_player_game_runs_to_completion() {
+    // 0/1: Werewolves, 2: Seer, 3: Witch, 4-6: Villagers. The town
+    // coordinates perfectly and lynches the wolves on days 1 and 2; the
+    // wolves never land a kill (seat 0 even tries an illegal one).
+    let mut builder = GameBuilder::new()
+        .role(Role::Werewolf, 2)
+        .role(Role::Seer, 1)
+        .role(Role::Witch, 1)
+        .role(Role::Villager, 3)
+        .assign(PlayerId(0), Role::Werewolf)
+        .assign(PlayerId(1), Role::Werewolf)
+        .assign(PlayerId(2), Role::Seer)
+        .assign(PlayerId(3), Role::Witch)
+        .seed(42);
+    for id in 0..7 {
+        let mut p = ScriptedPlayer::new().will_vote(PlayerId(0)).will_vote(PlayerId(1));
+        if id == 0 {
+            // Targeting a seat that doesn't exist is illegal: the driver
+            // must log it and carry on.
+            p = p.will_act(Some(Action::Kill(PlayerId(99))));
+        }
+        builder = builder.player(PlayerId(id), Box::new(p));
+    }
+    let (state, players) = builder.build_with_players().unwrap();
+
+    let result = run_game(state, players).await.unwrap();
+
+    assert_eq!(result.winner, Some(Alignment::Town));
+    assert_eq!(result.survivors, (2..=6).map(PlayerId).collect::<Vec<_>>());
+    assert!(result.days >= 2);
+    assert!(result.log.iter().any(|e| matches!(
+        e.kind,
+        GameEventKind::InvalidAction { player: PlayerId(0), action: Action::Kill(PlayerId(99)) }
+    )));
+    assert!(result
+        .log
+        .iter()
+        .any(|e| matches!(e.kind, GameEventKind::GameEnded { winner: Some(Alignment::Town) })));
+}